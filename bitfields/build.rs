@@ -51,28 +51,6 @@ fn extract_content(source: &str, start_tag: &str, end_tag: &str) -> String {
     output.trim().to_string()
 }
 
-/// Inserts `# use bitfields_impl as bitfields;` at the top of every rust code
-/// block in `content`.
-fn inject_rust_block_prelude(content: &str) -> String {
-    let marker = "```rust";
-    let prelude = "# use bitfields_impl as bitfields;\n";
-    let mut output = String::with_capacity(content.len());
-    let mut rest = content;
-
-    while let Some(pos) = rest.find(marker) {
-        let after_marker = pos + marker.len();
-        output.push_str(&rest[..after_marker]);
-        let newline_offset =
-            rest[after_marker..].find('\n').map(|i| i + 1).unwrap_or(rest[after_marker..].len());
-        let next = after_marker + newline_offset;
-        output.push_str(&rest[after_marker..next]);
-        output.push_str(prelude);
-        rest = &rest[next..];
-    }
-    output.push_str(rest);
-    output
-}
-
 /// Formats a block of content as Rust `///` doc-comment lines.
 fn format_as_doc_comments(content: &str) -> String {
     if content.is_empty() {
@@ -116,16 +94,16 @@ fn update_lib_docs() {
     let impl_lib_rs =
         fs::read_to_string(IMPL_LIB_RS_PATH).expect("Unable to find bitfields_impl lib.rs");
 
-    let docs_content = inject_rust_block_prelude(&extract_content(
+    let docs_content = extract_content(
         &readme,
         README_RUST_DOCS_EXTRACT_START_TAG,
         README_RUST_DOCS_EXTRACT_END_TAG,
-    ));
-    let bitflag_content = inject_rust_block_prelude(&extract_content(
+    );
+    let bitflag_content = extract_content(
         &readme,
         README_RUST_BITFLAG_DOCS_EXTRACT_START_TAG,
         README_RUST_BITFLAG_DOCS_EXTRACT_END_TAG,
-    ));
+    );
 
     let impl_updated = replace_between_tags(
         &impl_lib_rs,
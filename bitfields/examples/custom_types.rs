@@ -1,4 +1,4 @@
-use bitfields::bitfield;
+use bitfields::{BitfieldField, bitfield};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 struct Color {
@@ -7,9 +7,12 @@ struct Color {
     blue: u8,
 }
 
-impl Color {
+impl BitfieldField for Color {
+    const BITS: u32 = 8;
+    type Raw = u8;
+
     /// Convert raw bits into a `Color`.
-    const fn from_bits(bits: u8) -> Self {
+    fn from_raw(bits: u8) -> Self {
         Self {
             red: bits & 0b0000_0111,
             green: (bits >> 3) & 0b0000_0111,
@@ -18,7 +21,7 @@ impl Color {
     }
 
     /// Pack a `Color` back into bits.
-    const fn into_bits(self) -> u8 {
+    fn into_raw(self) -> u8 {
         (self.red & 0b0000_0111)
             | ((self.green & 0b0000_0111) << 3)
             | ((self.blue & 0b0000_0011) << 6)
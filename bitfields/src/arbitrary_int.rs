@@ -0,0 +1,715 @@
+//! Arbitrary-width integers (e.g. `u4` or `i12`), used as field types carrying
+//! the width of their field.
+
+use core::fmt;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::field::BitfieldField;
+
+/// An unsigned integer of `BITS` bits, stored in the primitive `T`.
+///
+/// Values are checked to fit in `BITS` bits when they're created, instead of
+/// being truncated when they're set. Fields of the `u1` to `u127` aliases take
+/// the width of their type, and their getters and setters use the type.
+///
+/// Arithmetic panics if the result doesn't fit, use the `checked_*` or
+/// `wrapping_*` methods otherwise.
+///
+/// ```rust
+/// use bitfields::{bitfield, u4, u12};
+///
+/// #[bitfield(u16)]
+/// struct Sprite {
+///     #[bits(default = 9)]
+///     palette: u4,
+///     tile: u12,
+/// }
+///
+/// let mut sprite = Sprite::new();
+/// assert_eq!(sprite.palette(), u4::new(9));
+///
+/// sprite.set_tile(u12::new(0x123));
+/// assert_eq!(sprite.tile().value(), 0x123);
+/// assert_eq!(sprite.into_bits(), 0x1239);
+///
+/// assert_eq!(u4::MAX.value(), 15);
+/// assert!(u4::try_new(0x12).is_err());
+/// assert_eq!(u4::new_truncated(0x12), u4::new(2));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UInt<T, const BITS: u32>(T);
+
+/// A signed integer of `BITS` bits, stored in the primitive `T`.
+///
+/// Values are checked to fit in `BITS` bits when they're created, instead of
+/// being truncated when they're set. Fields of the `i1` to `i127` aliases take
+/// the width of their type, and their getters and setters use the type.
+///
+/// Arithmetic panics if the result doesn't fit, use the `checked_*` or
+/// `wrapping_*` methods otherwise.
+///
+/// ```rust
+/// use bitfields::{bitfield, i4};
+///
+/// #[bitfield(u8)]
+/// struct Velocity {
+///     x: i4,
+///     #[bits(default = -3)]
+///     y: i4,
+/// }
+///
+/// let mut velocity = Velocity::new();
+/// assert_eq!(velocity.y().value(), -3);
+///
+/// velocity.set_x(i4::new(-8));
+/// assert_eq!(velocity.x(), i4::MIN);
+/// assert_eq!(velocity.into_bits(), 0xD8);
+///
+/// assert!(i4::try_new(8).is_err());
+/// assert_eq!(i4::new(7).checked_add(i4::new(1)), None);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Int<T, const BITS: u32>(T);
+
+macro_rules! impl_arbitrary_int {
+    ($($int:ident, $primitive:ty, $raw:ty, [$($into_primitive:ty),*];)*) => {
+        $(
+            impl<const BITS: u32> $int<$primitive, BITS> {
+                /// The number of bits of the integer.
+                pub const BITS: u32 = {
+                    assert!(
+                        BITS > 0 && BITS <= <$primitive>::BITS,
+                        "The bits of the integer don't fit in its primitive type."
+                    );
+                    BITS
+                };
+
+                /// The largest value of the integer.
+                pub const MAX: Self = Self(Self::max_value());
+
+                /// The smallest value of the integer.
+                pub const MIN: Self = Self(Self::min_value());
+
+                /// Creates the integer from a value.
+                ///
+                /// Panics if the value doesn't fit in the bits of the integer,
+                /// which fails compilation in const contexts.
+                pub const fn new(value: $primitive) -> Self {
+                    match Self::try_new(value) {
+                        Ok(int) => int,
+                        Err(err) => panic!("{}", err),
+                    }
+                }
+
+                /// Creates the integer from a value.
+                ///
+                /// Returns an error if the value doesn't fit in the bits of the
+                /// integer.
+                pub const fn try_new(value: $primitive) -> Result<Self, &'static str> {
+                    if value < Self::MIN.0 || value > Self::MAX.0 {
+                        return Err("The value doesn't fit in the bits of the integer.");
+                    }
+
+                    Ok(Self(value))
+                }
+
+                /// Creates the integer from the lowest bits of a value, discarding
+                /// the bits that don't fit.
+                pub const fn new_truncated(value: $primitive) -> Self {
+                    Self(Self::truncate(value))
+                }
+
+                /// Returns the value of the integer.
+                pub const fn value(self) -> $primitive {
+                    self.0
+                }
+
+                /// Adds two integers, returning `None` if the result doesn't fit.
+                pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_add(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Subtracts two integers, returning `None` if the result doesn't
+                /// fit.
+                pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_sub(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Multiplies two integers, returning `None` if the result doesn't
+                /// fit.
+                pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_mul(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Divides two integers, returning `None` if the divisor is zero
+                /// or the result doesn't fit.
+                pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                    match self.0.checked_div(rhs.0) {
+                        Some(value) => Self::checked_new(value),
+                        None => None,
+                    }
+                }
+
+                /// Adds two integers, wrapping around at the bounds of the
+                /// integer.
+                pub const fn wrapping_add(self, rhs: Self) -> Self {
+                    Self::new_truncated(self.0.wrapping_add(rhs.0))
+                }
+
+                /// Subtracts two integers, wrapping around at the bounds of the
+                /// integer.
+                pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                    Self::new_truncated(self.0.wrapping_sub(rhs.0))
+                }
+
+                /// Multiplies two integers, wrapping around at the bounds of the
+                /// integer.
+                pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                    Self::new_truncated(self.0.wrapping_mul(rhs.0))
+                }
+
+                /// Adds two integers, saturating at the bounds of the integer.
+                pub const fn saturating_add(self, rhs: Self) -> Self {
+                    match self.checked_add(rhs) {
+                        Some(int) => int,
+                        None if rhs.0 > 0 => Self::MAX,
+                        None => Self::MIN,
+                    }
+                }
+
+                /// Subtracts two integers, saturating at the bounds of the
+                /// integer.
+                pub const fn saturating_sub(self, rhs: Self) -> Self {
+                    match self.checked_sub(rhs) {
+                        Some(int) => int,
+                        None if rhs.0 > 0 => Self::MIN,
+                        None => Self::MAX,
+                    }
+                }
+
+                const fn checked_new(value: $primitive) -> Option<Self> {
+                    match Self::try_new(value) {
+                        Ok(int) => Some(int),
+                        Err(_) => None,
+                    }
+                }
+            }
+
+            impl<const BITS: u32> BitfieldField for $int<$primitive, BITS> {
+                const BITS: u32 = Self::BITS;
+                type Raw = $raw;
+
+                #[inline]
+                fn from_raw(raw: $raw) -> Self {
+                    Self::new_truncated(raw as $primitive)
+                }
+
+                #[inline]
+                fn into_raw(self) -> $raw {
+                    self.raw_bits()
+                }
+            }
+
+            impl<const BITS: u32> TryFrom<$primitive> for $int<$primitive, BITS> {
+                type Error = &'static str;
+
+                fn try_from(value: $primitive) -> Result<Self, Self::Error> {
+                    Self::try_new(value)
+                }
+            }
+
+            $(
+                impl<const BITS: u32> From<$int<$primitive, BITS>> for $into_primitive {
+                    fn from(int: $int<$primitive, BITS>) -> Self {
+                        int.0.into()
+                    }
+                }
+            )*
+
+            impl_arbitrary_int_op! {
+                $int<$primitive>,
+                Add add AddAssign add_assign checked_add "add",
+                Sub sub SubAssign sub_assign checked_sub "subtract",
+                Mul mul MulAssign mul_assign checked_mul "multiply",
+                Div div DivAssign div_assign checked_div "divide",
+            }
+
+            impl<const BITS: u32> Rem for $int<$primitive, BITS> {
+                type Output = Self;
+
+                fn rem(self, rhs: Self) -> Self {
+                    Self(self.0 % rhs.0)
+                }
+            }
+
+            impl<const BITS: u32> RemAssign for $int<$primitive, BITS> {
+                fn rem_assign(&mut self, rhs: Self) {
+                    *self = *self % rhs;
+                }
+            }
+
+            // The bits of both integers fit, so their bitwise results fit too.
+            impl_arbitrary_int_bit_op! {
+                $int<$primitive>,
+                BitAnd bitand BitAndAssign bitand_assign &,
+                BitOr bitor BitOrAssign bitor_assign |,
+                BitXor bitxor BitXorAssign bitxor_assign ^,
+            }
+
+            impl_arbitrary_int_fmt! {
+                $int<$primitive>,
+                fmt::Debug,
+                fmt::Display,
+                fmt::Binary,
+                fmt::Octal,
+                fmt::LowerHex,
+                fmt::UpperHex,
+            }
+        )*
+    };
+}
+
+macro_rules! impl_arbitrary_int_op {
+    ($int:ident<$primitive:ty>, $(
+        $op:ident $op_fn:ident $op_assign:ident $op_assign_fn:ident $checked_fn:ident $verb:literal,
+    )*) => {
+        $(
+            impl<const BITS: u32> $op for $int<$primitive, BITS> {
+                type Output = Self;
+
+                fn $op_fn(self, rhs: Self) -> Self {
+                    self.$checked_fn(rhs)
+                        .expect(concat!("attempt to ", $verb, " with overflow"))
+                }
+            }
+
+            impl<const BITS: u32> $op_assign for $int<$primitive, BITS> {
+                fn $op_assign_fn(&mut self, rhs: Self) {
+                    *self = $op::$op_fn(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_arbitrary_int_bit_op {
+    ($int:ident<$primitive:ty>, $(
+        $op:ident $op_fn:ident $op_assign:ident $op_assign_fn:ident $operator:tt,
+    )*) => {
+        $(
+            impl<const BITS: u32> $op for $int<$primitive, BITS> {
+                type Output = Self;
+
+                fn $op_fn(self, rhs: Self) -> Self {
+                    Self(self.0 $operator rhs.0)
+                }
+            }
+
+            impl<const BITS: u32> $op_assign for $int<$primitive, BITS> {
+                fn $op_assign_fn(&mut self, rhs: Self) {
+                    self.0 = self.0 $operator rhs.0;
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_arbitrary_int_fmt {
+    ($int:ident<$primitive:ty>, $($fmt_trait:path),* $(,)?) => {
+        $(
+            impl<const BITS: u32> $fmt_trait for $int<$primitive, BITS> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    <$primitive as $fmt_trait>::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_arbitrary_int_unsigned {
+    ($($primitive:ty => [$($into_primitive:ty),*]),* $(,)?) => {
+        impl_arbitrary_int! {
+            $(UInt, $primitive, $primitive, [$($into_primitive),*];)*
+        }
+
+        $(
+            impl<const BITS: u32> UInt<$primitive, BITS> {
+                const fn max_value() -> $primitive {
+                    <$primitive>::MAX >> (<$primitive>::BITS - Self::BITS)
+                }
+
+                const fn min_value() -> $primitive {
+                    0
+                }
+
+                const fn truncate(value: $primitive) -> $primitive {
+                    value & Self::max_value()
+                }
+
+                const fn raw_bits(self) -> $primitive {
+                    self.0
+                }
+            }
+
+            impl<const BITS: u32> Not for UInt<$primitive, BITS> {
+                type Output = Self;
+
+                fn not(self) -> Self {
+                    Self(self.0 ^ Self::MAX.0)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_arbitrary_int_signed {
+    ($($primitive:ty => $raw:ty => [$($into_primitive:ty),*]),* $(,)?) => {
+        impl_arbitrary_int! {
+            $(Int, $primitive, $raw, [$($into_primitive),*];)*
+        }
+
+        $(
+            impl<const BITS: u32> Int<$primitive, BITS> {
+                const fn max_value() -> $primitive {
+                    <$primitive>::MAX >> (<$primitive>::BITS - Self::BITS)
+                }
+
+                const fn min_value() -> $primitive {
+                    <$primitive>::MIN >> (<$primitive>::BITS - Self::BITS)
+                }
+
+                /// Sign extends the lowest bits of the value.
+                const fn truncate(value: $primitive) -> $primitive {
+                    (value << (<$primitive>::BITS - Self::BITS)) >> (<$primitive>::BITS - Self::BITS)
+                }
+
+                /// Returns the bits of the integer without the sign extension.
+                const fn raw_bits(self) -> $raw {
+                    self.0 as $raw & (<$raw>::MAX >> (<$raw>::BITS - Self::BITS))
+                }
+            }
+
+            // Integers are sign extended, so their complement always fits.
+            impl<const BITS: u32> Not for Int<$primitive, BITS> {
+                type Output = Self;
+
+                fn not(self) -> Self {
+                    Self(!self.0)
+                }
+            }
+
+            impl<const BITS: u32> Neg for Int<$primitive, BITS> {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    Self::new(-self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_int_unsigned! {
+    u8 => [u8, u16, u32, u64, u128],
+    u16 => [u16, u32, u64, u128],
+    u32 => [u32, u64, u128],
+    u64 => [u64, u128],
+    u128 => [u128],
+}
+
+impl_arbitrary_int_signed! {
+    i8 => u8 => [i8, i16, i32, i64, i128],
+    i16 => u16 => [i16, i32, i64, i128],
+    i32 => u32 => [i32, i64, i128],
+    i64 => u64 => [i64, i128],
+    i128 => u128 => [i128],
+}
+
+macro_rules! arbitrary_int_aliases {
+    ($int:ident, $primitive:ty, $($alias:ident = $bits:literal),* $(,)?) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[doc = concat!("A ", $bits, " bit ", stringify!($int), " stored in a `", stringify!($primitive), "`.")]
+            pub type $alias = $int<$primitive, $bits>;
+        )*
+    };
+}
+
+arbitrary_int_aliases!(UInt, u8, u1 = 1, u2 = 2, u3 = 3, u4 = 4, u5 = 5, u6 = 6, u7 = 7);
+arbitrary_int_aliases!(
+    UInt,
+    u16,
+    u9 = 9,
+    u10 = 10,
+    u11 = 11,
+    u12 = 12,
+    u13 = 13,
+    u14 = 14,
+    u15 = 15
+);
+arbitrary_int_aliases!(
+    UInt,
+    u32,
+    u17 = 17,
+    u18 = 18,
+    u19 = 19,
+    u20 = 20,
+    u21 = 21,
+    u22 = 22,
+    u23 = 23,
+    u24 = 24,
+    u25 = 25,
+    u26 = 26,
+    u27 = 27,
+    u28 = 28,
+    u29 = 29,
+    u30 = 30,
+    u31 = 31
+);
+arbitrary_int_aliases!(
+    UInt,
+    u64,
+    u33 = 33,
+    u34 = 34,
+    u35 = 35,
+    u36 = 36,
+    u37 = 37,
+    u38 = 38,
+    u39 = 39,
+    u40 = 40,
+    u41 = 41,
+    u42 = 42,
+    u43 = 43,
+    u44 = 44,
+    u45 = 45,
+    u46 = 46,
+    u47 = 47,
+    u48 = 48,
+    u49 = 49,
+    u50 = 50,
+    u51 = 51,
+    u52 = 52,
+    u53 = 53,
+    u54 = 54,
+    u55 = 55,
+    u56 = 56,
+    u57 = 57,
+    u58 = 58,
+    u59 = 59,
+    u60 = 60,
+    u61 = 61,
+    u62 = 62,
+    u63 = 63
+);
+arbitrary_int_aliases!(
+    UInt,
+    u128,
+    u65 = 65,
+    u66 = 66,
+    u67 = 67,
+    u68 = 68,
+    u69 = 69,
+    u70 = 70,
+    u71 = 71,
+    u72 = 72,
+    u73 = 73,
+    u74 = 74,
+    u75 = 75,
+    u76 = 76,
+    u77 = 77,
+    u78 = 78,
+    u79 = 79,
+    u80 = 80,
+    u81 = 81,
+    u82 = 82,
+    u83 = 83,
+    u84 = 84,
+    u85 = 85,
+    u86 = 86,
+    u87 = 87,
+    u88 = 88,
+    u89 = 89,
+    u90 = 90,
+    u91 = 91,
+    u92 = 92,
+    u93 = 93,
+    u94 = 94,
+    u95 = 95,
+    u96 = 96,
+    u97 = 97,
+    u98 = 98,
+    u99 = 99,
+    u100 = 100,
+    u101 = 101,
+    u102 = 102,
+    u103 = 103,
+    u104 = 104,
+    u105 = 105,
+    u106 = 106,
+    u107 = 107,
+    u108 = 108,
+    u109 = 109,
+    u110 = 110,
+    u111 = 111,
+    u112 = 112,
+    u113 = 113,
+    u114 = 114,
+    u115 = 115,
+    u116 = 116,
+    u117 = 117,
+    u118 = 118,
+    u119 = 119,
+    u120 = 120,
+    u121 = 121,
+    u122 = 122,
+    u123 = 123,
+    u124 = 124,
+    u125 = 125,
+    u126 = 126,
+    u127 = 127
+);
+arbitrary_int_aliases!(Int, i8, i1 = 1, i2 = 2, i3 = 3, i4 = 4, i5 = 5, i6 = 6, i7 = 7);
+arbitrary_int_aliases!(
+    Int,
+    i16,
+    i9 = 9,
+    i10 = 10,
+    i11 = 11,
+    i12 = 12,
+    i13 = 13,
+    i14 = 14,
+    i15 = 15
+);
+arbitrary_int_aliases!(
+    Int,
+    i32,
+    i17 = 17,
+    i18 = 18,
+    i19 = 19,
+    i20 = 20,
+    i21 = 21,
+    i22 = 22,
+    i23 = 23,
+    i24 = 24,
+    i25 = 25,
+    i26 = 26,
+    i27 = 27,
+    i28 = 28,
+    i29 = 29,
+    i30 = 30,
+    i31 = 31
+);
+arbitrary_int_aliases!(
+    Int,
+    i64,
+    i33 = 33,
+    i34 = 34,
+    i35 = 35,
+    i36 = 36,
+    i37 = 37,
+    i38 = 38,
+    i39 = 39,
+    i40 = 40,
+    i41 = 41,
+    i42 = 42,
+    i43 = 43,
+    i44 = 44,
+    i45 = 45,
+    i46 = 46,
+    i47 = 47,
+    i48 = 48,
+    i49 = 49,
+    i50 = 50,
+    i51 = 51,
+    i52 = 52,
+    i53 = 53,
+    i54 = 54,
+    i55 = 55,
+    i56 = 56,
+    i57 = 57,
+    i58 = 58,
+    i59 = 59,
+    i60 = 60,
+    i61 = 61,
+    i62 = 62,
+    i63 = 63
+);
+arbitrary_int_aliases!(
+    Int,
+    i128,
+    i65 = 65,
+    i66 = 66,
+    i67 = 67,
+    i68 = 68,
+    i69 = 69,
+    i70 = 70,
+    i71 = 71,
+    i72 = 72,
+    i73 = 73,
+    i74 = 74,
+    i75 = 75,
+    i76 = 76,
+    i77 = 77,
+    i78 = 78,
+    i79 = 79,
+    i80 = 80,
+    i81 = 81,
+    i82 = 82,
+    i83 = 83,
+    i84 = 84,
+    i85 = 85,
+    i86 = 86,
+    i87 = 87,
+    i88 = 88,
+    i89 = 89,
+    i90 = 90,
+    i91 = 91,
+    i92 = 92,
+    i93 = 93,
+    i94 = 94,
+    i95 = 95,
+    i96 = 96,
+    i97 = 97,
+    i98 = 98,
+    i99 = 99,
+    i100 = 100,
+    i101 = 101,
+    i102 = 102,
+    i103 = 103,
+    i104 = 104,
+    i105 = 105,
+    i106 = 106,
+    i107 = 107,
+    i108 = 108,
+    i109 = 109,
+    i110 = 110,
+    i111 = 111,
+    i112 = 112,
+    i113 = 113,
+    i114 = 114,
+    i115 = 115,
+    i116 = 116,
+    i117 = 117,
+    i118 = 118,
+    i119 = 119,
+    i120 = 120,
+    i121 = 121,
+    i122 = 122,
+    i123 = 123,
+    i124 = 124,
+    i125 = 125,
+    i126 = 126,
+    i127 = 127
+);
@@ -0,0 +1,189 @@
+//! A fixed-length ASCII string, used by string fields embedding fixed-width
+//! text (e.g. the title of a ROM header).
+
+use core::fmt;
+
+use crate::field::BitfieldField;
+
+/// A fixed-length ASCII string of `N` bytes, padded with trailing NUL bytes.
+///
+/// Strings created from a `&str` are always ASCII, while strings read from
+/// field bits keep the bytes as is, so [`as_str`](Self::as_str) returns an
+/// error if they aren't ASCII.
+///
+/// String fields are stored as `N * 8` bits with the first byte in the lowest
+/// bits, the same as `[u8; N]` array fields. Integer-backed bitfields hold
+/// strings of up to 16 bytes, while array-backed bitfields hold strings of any
+/// length that fits the bitfield.
+///
+/// ```rust
+/// use bitfields::{bitfield, AsciiStr};
+///
+/// #[bitfield(u128)]
+/// struct RomHeader {
+///     #[bits(96, default = "NONAME")]
+///     title: AsciiStr<12>,
+///     #[bits(32)]
+///     maker_code: u32,
+/// }
+///
+/// let mut header = RomHeader::new();
+/// assert_eq!(header.title().as_str(), Ok("NONAME"));
+///
+/// header.set_title(AsciiStr::new("ZELDA").unwrap());
+/// assert_eq!(header.title(), "ZELDA");
+/// assert_eq!(header.title().as_bytes(), b"ZELDA\0\0\0\0\0\0\0");
+///
+/// assert!(AsciiStr::<12>::new("ÉPÉE").is_err());
+/// assert!(AsciiStr::<12>::new("THE LEGEND OF ZELDA").is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiStr<const N: usize>([u8; N]);
+
+impl<const N: usize> AsciiStr<N> {
+    /// Creates an ASCII string from a string slice, padding it with NUL bytes.
+    ///
+    /// Returns an error if the string isn't ASCII or is longer than `N` bytes.
+    pub const fn new(s: &str) -> Result<Self, &'static str> {
+        let bytes = s.as_bytes();
+        if bytes.len() > N {
+            return Err("The string is longer than the ASCII string length.");
+        }
+
+        let mut padded_bytes = [0; N];
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii() {
+                return Err("The string contains non-ASCII characters.");
+            }
+            padded_bytes[i] = bytes[i];
+            i += 1;
+        }
+
+        Ok(Self(padded_bytes))
+    }
+
+    /// Creates an ASCII string from a string slice, padding it with NUL bytes.
+    ///
+    /// Panics if the string isn't ASCII or is longer than `N` bytes, which
+    /// fails compilation in const contexts.
+    pub const fn from_ascii(s: &str) -> Self {
+        match Self::new(s) {
+            Ok(ascii_str) => ascii_str,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates an ASCII string from its bytes, including the padding.
+    ///
+    /// Returns an error if the bytes aren't ASCII.
+    pub const fn from_bytes(bytes: [u8; N]) -> Result<Self, &'static str> {
+        if !bytes.as_slice().is_ascii() {
+            return Err("The bytes contain non-ASCII characters.");
+        }
+
+        Ok(Self(bytes))
+    }
+
+    /// Creates an ASCII string from its bytes as is, including the padding,
+    /// which may not be ASCII (e.g. the bytes of a field).
+    pub const fn from_raw_bytes(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns if the bytes of the string are ASCII.
+    pub const fn is_ascii(&self) -> bool {
+        self.0.as_slice().is_ascii()
+    }
+
+    /// Returns the bytes of the string, including the padding.
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the bytes of the string, including the padding.
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns the bytes of the string without the trailing NUL bytes.
+    pub const fn trimmed_bytes(&self) -> &[u8] {
+        let mut length = N;
+        while length > 0 && self.0[length - 1] == 0 {
+            length -= 1;
+        }
+        self.0.split_at(length).0
+    }
+
+    /// Returns the string without the trailing NUL bytes.
+    ///
+    /// Returns an error if the bytes aren't ASCII.
+    pub const fn as_str(&self) -> Result<&str, &'static str> {
+        if !self.is_ascii() {
+            return Err("The bytes contain non-ASCII characters.");
+        }
+
+        match core::str::from_utf8(self.trimmed_bytes()) {
+            Ok(s) => Ok(s),
+            Err(_) => Err("The bytes contain non-ASCII characters."),
+        }
+    }
+}
+
+impl<const N: usize> Default for AsciiStr<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for AsciiStr<N> {
+    type Error = &'static str;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> PartialEq<str> for AsciiStr<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.trimmed_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for AsciiStr<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.trimmed_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> fmt::Debug for AsciiStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.trimmed_bytes().escape_ascii())
+    }
+}
+
+impl<const N: usize> BitfieldField for AsciiStr<N> {
+    const BITS: u32 = {
+        assert!(N > 0, "ASCII string fields must have at least 1 byte.");
+        N as u32 * 8
+    };
+    type Raw = u128;
+
+    /// The bytes are kept as is, and bytes past the 16 bytes of the raw bits
+    /// are NUL bytes.
+    fn from_raw(raw: u128) -> Self {
+        let mut bytes = [0; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = raw.checked_shr(i as u32 * 8).unwrap_or(0) as u8;
+        }
+        Self(bytes)
+    }
+
+    /// Bytes past the 16 bytes of the raw bits are dropped.
+    fn into_raw(self) -> u128 {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(0, |raw, (i, &byte)| raw | (byte as u128).checked_shl(i as u32 * 8).unwrap_or(0))
+    }
+}
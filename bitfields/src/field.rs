@@ -0,0 +1,187 @@
+/// A type that can be stored in a bitfield field.
+///
+/// Custom field types convert to and from a primitive [`Raw`] representation
+/// which the bitfield packs into the field bits. Types annotated with
+/// `#[bitfield]` (integer backed) and `#[bitflag]` implement this trait
+/// automatically.
+///
+/// Trait methods can't be called from const functions, so the accessors of
+/// fields converted through this trait, and the functions converting all fields
+/// (e.g. `new` and `from_bits`), aren't `const`.
+///
+/// ```rust
+/// use bitfields::{bitfield, BitfieldField};
+///
+/// #[bitfield(u8)]
+/// struct Player {
+///     #[bits(4)]
+///     health: Health,
+///     #[bits(4)]
+///     _reserved: u8,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Health(u8);
+///
+/// impl BitfieldField for Health {
+///     const BITS: u32 = 4;
+///     type Raw = u8;
+///
+///     fn from_raw(raw: u8) -> Self {
+///         Self(raw)
+///     }
+///
+///     fn into_raw(self) -> u8 {
+///         self.0
+///     }
+/// }
+///
+/// let mut player = Player::new();
+/// player.set_health(Health(9));
+/// assert_eq!(player.health(), Health(9));
+/// ```
+///
+/// [`Raw`]: BitfieldField::Raw
+pub trait BitfieldField: Sized {
+    /// The number of bits the type occupies.
+    const BITS: u32;
+
+    /// The primitive representation of the type.
+    type Raw: BitfieldRaw;
+
+    /// Creates the type from the field bits.
+    fn from_raw(raw: Self::Raw) -> Self;
+
+    /// Returns the field bits of the type.
+    fn into_raw(self) -> Self::Raw;
+}
+
+/// A primitive type that can be the [`BitfieldField::Raw`] representation of
+/// a field type.
+pub trait BitfieldRaw: Copy {
+    /// Whether the primitive is signed. Fields of types with a signed raw
+    /// representation must match the bits of the type, as wider fields
+    /// wouldn't carry the sign in their top bit.
+    const SIGNED: bool;
+
+    /// Creates the primitive from bits, truncating the bits that don't fit.
+    fn from_u128(bits: u128) -> Self;
+
+    /// Returns the bits of the primitive, zero extended to a `u128`.
+    fn into_u128(self) -> u128;
+}
+
+macro_rules! impl_bitfield_raw {
+    ($($unsigned_type:ty => $signed_type:ty),* $(,)?) => {
+        $(
+            impl BitfieldRaw for $unsigned_type {
+                const SIGNED: bool = false;
+
+                #[inline]
+                fn from_u128(bits: u128) -> Self {
+                    bits as $unsigned_type
+                }
+
+                #[inline]
+                fn into_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+
+            impl BitfieldRaw for $signed_type {
+                const SIGNED: bool = true;
+
+                #[inline]
+                fn from_u128(bits: u128) -> Self {
+                    bits as $unsigned_type as $signed_type
+                }
+
+                #[inline]
+                fn into_u128(self) -> u128 {
+                    self as $unsigned_type as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_bitfield_raw! {
+    u8 => i8,
+    u16 => i16,
+    u32 => i32,
+    u64 => i64,
+    u128 => i128,
+    usize => isize,
+}
+
+impl BitfieldRaw for bool {
+    const SIGNED: bool = false;
+
+    #[inline]
+    fn from_u128(bits: u128) -> Self {
+        bits & 1 != 0
+    }
+
+    #[inline]
+    fn into_u128(self) -> u128 {
+        self as u128
+    }
+}
+
+macro_rules! impl_bitfield_field_float {
+    ($($float_type:ty => $raw_type:ty),* $(,)?) => {
+        $(
+            impl BitfieldField for $float_type {
+                const BITS: u32 = <$raw_type>::BITS;
+                type Raw = $raw_type;
+
+                #[inline]
+                fn from_raw(raw: $raw_type) -> Self {
+                    <$float_type>::from_bits(raw)
+                }
+
+                #[inline]
+                fn into_raw(self) -> $raw_type {
+                    self.to_bits()
+                }
+            }
+        )*
+    };
+}
+
+// Floats are stored as their IEEE 754 bits.
+impl_bitfield_field_float! {
+    f32 => u32,
+    f64 => u64,
+}
+
+macro_rules! impl_bitfield_field_non_zero {
+    ($($non_zero_type:ty => $raw_type:ty),* $(,)?) => {
+        $(
+            impl BitfieldField for $non_zero_type {
+                const BITS: u32 = <$raw_type>::BITS;
+                type Raw = $raw_type;
+
+                /// Panics if the bits are zero.
+                #[inline]
+                fn from_raw(raw: $raw_type) -> Self {
+                    <$non_zero_type>::new(raw).expect("The field bits are zero.")
+                }
+
+                #[inline]
+                fn into_raw(self) -> $raw_type {
+                    self.get()
+                }
+            }
+        )*
+    };
+}
+
+// Non-zero integers are stored as their integer bits, zero bits have no value.
+impl_bitfield_field_non_zero! {
+    core::num::NonZeroU8 => u8,
+    core::num::NonZeroU16 => u16,
+    core::num::NonZeroU32 => u32,
+    core::num::NonZeroU64 => u64,
+    core::num::NonZeroU128 => u128,
+}
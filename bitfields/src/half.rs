@@ -0,0 +1,88 @@
+//! Conversions between `f32` and the bits of an IEEE 754 half-precision float,
+//! used by half-precision float fields.
+
+/// Converts an `f32` into the bits of the nearest half-precision float,
+/// rounding ties to even.
+///
+/// Values too large for a half-precision float become infinity and values too
+/// small become zero, keeping their sign.
+///
+/// ```rust
+/// use bitfields::half::f32_to_f16_bits;
+///
+/// assert_eq!(f32_to_f16_bits(1.5), 0x3E00);
+/// assert_eq!(f32_to_f16_bits(-2.0), 0xC000);
+/// assert_eq!(f32_to_f16_bits(f32::INFINITY), 0x7C00);
+/// ```
+pub const fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xFF) as i32;
+    let mantissa = bits & 0x007F_FFFF;
+
+    // Infinity and NaN, keeping NaN a NaN.
+    if exponent == 0xFF {
+        let nan_bit = if mantissa != 0 { 0x0200 } else { 0 };
+        return sign | 0x7C00 | nan_bit | (mantissa >> 13) as u16;
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1F {
+        return sign | 0x7C00;
+    }
+
+    // Subnormal half-precision floats, the implicit bit becomes explicit.
+    if half_exponent <= 0 {
+        if half_exponent < -10 {
+            return sign;
+        }
+
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let half_mantissa = mantissa >> shift;
+        let round_bit = 1 << (shift - 1);
+        let remainder = mantissa & ((round_bit << 1) - 1);
+        let rounds_up =
+            remainder > round_bit || (remainder == round_bit && (half_mantissa & 1) != 0);
+        return sign | (half_mantissa + rounds_up as u32) as u16;
+    }
+
+    let half_mantissa = mantissa >> 13;
+    let remainder = mantissa & 0x1FFF;
+    let rounds_up = remainder > 0x1000 || (remainder == 0x1000 && (half_mantissa & 1) != 0);
+    // A carry out of the mantissa correctly increments the exponent.
+    let half_bits = ((half_exponent as u32) << 10 | half_mantissa) + rounds_up as u32;
+    sign | half_bits as u16
+}
+
+/// Converts the bits of a half-precision float into an `f32`, which is
+/// lossless.
+///
+/// ```rust
+/// use bitfields::half::f16_bits_to_f32;
+///
+/// assert_eq!(f16_bits_to_f32(0x3E00), 1.5);
+/// assert_eq!(f16_bits_to_f32(0xC000), -2.0);
+/// assert!(f16_bits_to_f32(0x7E00).is_nan());
+/// ```
+pub const fn f16_bits_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1F) as u32;
+    let mantissa = (bits & 0x03FF) as u32;
+
+    let float_bits = if exponent == 0x1F {
+        sign | 0x7F80_0000 | (mantissa << 13)
+    } else if exponent != 0 {
+        sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+    } else if mantissa == 0 {
+        sign
+    } else {
+        // Subnormal half-precision floats are normal `f32`s.
+        let shift = mantissa.leading_zeros() - 21;
+        let mantissa = (mantissa << shift) & 0x03FF;
+        let exponent = 127 - 15 + 1 - shift;
+        sign | (exponent << 23) | (mantissa << 13)
+    };
+
+    f32::from_bits(float_bits)
+}
@@ -1,7 +1,15 @@
 #![no_std]
 #![warn(incomplete_features)]
 
+mod arbitrary_int;
+mod ascii_str;
+mod field;
+pub mod half;
+
+pub use arbitrary_int::*;
+pub use ascii_str::AsciiStr;
 /// Creates a bitfield from the attributed struct.
 pub use bitfields_impl::bitfield;
 /// Creates a bitfield from the attributed enum.
 pub use bitfields_impl::bitflag;
+pub use field::{BitfieldField, BitfieldRaw};
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(24)]
+    a: [u16; 2],
+    #[bits(8)]
+    _reserved: u8,
+}
+
+fn main() {}
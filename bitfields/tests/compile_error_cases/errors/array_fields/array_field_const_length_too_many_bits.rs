@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+const LEN: usize = 4;
+
+/// Array field bits exceed the integer-backed bitfield size.
+#[bitfield(u16)]
+pub struct Bitfield {
+    a: [u8; LEN],
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use bitfields::bitfield;
+
+const LEN: usize = 2;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(16)]
+    a: [u8; LEN],
+    b: u16,
+}
+
+fn main() {}
@@ -2,8 +2,7 @@ use bitfields::bitfield;
 
 #[bitfield(u32)]
 pub struct Bitfield {
-    a: [u16; 2],
+    a: [[u8; 2]; 2],
 }
 
 fn main() {}
-
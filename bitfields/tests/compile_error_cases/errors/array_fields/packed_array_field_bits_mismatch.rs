@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(6, element = 2)]
+    a: [u8; 4],
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+const LEN: usize = 4;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(element = 4)]
+    a: [u8; LEN],
+}
+
+fn main() {}
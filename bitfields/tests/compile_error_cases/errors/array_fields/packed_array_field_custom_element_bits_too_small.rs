@@ -0,0 +1,17 @@
+use bitfields::{bitfield, bitflag};
+
+#[bitflag(u8)]
+#[derive(Debug)]
+enum Mode {
+    #[base]
+    Off = 0,
+    Fast = 4,
+}
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(element = 2)]
+    modes: [Mode; 4],
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use bitfields::{bitfield, bitflag};
+
+#[bitflag(u8)]
+enum Mode {
+    #[base]
+    Off = 0,
+    On = 1,
+}
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(8)]
+    modes: [Mode; 8],
+}
+
+fn main() {}
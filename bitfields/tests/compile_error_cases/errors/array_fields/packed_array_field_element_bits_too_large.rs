@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(element = 9)]
+    a: [u8; 2],
+    #[bits(14)]
+    b: u16,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u64)]
+pub struct Bitfield {
+    #[bits(element = 17)]
+    a: [u16; 2],
+    #[bits(30)]
+    _reserved: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(element = 2)]
+    a: u8,
+}
+
+fn main() {}
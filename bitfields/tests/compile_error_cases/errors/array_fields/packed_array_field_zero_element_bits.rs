@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(element = 0)]
+    a: [u8; 4],
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+const SIZE: usize = 2;
+
+#[bitfield([u8; SIZE])]
+pub struct Bitfield {
+    #[bits(8, at = 8)]
+    a: u8,
+    #[bits(8, at = 0)]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+const SIZE: usize = 2;
+
+#[bitfield([u8; SIZE], fill = reserved)]
+pub struct Bitfield {
+    a: u8,
+}
+
+fn main() {}
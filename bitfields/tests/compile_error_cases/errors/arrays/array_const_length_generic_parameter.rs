@@ -0,0 +1,8 @@
+use bitfields::bitfield;
+
+#[bitfield([u8; N])]
+pub struct Bitfield<const N: usize> {
+    a: u16,
+}
+
+fn main() {}
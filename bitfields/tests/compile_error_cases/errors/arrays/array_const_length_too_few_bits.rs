@@ -4,8 +4,7 @@ const SIZE: usize = 4;
 
 #[bitfield([u8; SIZE])]
 pub struct Bitfield {
-    a: u32,
+    a: u16,
 }
 
 fn main() {}
-
@@ -1,6 +1,6 @@
 use bitfields::bitfield;
 
-#[bitfield([u16; 4])]
+#[bitfield([u128; 4])]
 pub struct Bitfield {
     a: u64,
 }
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    ax: u16,
+    #[bits(alias_of = ax, 12..=19)]
+    ah: u8,
+    bx: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    ax: u16,
+    #[bits(8, alias_of = bx)]
+    bl: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Sprite {
+    #[bits(5)]
+    palette: bitfields::u4,
+    #[bits(3)]
+    _reserved: u8,
+}
+
+fn main() {}
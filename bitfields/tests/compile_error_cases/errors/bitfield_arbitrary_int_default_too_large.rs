@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Sprite {
+    #[bits(default = 16)]
+    palette: bitfields::u4,
+    #[bits(default = -9)]
+    velocity: bitfields::i4,
+}
+
+fn main() {}
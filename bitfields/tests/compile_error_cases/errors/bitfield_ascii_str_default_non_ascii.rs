@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(u64)]
+pub struct DeviceName {
+    #[bits(64, default = "ÉCRAN")]
+    name: bitfields::AsciiStr<8>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(6, encoding = bcd)]
+    a: u8,
+    #[bits(2)]
+    b: u8,
+}
+
+fn main() {}
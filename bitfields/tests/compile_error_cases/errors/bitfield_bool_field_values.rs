@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(values = [1])]
+    a: bool,
+    #[bits(7)]
+    b: u8,
+}
+
+fn main() {}
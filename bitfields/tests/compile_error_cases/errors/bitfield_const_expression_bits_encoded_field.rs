@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+const LEVEL_BITS: u32 = 4;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(LEVEL_BITS, encoding = gray)]
+    level: u8,
+    #[bits(4)]
+    flags: u8,
+}
+
+fn main() {}
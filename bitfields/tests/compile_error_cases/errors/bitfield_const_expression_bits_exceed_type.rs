@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+const ADDR_BITS: u32 = 12;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(ADDR_BITS)]
+    addr: u8,
+    #[bits(4)]
+    flags: u8,
+}
+
+fn main() {}
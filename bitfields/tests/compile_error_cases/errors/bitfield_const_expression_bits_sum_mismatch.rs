@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+const ADDR_BITS: u32 = 20;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(ADDR_BITS)]
+    addr: u32,
+    #[bits(8)]
+    flags: u8,
+}
+
+fn main() {}
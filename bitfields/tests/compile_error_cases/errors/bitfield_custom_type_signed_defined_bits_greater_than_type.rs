@@ -0,0 +1,25 @@
+use bitfields::{bitfield, BitfieldField};
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(8)]
+    offset: Offset,
+    b: u8,
+}
+
+pub struct Offset(i8);
+
+impl BitfieldField for Offset {
+    const BITS: u32 = 4;
+    type Raw = i8;
+
+    fn from_raw(raw: i8) -> Self {
+        Self((raw << 4) >> 4)
+    }
+
+    fn into_raw(self) -> i8 {
+        self.0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    a: u8,
+    #[bits(8)]
+    b: CustomType,
+}
+
+pub struct CustomType(u8);
+
+impl CustomType {
+    const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    const fn into_bits(self) -> u8 {
+        self.0
+    }
+}
+
+fn main() {}
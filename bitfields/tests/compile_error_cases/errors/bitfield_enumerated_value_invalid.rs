@@ -0,0 +1,37 @@
+use bitfields::bitfield;
+
+const GAME: u8 = 3;
+
+#[bitfield(u8)]
+pub struct DisplayControl {
+    #[bits(2, values(Picture = 0, Game = GAME))]
+    bg_mode: BgMode,
+    #[bits(6)]
+    _reserved: u8,
+}
+
+#[bitfield(u8)]
+pub struct DuplicateValues {
+    #[bits(2, values(Picture = 0, Video = 0))]
+    bg_mode: BgMode,
+    #[bits(6)]
+    _reserved: u8,
+}
+
+#[bitfield(u8)]
+pub struct WithoutBits {
+    #[bits(values(Picture = 0, Video = 1))]
+    bg_mode: BgMode,
+    #[bits(7)]
+    _reserved: u8,
+}
+
+#[bitfield(u8)]
+pub struct NonEnumType {
+    #[bits(2, values(Picture = 0, Video = 1))]
+    bg_mode: u8,
+    #[bits(6)]
+    _reserved: u8,
+}
+
+fn main() {}
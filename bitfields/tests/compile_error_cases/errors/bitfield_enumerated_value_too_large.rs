@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct DisplayControl {
+    #[bits(2, values(Picture = 0, Video = 1, Sleep = 2, Game = 4))]
+    bg_mode: BgMode,
+    #[bits(6)]
+    _reserved: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+const START: u32 = 4;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(0..=3)]
+    a: u8,
+    #[bits(START..=7)]
+    b: u8,
+}
+
+fn main() {}
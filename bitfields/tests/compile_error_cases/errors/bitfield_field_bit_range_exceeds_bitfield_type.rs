@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(0..=7)]
+    a: u8,
+    #[bits(12..=19)]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(4..=9)]
+    a: u8,
+    #[bits(8..=15)]
+    b: u8,
+}
+
+fn main() {}
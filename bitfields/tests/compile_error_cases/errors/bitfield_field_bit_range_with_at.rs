@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(0..=7, at = 0)]
+    a: u8,
+    b: u8,
+}
+
+fn main() {}
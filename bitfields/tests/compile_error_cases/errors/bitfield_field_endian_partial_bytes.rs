@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(12, endian = little)]
+    a: u16,
+    #[bits(4)]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(12)]
+    a: u16,
+    #[bits(4)]
+    nested_field: NestedBitfield,
+}
+
+#[bitfield(u8)]
+pub struct NestedBitfield {
+    #[bits(4)]
+    a: u8,
+    #[bits(4)]
+    b: u8,
+}
+
+fn main() {}
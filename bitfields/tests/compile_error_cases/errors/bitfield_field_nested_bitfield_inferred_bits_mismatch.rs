@@ -8,11 +8,11 @@ pub struct Bitfield {
     nested_field: NestedBitfield,
 }
 
-#[bitfield(u8)]
+#[bitfield(u16)]
 pub struct NestedBitfield {
-    #[bits(4, default = 0x3)]
+    #[bits(8, default = 0x3)]
     a: u8,
-    #[bits(4, default = 0x4)]
+    #[bits(8, default = 0x4)]
     b: u16,
 }
 
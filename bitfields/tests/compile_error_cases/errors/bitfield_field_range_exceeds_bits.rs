@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(4, range = 1..=16)]
+    a: u8,
+    #[bits(4)]
+    b: u8,
+}
+
+fn main() {}
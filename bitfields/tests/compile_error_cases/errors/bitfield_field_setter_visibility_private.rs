@@ -0,0 +1,16 @@
+mod driver {
+    use bitfields::bitfield;
+
+    #[bitfield(u8)]
+    pub struct Bitfield {
+        #[bits(get = pub, set = pub(self))]
+        a: u8,
+    }
+}
+
+fn main() {
+    let mut bitfield = driver::Bitfield::new();
+    let _ = bitfield.a();
+    bitfield.set_a(0x12);
+    let _ = driver::BitfieldBuilder::new().with_a(0x12).build();
+}
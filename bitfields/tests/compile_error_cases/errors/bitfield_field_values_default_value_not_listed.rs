@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(5, values = [4, 8, 16], default = 5)]
+    a: u8,
+    #[bits(3)]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(get = pub(in crate::registers))]
+    a: u8,
+}
+
+fn main() {}
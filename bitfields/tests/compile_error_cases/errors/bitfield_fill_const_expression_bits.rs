@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+const A_BITS: u32 = 4;
+
+#[bitfield(u32, fill = reserved)]
+pub struct Bitfield {
+    #[bits(A_BITS)]
+    a: u8,
+}
+
+fn main() {}
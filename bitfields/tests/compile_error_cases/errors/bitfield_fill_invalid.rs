@@ -0,0 +1,8 @@
+use bitfields::bitfield;
+
+#[bitfield(u32, fill = zeros)]
+pub struct Bitfield {
+    a: u8,
+}
+
+fn main() {}
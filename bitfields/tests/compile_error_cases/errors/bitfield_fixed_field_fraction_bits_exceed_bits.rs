@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(12, fixed = 13)]
+    a: u16,
+    #[bits(20)]
+    b: u32,
+}
+
+fn main() {}
@@ -2,6 +2,7 @@ use bitfields::bitfield;
 
 #[bitfield(u32)]
 pub struct Bitfield {
+    #[bits(default = 1)]
     a: f32,
 }
 
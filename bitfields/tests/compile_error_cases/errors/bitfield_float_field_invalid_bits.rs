@@ -2,8 +2,9 @@ use bitfields::bitfield;
 
 #[bitfield(u32)]
 pub struct Bitfield {
-    a: [i8; 4],
+    #[bits(24)]
+    a: f32,
+    b: u8,
 }
 
 fn main() {}
-
@@ -0,0 +1,8 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield<T: bitfields::BitfieldField> {
+    kind: T,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield<'a> {
+    a: u8,
+}
+
+fn main() {}
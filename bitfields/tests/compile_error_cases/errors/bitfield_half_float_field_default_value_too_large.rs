@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(half = true, default = 70000.0)]
+    a: f32,
+    b: u16,
+}
+
+fn main() {}
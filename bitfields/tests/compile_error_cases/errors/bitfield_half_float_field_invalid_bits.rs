@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(24, half = true)]
+    a: f32,
+    b: u8,
+}
+
+fn main() {}
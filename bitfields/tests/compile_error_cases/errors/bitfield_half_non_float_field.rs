@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(16, half = true)]
+    a: u16,
+    b: u16,
+}
+
+fn main() {}
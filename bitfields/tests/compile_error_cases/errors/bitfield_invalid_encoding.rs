@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(4, encoding = excess_3)]
+    a: u8,
+    #[bits(4)]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use bitfields::bitfield;
+
+#[bitfield(u32, assert(mode.width = 8))]
+pub struct Register {
+    mode: u8,
+    #[bits(24)]
+    _reserved: u32,
+}
+
+#[bitfield(u32, assert(mode == 0))]
+pub struct OtherRegister {
+    mode: u8,
+    #[bits(24)]
+    _reserved: u32,
+}
+
+#[bitfield(u32, assert = true)]
+pub struct AnotherRegister {
+    mode: u8,
+    #[bits(24)]
+    _reserved: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+#[bitfield(u32, assert(mode.offset = 0, enable.offset = 8, crc.bits = 8))]
+pub struct Register {
+    #[bits(7)]
+    mode: u8,
+    enable: bool,
+    crc: u8,
+    #[bits(16)]
+    _reserved: u16,
+}
+
+fn main() {}
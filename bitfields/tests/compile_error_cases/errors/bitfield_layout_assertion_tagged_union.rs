@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+#[bitfield(u32, tag_bits = 8, assert(a.offset = 8))]
+pub enum Packet {
+    #[tag = 0]
+    Data {
+        a: u8,
+        #[bits(16)]
+        _reserved: u16,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bitfields::bitfield;
+
+#[bitfield(u32, assert(mode.offset = 0, status.bits = 8))]
+pub struct Register {
+    mode: u8,
+    #[bits(24)]
+    _reserved: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+#[bitfield(u16, order = msb)]
+pub struct Bitfield {
+    #[bits(0..=7)]
+    a: u8,
+    #[bits(4)]
+    next: u8,
+    #[bits(4)]
+    _reserved: u8,
+}
+
+fn main() {}
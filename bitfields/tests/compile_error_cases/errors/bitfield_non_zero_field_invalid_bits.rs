@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(16)]
+    a: core::num::NonZeroU8,
+}
+
+fn main() {}
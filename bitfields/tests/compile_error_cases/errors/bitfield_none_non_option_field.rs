@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(8, none = 0xFF)]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(4, none = 0xFF)]
+    a: Option<u8>,
+    #[bits(4)]
+    b: u8,
+}
+
+fn main() {}
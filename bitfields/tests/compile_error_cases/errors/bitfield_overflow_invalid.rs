@@ -0,0 +1,8 @@
+use bitfields::bitfield;
+
+#[bitfield(u8, overflow = wrap)]
+pub struct Bitfield {
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(1, overflow = saturate)]
+    a: bool,
+    #[bits(7)]
+    b: u8,
+}
+
+fn main() {}
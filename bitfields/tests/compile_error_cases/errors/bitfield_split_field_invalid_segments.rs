@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u8)]
+pub struct Bitfield {
+    #[bits(segments = [7, b])]
+    a: u8,
+    #[bits(6, at = 1)]
+    b: u8,
+}
+
+fn main() {}
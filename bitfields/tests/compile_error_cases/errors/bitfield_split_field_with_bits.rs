@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub struct Bitfield {
+    #[bits(8, segments = [15..=12, 3..=0])]
+    a: u8,
+    #[bits(8, at = 4)]
+    b: u8,
+}
+
+fn main() {}
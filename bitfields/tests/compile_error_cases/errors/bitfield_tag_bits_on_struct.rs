@@ -0,0 +1,8 @@
+use bitfields::bitfield;
+
+#[bitfield(u16, tag_bits = 4)]
+pub struct Frame {
+    a: u16,
+}
+
+fn main() {}
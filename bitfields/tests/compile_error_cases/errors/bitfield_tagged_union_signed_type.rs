@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(i16, tag_bits = 4)]
+pub enum Frame {
+    #[tag = 1]
+    Data { a: u8 },
+}
+
+fn main() {}
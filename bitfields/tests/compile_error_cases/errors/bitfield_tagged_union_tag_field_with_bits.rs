@@ -0,0 +1,14 @@
+use bitfields::bitfield;
+
+#[bitfield(u16, tag_bits = 4)]
+pub enum Frame {
+    #[tag = 1]
+    Data {
+        #[bits(12)]
+        a: u16,
+        #[bits(4)]
+        tag: u8,
+    },
+}
+
+fn main() {}
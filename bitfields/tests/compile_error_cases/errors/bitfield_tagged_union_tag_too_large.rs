@@ -0,0 +1,13 @@
+use bitfields::bitfield;
+
+#[bitfield(u16, tag_bits = 4)]
+pub enum Frame {
+    #[tag = 16]
+    Data {
+        a: u8,
+        #[bits(4)]
+        b: u8,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use bitfields::bitfield;
+
+#[bitfield(u16, tag_bits = 4)]
+pub enum Frame {
+    #[tag = 1]
+    Data {
+        a: u8,
+        #[bits(4)]
+        b: u8,
+    },
+    Ack {
+        #[bits(12)]
+        sequence: u16,
+    },
+}
+
+fn main() {}
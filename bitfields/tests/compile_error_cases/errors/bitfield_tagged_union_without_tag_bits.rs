@@ -0,0 +1,9 @@
+use bitfields::bitfield;
+
+#[bitfield(u16)]
+pub enum Frame {
+    #[tag = 1]
+    Data { a: u8 },
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bitfields::bitfield;
+
+#[bitfield(u32)]
+pub struct Bitfield {
+    #[bits(10, unit = "C")]
+    a: u16,
+    #[bits(22)]
+    b: u32,
+}
+
+fn main() {}
@@ -1,6 +1,6 @@
 use bitfields::bitfield;
 
-#[bitfield(i32)]
+#[bitfield(char)]
 pub struct Bitfield {
     a: u8,
     b: u8,
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
 
+    use bitfields::BitfieldField;
     use bitfields::bitfield;
     use bitfields::bitflag;
 
@@ -656,8 +657,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -665,7 +669,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -701,15 +705,20 @@ mod tests {
                     b: 4,
                 }
             }
+        }
 
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self {
                     a: bits as i8,
                     b: bits,
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self.b
             }
         }
@@ -742,15 +751,20 @@ mod tests {
                     b: 4,
                 }
             }
+        }
 
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self {
                     a: bits as i8,
                     b: bits,
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self.b
             }
         }
@@ -780,8 +794,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -789,7 +806,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -825,8 +842,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -834,7 +854,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -870,8 +890,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -879,7 +902,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -1154,6 +1177,83 @@ mod tests {
         assert_eq!(bitfield.into_bytes(), [0x78, 0x56, 0x34, 0x12]);
     }
 
+    #[test]
+    fn bitfield_alias_fields() {
+        #[bitfield([u8; 4])]
+        pub struct Registers {
+            bc: u16,
+            #[bits(8, alias_of = bc)]
+            c: u8,
+            #[bits(alias_of = bc, 8..=15)]
+            b: u8,
+            de: u16,
+        }
+
+        let mut registers = Registers::new();
+        registers.set_bc(0x1234);
+        assert_eq!(registers.c(), 0x34);
+        assert_eq!(registers.b(), 0x12);
+
+        registers.set_b(0xAB);
+        registers.set_de(0xFFFF);
+        assert_eq!(registers.bc(), 0xAB34);
+        assert_eq!(registers.into_le_bytes(), [0x34, 0xAB, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn bitfield_split_fields() {
+        #[bitfield([u8; 4])]
+        pub struct Bitfield {
+            #[bits(segments = [31..=28, 3..=0], shift = 2)]
+            a: i16,
+            #[bits(4..=27)]
+            b: u32,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_a(-4);
+        bitfield.set_b(0xFF_FFFF);
+        assert_eq!(bitfield.a(), -4);
+        assert_eq!(bitfield.into_le_bytes(), [0xFF, 0xFF, 0xFF, 0xFF]);
+
+        bitfield.set_a(0x54);
+        assert_eq!(bitfield.a(), 0x54);
+        assert_eq!(bitfield.into_le_bytes(), [0xF5, 0xFF, 0xFF, 0x1F]);
+        assert!(bitfield.checked_set_a(0x56).is_err());
+        assert_eq!(bitfield.a(), 0x54);
+    }
+
+    #[test]
+    fn bitfield_explicit_bit_positions() {
+        #[bitfield([u8; 20])]
+        pub struct Bitfield {
+            #[bits(152..=159, default = 0x12)]
+            a: u8,
+            #[bits(0..=3, default = 0x3)]
+            b: u8,
+            #[bits(at = 8, default = 0x45)]
+            c: u8,
+        }
+
+        assert_eq!(Bitfield::A_OFFSET, 152);
+        assert_eq!(Bitfield::B_OFFSET, 0);
+        assert_eq!(Bitfield::C_OFFSET, 8);
+
+        let bitfield = Bitfield::new();
+        assert_eq!(bitfield.a(), 0x12);
+        assert_eq!(bitfield.b(), 0x3);
+        assert_eq!(bitfield.c(), 0x45);
+        assert_eq!(
+            bitfield.into_le_bytes(),
+            [0x03, 0x45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x12]
+        );
+
+        let bitfield = Bitfield::from_le_bytes([0xFF; 20]);
+        assert_eq!(bitfield.a(), 0xFF);
+        assert_eq!(bitfield.b(), 0xF);
+        assert_eq!(bitfield.into_le_bytes(), [0xFF; 20]);
+    }
+
     #[test]
     fn bitfield_debug() {
         #[bitfield([u8; 4])]
@@ -1935,8 +2035,11 @@ mod tests {
             C(u8),
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -1944,7 +2047,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -1979,8 +2082,11 @@ mod tests {
             C(u8),
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -1988,7 +2094,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -2023,8 +2129,11 @@ mod tests {
             C(u8),
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -2032,7 +2141,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -2059,8 +2168,11 @@ mod tests {
             Black,
         }
 
-        impl Colour {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Colour {
+            const BITS: u32 = 1;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0 => Self::White,
                     1 => Self::Black,
@@ -2068,7 +2180,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self as u8
             }
         }
@@ -2083,8 +2195,11 @@ mod tests {
             Pawn,
         }
 
-        impl PieceType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for PieceType {
+            const BITS: u32 = 3;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0 => Self::King,
                     1 => Self::Queen,
@@ -2096,7 +2211,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self as u8
             }
         }
@@ -2760,8 +2875,11 @@ mod tests {
             B,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -2769,7 +2887,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -3829,8 +3947,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType2 {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType2 {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -3838,7 +3959,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -4142,6 +4263,307 @@ mod tests {
         assert_eq!(raw, [0x07, 0x03]);
     }
 
+    #[test]
+    fn bitfield_generic_marker_parameter() {
+        struct Secure;
+
+        #[bitfield([u8; 2])]
+        struct Page<M> {
+            present: bool,
+            #[bits(15)]
+            frame: u16,
+        }
+
+        let page: Page<Secure> = PageBuilder::new().with_present(true).with_frame(0x10).build();
+        assert_eq!(page.frame(), 0x10);
+        assert_eq!(page.into_le_bytes(), [0x21, 0x00]);
+        assert_eq!(Page::<Secure>::from_le_bytes([0x21, 0x00]).frame(), 0x10);
+    }
+
+    #[test]
+    fn bitfield_field_constraint_checked_from_bytes() {
+        #[bitfield([u8; 3])]
+        struct Bitfield {
+            #[bits(4, range = 1..=12)]
+            divider: u8,
+            #[bits(4)]
+            _reserved: u8,
+            #[bits(16, values = [0x1234, 0xABCD])]
+            magic: u16,
+        }
+
+        let bitfield = Bitfield::checked_from_bytes([0xAB, 0xCD, 0x0C]).unwrap();
+        assert_eq!(bitfield.divider(), 12);
+        assert_eq!(bitfield.magic(), 0xABCD);
+        assert!(Bitfield::checked_from_bytes([0xAB, 0xCD, 0x00]).is_err());
+        assert!(Bitfield::checked_from_bytes([0xAC, 0xCD, 0x01]).is_err());
+
+        let mut bitfield = Bitfield::new();
+        assert!(bitfield.checked_set_magic(0x1234).is_ok());
+        assert!(bitfield.checked_set_magic(0x1235).is_err());
+    }
+
+    #[test]
+    fn bitfield_encoded_fields() {
+        #[bitfield([u8; 2])]
+        struct Bitfield {
+            #[bits(8, encoding = bcd, default = 31)]
+            day: u8,
+            #[bits(7, encoding = offset_binary)]
+            trim: i8,
+            #[bits(1, encoding = active_low, default = true)]
+            enable: bool,
+        }
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.day(), 31);
+        assert_eq!(bitfield.trim(), 0);
+        assert!(bitfield.enable());
+        assert_eq!(bitfield.into_bytes(), [0x40, 0x31]);
+
+        bitfield.set_trim(-64);
+        assert_eq!(bitfield.trim(), -64);
+        assert_eq!(bitfield.into_bytes(), [0x00, 0x31]);
+        assert!(bitfield.checked_set_trim(64).is_err());
+
+        let bitfield = Bitfield::from_bytes([0xC0, 0x15]);
+        assert_eq!(bitfield.day(), 15);
+        assert_eq!(bitfield.trim(), 0);
+        assert!(!bitfield.enable());
+        assert_eq!(bitfield.checked_day(), Ok(15));
+        assert_eq!(
+            Bitfield::from_bytes([0x00, 0x3F]).checked_day(),
+            Err("The field bits contain a non-decimal digit.")
+        );
+    }
+
+    #[test]
+    fn bitfield_ascii_str_fields() {
+        use bitfields::AsciiStr;
+
+        #[bitfield([u8; 32])]
+        struct RomHeader {
+            #[bits(168, default = "NONAME")]
+            title: AsciiStr<21>,
+            #[bits(88)]
+            reserved_bytes: [u8; 11],
+        }
+
+        let mut rom_header = RomHeader::new();
+        assert_eq!(rom_header.title(), "NONAME");
+        assert_eq!(rom_header.into_bytes()[31], b'N');
+
+        rom_header.set_title(AsciiStr::from_ascii("THE LEGEND OF ZELDA 2"));
+        assert_eq!(rom_header.title().as_str(), Ok("THE LEGEND OF ZELDA 2"));
+        assert_eq!(rom_header.into_bytes()[11], b'2');
+        assert!(rom_header.checked_set_title(AsciiStr::from_ascii("ZELDA")).is_ok());
+        assert_eq!(rom_header.title().as_bytes()[..6], *b"ZELDA\0");
+
+        // Non-ASCII bytes are read as is.
+        let mut bytes = [0; 32];
+        bytes[31] = b'A';
+        bytes[11] = 0xFF;
+        let rom_header = RomHeader::from_bytes(bytes);
+        assert_eq!(rom_header.title().as_bytes()[20], 0xFF);
+        assert!(rom_header.title().as_str().is_err());
+
+        let rom_header =
+            RomHeaderBuilder::new().with_title(AsciiStr::from_ascii("A LINK TO THE PAST")).build();
+        assert_eq!(rom_header.title(), "A LINK TO THE PAST");
+        assert_eq!(
+            format!("{rom_header:?}"),
+            "RomHeader { title: \"A LINK TO THE PAST\", reserved_bytes: [0, 0, 0, 0, 0, 0, 0, 0, \
+             0, 0, 0] }"
+        );
+        assert_eq!(<AsciiStr<21> as BitfieldField>::BITS, 168);
+    }
+
+    #[test]
+    fn bitfield_field_endian() {
+        #[bitfield([u8; 6])]
+        struct Header {
+            #[bits(16)]
+            length: u16,
+            #[bits(32, endian = little)]
+            vendor: u32,
+        }
+
+        let header = Header::from_bytes([0x11, 0x22, 0x33, 0x44, 0xAB, 0xCD]);
+        assert_eq!(header.length(), 0xABCD);
+        assert_eq!(header.vendor(), 0x4433_2211);
+
+        let header = HeaderBuilder::new().with_length(0x0102).with_vendor(0x0A0B_0C0D).build();
+        assert_eq!(header.into_bytes(), [0x0D, 0x0C, 0x0B, 0x0A, 0x01, 0x02]);
+        assert_eq!(
+            format!("{header:?}"),
+            format!("Header {{ length: {}, vendor: {} }}", 0x0102, 0x0A0B_0C0D)
+        );
+    }
+
+    #[test]
+    fn bitfield_field_endian_in_little_endian_bitfield() {
+        #[bitfield([u8; 4], from_endian = little, into_endian = little)]
+        struct Record {
+            #[bits(16, endian = big)]
+            length: u16,
+            #[bits(16, endian = little)]
+            checksum: i16,
+        }
+
+        let mut record = Record::from_bytes([0x01, 0x02, 0xFE, 0xFF]);
+        assert_eq!(record.length(), 0x0102);
+        assert_eq!(record.checksum(), -2);
+
+        record.set_length(0x0304);
+        assert_eq!(record.into_bytes(), [0x03, 0x04, 0xFE, 0xFF]);
+    }
+
+    #[test]
+    fn bitfield_niche_typed_fields() {
+        use core::num::NonZeroU16;
+
+        #[bitfield([u8; 4])]
+        struct Descriptor {
+            #[bits(16)]
+            length: NonZeroU16,
+            #[bits(8, none = 0xFF, default = None)]
+            slot: Option<u8>,
+            #[bits(8)]
+            flags: u8,
+        }
+
+        let mut descriptor = Descriptor::new();
+        assert_eq!(descriptor.checked_length(), Err("The field bits are zero."));
+        assert_eq!(descriptor.slot(), None);
+        assert_eq!(descriptor.into_bytes(), [0x00, 0xFF, 0x00, 0x00]);
+
+        descriptor.set_length(NonZeroU16::new(0x0102).unwrap());
+        descriptor.set_slot(Some(4));
+        assert_eq!(descriptor.length().get(), 0x0102);
+        assert_eq!(descriptor.slot(), Some(4));
+        assert_eq!(descriptor.into_bytes(), [0x00, 0x04, 0x01, 0x02]);
+
+        let descriptor = Descriptor::from_bytes([0x01, 0x00, 0x00, 0x00]);
+        assert_eq!(descriptor.checked_length(), Err("The field bits are zero."));
+        assert_eq!(descriptor.slot(), Some(0));
+        assert_eq!(descriptor.flags(), 1);
+    }
+
+    #[test]
+    fn bitfield_const_expression_bits_and_array_length() {
+        const ADDR_BITS: u32 = 20;
+
+        #[bitfield([u8; 2 * 2])]
+        struct Descriptor {
+            #[bits(ADDR_BITS)]
+            addr: u32,
+            #[bits(32 - ADDR_BITS)]
+            flags: u16,
+        }
+
+        let mut descriptor = Descriptor::new();
+        descriptor.set_addr(0xABCDE);
+        descriptor.set_flags(0x123);
+        assert_eq!(descriptor.addr(), 0xABCDE);
+        assert_eq!(descriptor.flags(), 0x123);
+        assert_eq!(descriptor.into_bytes(), [0x12, 0x3A, 0xBC, 0xDE]);
+        assert!(descriptor.checked_set_flags(0x1000).is_err());
+    }
+
+    #[test]
+    fn bitfield_const_array_length() {
+        const DESCRIPTOR_SIZE: usize = 4;
+
+        #[bitfield([u8; DESCRIPTOR_SIZE], order = msb)]
+        struct Descriptor {
+            #[bits(20)]
+            addr: u32,
+            #[bits(12)]
+            flags: u16,
+        }
+
+        let mut descriptor = Descriptor::new();
+        descriptor.set_addr(0xABCDE);
+        descriptor.set_flags(0x123);
+        assert_eq!(descriptor.addr(), 0xABCDE);
+        assert_eq!(descriptor.flags(), 0x123);
+        assert_eq!(Descriptor::ADDR_OFFSET, 12);
+        assert_eq!(descriptor.into_bytes(), [0xAB, 0xCD, 0xE1, 0x23]);
+        assert_eq!(descriptor.into_le_bytes(), [0x23, 0xE1, 0xCD, 0xAB]);
+        assert_eq!(Descriptor::from_bytes([0xAB, 0xCD, 0xE1, 0x23]).addr(), 0xABCDE);
+        assert!(descriptor.checked_set_flags(0x1000).is_err());
+    }
+
+    #[test]
+    fn bitfield_const_word_array_length() {
+        const WORDS: usize = 2;
+
+        #[bitfield([u32; WORDS])]
+        struct Descriptor {
+            addr: u32,
+            #[bits(24)]
+            len: u32,
+            flags: u8,
+        }
+
+        let descriptor = Descriptor::from_words([0x1234_5678, 0x9ABC_DEF0]);
+        assert_eq!(descriptor.addr(), 0x9ABC_DEF0);
+        assert_eq!(descriptor.len(), 0x34_5678);
+        assert_eq!(descriptor.flags(), 0x12);
+        assert_eq!(descriptor.into_words(), [0x1234_5678, 0x9ABC_DEF0]);
+        assert_eq!(descriptor.into_le_words(), [0x9ABC_DEF0, 0x1234_5678]);
+        assert_eq!(descriptor.into_bytes(), [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]);
+    }
+
+    #[test]
+    fn bitfield_word_array_backed() {
+        #[bitfield([u32; 2])]
+        struct Descriptor {
+            addr: u32,
+            #[bits(24)]
+            len: u32,
+            flags: u8,
+        }
+
+        let descriptor = Descriptor::from_words([0x1234_5678, 0x9ABC_DEF0]);
+        assert_eq!(descriptor.addr(), 0x9ABC_DEF0);
+        assert_eq!(descriptor.len(), 0x34_5678);
+        assert_eq!(descriptor.flags(), 0x12);
+        assert_eq!(descriptor.into_words(), [0x1234_5678, 0x9ABC_DEF0]);
+        assert_eq!(descriptor.into_be_words(), [0x1234_5678, 0x9ABC_DEF0]);
+        assert_eq!(descriptor.into_le_words(), [0x9ABC_DEF0, 0x1234_5678]);
+        assert_eq!(descriptor.into_bytes(), [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]);
+        assert_eq!(Descriptor::from_le_words([0x9ABC_DEF0, 0x1234_5678]).addr(), 0x9ABC_DEF0);
+        assert_eq!(Descriptor::from_be_words([0x1234_5678, 0x9ABC_DEF0]).flags(), 0x12);
+
+        let descriptor = Descriptor::from([0x0100_0000, 0x0000_0002]);
+        assert_eq!(descriptor.flags(), 0x01);
+        assert_eq!(descriptor.addr(), 0x02);
+        let words: [u32; 2] = descriptor.into();
+        assert_eq!(words, [0x0100_0000, 0x0000_0002]);
+    }
+
+    #[test]
+    fn bitfield_word_array_backed_words_range() {
+        #[bitfield([u16; 4])]
+        struct Bitfield {
+            a: u16,
+            b: u32,
+            #[bits(16, access = ro)]
+            c: u16,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_words_range(8, 24, [0xBEEF, 0x00AD, 0, 0]);
+        assert_eq!(bitfield.a(), 0xEF00);
+        assert_eq!(bitfield.b(), 0xADBE);
+        assert_eq!(bitfield.get_words_range(8, 24), [0xBEEF, 0x00AD, 0, 0]);
+        assert_eq!(bitfield.checked_get_words_range(8, 24), Ok([0xBEEF, 0x00AD, 0, 0]));
+        assert!(bitfield.checked_set_words_range(48, 16, [0xFFFF, 0, 0, 0]).is_err());
+        assert!(bitfield.checked_get_words_range(60, 8).is_err());
+        assert_eq!(bitfield.into_words(), [0x0000, 0x0000, 0xADBE, 0xEF00]);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn compile_error_cases() {
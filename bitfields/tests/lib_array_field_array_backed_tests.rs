@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use bitfields::bitfield;
+    use bitfields::{bitfield, bitflag};
 
     #[test]
     fn array_field_single_byte_backing() {
@@ -455,6 +455,26 @@ mod tests {
         assert_eq!(bf.into_bytes(), [0x44, 0x33, 0x22, 0x11]);
     }
 
+    #[test]
+    fn array_field_const_length() {
+        const LEN: usize = 4;
+        const HIGH_LEN: usize = LEN - 2;
+
+        #[bitfield([u8; LEN], order = msb)]
+        pub struct Bitfield {
+            high: [u8; HIGH_LEN],
+            low: u16,
+        }
+
+        let mut bf = Bitfield::new();
+        bf.set_high([0x11, 0x22]);
+        bf.set_low(0x3344);
+        assert_eq!(bf.high(), [0x11, 0x22]);
+        assert_eq!(bf.low(), 0x3344);
+        assert_eq!(bf.into_bytes(), [0x22, 0x11, 0x33, 0x44]);
+        assert_eq!(Bitfield::from_bytes([0x22, 0x11, 0x33, 0x44]).high(), [0x11, 0x22]);
+    }
+
     #[test]
     fn array_field_msb_order() {
         #[bitfield([u8; 4], order = msb)]
@@ -901,4 +921,83 @@ mod tests {
         assert_eq!(bf.b(), 0);
         assert_eq!(bf.c(), 0);
     }
+
+    #[test]
+    fn packed_array_field() {
+        #[bitfield([u8; 4])]
+        pub struct Bitfield {
+            a: u8,
+            #[bits(element = 3)]
+            samples: [u8; 8],
+        }
+
+        let mut bf = Bitfield::new();
+        bf.set_a(0x12);
+        bf.set_samples([0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(bf.samples(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(bf.into_le_bytes(), [0x12, 0x88, 0xC6, 0xFA]);
+
+        bf.set_samples_at(7, 0);
+        assert_eq!(bf.samples_at(7), 0);
+        assert_eq!(bf.samples_at(6), 6);
+        assert!(bf.checked_set_samples_at(0, 8).is_err());
+        assert!(bf.checked_samples_at(8).is_err());
+        assert_eq!(bf.into_le_bytes(), [0x12, 0x88, 0xC6, 0x1A]);
+        assert_eq!(Bitfield::from_le_bytes([0x12, 0x88, 0xC6, 0xFA]).samples_at(7), 7);
+    }
+
+    #[test]
+    fn packed_array_field_bitflag_elements() {
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum Priority {
+            #[base]
+            Low = 0,
+            Medium = 1,
+            High = 2,
+            Critical = 3,
+        }
+
+        #[bitfield([u8; 2])]
+        pub struct Bitfield {
+            #[bits(element = 2)]
+            priorities: [Priority; 6],
+            #[bits(4)]
+            _reserved: u8,
+        }
+
+        let mut bf = Bitfield::new();
+        bf.set_priorities_at(0, Priority::Critical);
+        bf.set_priorities_at(5, Priority::High);
+        assert_eq!(bf.priorities_at(5), Priority::High);
+        assert_eq!(bf.into_le_bytes(), [0x03, 0x08]);
+        assert_eq!(
+            Bitfield::from_le_bytes([0x1B, 0x01]).priorities(),
+            [
+                Priority::Critical,
+                Priority::High,
+                Priority::Medium,
+                Priority::Low,
+                Priority::Medium,
+                Priority::Low,
+            ]
+        );
+    }
+
+    #[test]
+    fn u16_and_bool_array_fields() {
+        #[bitfield([u8; 6])]
+        pub struct Bitfield {
+            words: [u16; 2],
+            flags: [bool; 16],
+        }
+
+        let mut bf = Bitfield::new();
+        bf.set_words([0x1234, 0xABCD]);
+        bf.set_flags_at(15, true);
+        assert_eq!(bf.words(), [0x1234, 0xABCD]);
+        assert!(bf.flags_at(15));
+        assert_eq!(bf.into_le_bytes(), [0x34, 0x12, 0xCD, 0xAB, 0x00, 0x80]);
+        assert_eq!(Bitfield::from_le_bytes([0, 0, 0xFF, 0xFF, 0x01, 0]).words_at(1), 0xFFFF);
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use bitfields::bitfield;
+    use bitfields::{BitfieldField, bitfield, bitflag};
 
     #[test]
     fn array_field_u8_backing() {
@@ -807,6 +807,369 @@ mod tests {
         assert_eq!(packet.payload(), expected);
     }
 
+    #[test]
+    fn array_field_const_length() {
+        const LOGO_LEN: usize = 2;
+
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            a: u8,
+            logo: [u8; LOGO_LEN],
+            b: u8,
+        }
+
+        let mut bf = Bitfield::new();
+        bf.set_a(0x12);
+        bf.set_logo([0x34, 0x56]);
+        bf.set_b(0x78);
+        assert_eq!(bf.logo(), [0x34, 0x56]);
+        assert_eq!(bf.into_bits(), 0x78563412_u32);
+        assert_eq!(Bitfield::LOGO_BITS, 16);
+        assert_eq!(Bitfield::B_OFFSET, 24);
+
+        let bf = BitfieldBuilder::new().with_a(0x12).with_logo([0xAB, 0xCD]).with_b(0x78).build();
+        assert_eq!(bf.into_bits(), 0x78CDAB12_u32);
+    }
+
+    #[test]
+    fn packed_array_field() {
+        #[bitfield(u16)]
+        struct Sprite {
+            #[bits(element = 2)]
+            palette: [u8; 4],
+            #[bits(element = 4)]
+            tiles: [u8; 2],
+        }
+
+        let mut sprite = Sprite::new();
+        sprite.set_palette([1, 2, 3, 0]);
+        sprite.set_tiles([0xA, 0x5]);
+        assert_eq!(sprite.palette(), [1, 2, 3, 0]);
+        assert_eq!(sprite.tiles(), [0xA, 0x5]);
+        assert_eq!(sprite.into_bits(), 0x5A39);
+
+        sprite.set_palette_at(3, 2);
+        assert_eq!(sprite.palette_at(3), 2);
+        assert_eq!(sprite.palette_at(1), 2);
+        assert_eq!(sprite.palette(), [1, 2, 3, 2]);
+        assert_eq!(sprite.into_bits(), 0x5AB9);
+
+        assert_eq!(Sprite::PALETTE_BITS, 8);
+        assert_eq!(Sprite::PALETTE_ELEMENT_BITS, 2);
+        assert_eq!(Sprite::PALETTE_LEN, 4);
+        assert_eq!(Sprite::TILES_OFFSET, 8);
+    }
+
+    #[test]
+    fn packed_array_field_checked_setters() {
+        #[bitfield(u8)]
+        struct Sprite {
+            #[bits(element = 2)]
+            palette: [u8; 4],
+        }
+
+        let mut sprite = Sprite::new();
+        assert_eq!(sprite.checked_set_palette_at(2, 3), Ok(()));
+        assert_eq!(sprite.palette_at(2), 3);
+        assert!(sprite.checked_set_palette_at(2, 4).is_err());
+        assert!(sprite.checked_set_palette_at(4, 1).is_err());
+        assert!(sprite.checked_palette_at(4).is_err());
+        assert_eq!(sprite.checked_palette_at(2), Ok(3));
+        assert_eq!(sprite.into_bits(), 0x30);
+
+        assert!(sprite.checked_set_palette([0, 1, 4, 3]).is_err());
+        assert_eq!(sprite.checked_set_palette([0, 1, 2, 3]), Ok(()));
+        assert_eq!(sprite.into_bits(), 0xE4);
+
+        // Elements are truncated to the element bits.
+        sprite.set_palette([0, 5, 0, 0]);
+        assert_eq!(sprite.palette(), [0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn packed_array_field_overflow_policy() {
+        #[bitfield(u16, overflow = saturate)]
+        struct Sprite {
+            #[bits(element = 2)]
+            palette: [u8; 4],
+            #[bits(element = 4, overflow = truncate)]
+            tiles: [u8; 2],
+        }
+
+        let mut sprite = Sprite::new();
+        sprite.set_palette([0, 5, 2, 0xFF]);
+        assert_eq!(sprite.palette(), [0, 3, 2, 3]);
+        sprite.set_palette_at(0, 4);
+        assert_eq!(sprite.palette_at(0), 3);
+
+        sprite.set_tiles([0x1F, 0x2]);
+        assert_eq!(sprite.tiles(), [0xF, 0x2]);
+        sprite.set_tiles_at(1, 0x13);
+        assert_eq!(sprite.tiles_at(1), 0x3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value is too big to fit within the field bits.")]
+    fn packed_array_field_overflow_policy_panic() {
+        #[bitfield(u8)]
+        struct Sprite {
+            #[bits(element = 2, overflow = panic)]
+            palette: [u8; 4],
+        }
+
+        let mut sprite = Sprite::new();
+        sprite.set_palette_at(1, 3);
+        assert_eq!(sprite.palette_at(1), 3);
+        sprite.set_palette_at(1, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "The index is outside the array range.")]
+    fn packed_array_field_getter_index_out_of_range_panic() {
+        #[bitfield(u8)]
+        struct Sprite {
+            #[bits(element = 2)]
+            palette: [u8; 4],
+        }
+
+        let sprite = Sprite::new();
+        sprite.palette_at(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "The index is outside the array range.")]
+    fn packed_array_field_setter_index_out_of_range_panic() {
+        #[bitfield(u8)]
+        struct Sprite {
+            #[bits(element = 2)]
+            palette: [u8; 4],
+        }
+
+        let mut sprite = Sprite::new();
+        sprite.set_palette_at(100, 3);
+    }
+
+    #[test]
+    fn packed_array_field_defaults() {
+        #[bitfield(u16)]
+        struct Sprite {
+            #[bits(element = 3, default = [7, 0, 5, 1])]
+            palette: [u8; 4],
+            #[bits(4)]
+            _reserved: u8,
+        }
+
+        let sprite = Sprite::new();
+        assert_eq!(sprite.palette(), [7, 0, 5, 1]);
+        assert_eq!(sprite.into_bits(), 0x0347);
+        assert_eq!(Sprite::from_bits(0x0FFF).palette(), [7, 7, 7, 7]);
+        assert_eq!(format!("{:?}", sprite), "Sprite { palette: [7, 0, 5, 1], _reserved: 0 }");
+        assert_eq!(SpriteBuilder::new().with_palette([1, 1, 1, 1]).build().into_bits(), 0x249);
+        assert_eq!(sprite.palette_inverted(), [0, 7, 2, 6]);
+    }
+
+    #[test]
+    fn packed_array_field_bitflag_elements() {
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum PinMode {
+            #[base]
+            Input = 0,
+            Output = 1,
+            Alternate = 2,
+            Analog = 3,
+        }
+
+        #[bitfield(u16)]
+        struct GpioModes {
+            #[bits(element = 2)]
+            modes: [PinMode; 8],
+        }
+
+        let mut gpio = GpioModes::new();
+        assert_eq!(gpio.modes(), [PinMode::Input; 8]);
+
+        gpio.set_modes_at(0, PinMode::Output);
+        gpio.set_modes_at(7, PinMode::Analog);
+        assert_eq!(gpio.modes_at(0), PinMode::Output);
+        assert_eq!(gpio.modes_at(7), PinMode::Analog);
+        assert_eq!(gpio.into_bits(), 0xC001);
+
+        gpio.set_modes([
+            PinMode::Alternate,
+            PinMode::Input,
+            PinMode::Output,
+            PinMode::Analog,
+            PinMode::Input,
+            PinMode::Input,
+            PinMode::Input,
+            PinMode::Input,
+        ]);
+        assert_eq!(gpio.into_bits(), 0x00D2);
+        assert_eq!(gpio.modes()[3], PinMode::Analog);
+        assert_eq!(gpio.checked_modes_at(8), Err("The index is outside the array range."));
+        assert_eq!(gpio.checked_set_modes_at(1, PinMode::Output), Ok(()));
+        assert_eq!(GpioModes::from_bits(0xFFFF).modes(), [PinMode::Analog; 8]);
+        assert_eq!(
+            GpioModesBuilder::new().with_modes([PinMode::Output; 8]).build().into_bits(),
+            0x5555
+        );
+        assert_eq!(GpioModes::new().modes_inverted(), [PinMode::Analog; 8]);
+        assert_eq!(
+            format!("{:?}", GpioModes::from_bits(0x0001)),
+            "GpioModes { modes: [Output, Input, Input, Input, Input, Input, Input, Input] }"
+        );
+    }
+
+    #[test]
+    fn packed_array_field_custom_elements() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Priority(u8);
+
+        impl BitfieldField for Priority {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                self.0
+            }
+        }
+
+        #[bitfield(u16)]
+        struct SpritePriorities {
+            #[bits(element = 4)]
+            priorities: [Priority; 4],
+        }
+
+        let mut sprites = SpritePriorities::new();
+        sprites.set_priorities_at(1, Priority(5));
+        assert_eq!(sprites.priorities_at(1), Priority(5));
+        assert_eq!(sprites.into_bits(), 0x0050);
+
+        // Bits of the element type beyond the element bits don't fit.
+        assert!(sprites.checked_set_priorities_at(2, Priority(0x10)).is_err());
+        assert!(
+            sprites
+                .checked_set_priorities([Priority(1), Priority(2), Priority(0x13), Priority(4),])
+                .is_err()
+        );
+
+        sprites.set_priorities([Priority(1), Priority(2), Priority(3), Priority(4)]);
+        assert_eq!(sprites.into_bits(), 0x4321);
+        assert_eq!(sprites.priorities()[2], Priority(3));
+    }
+
+    #[test]
+    fn bool_array_field() {
+        #[bitfield(u32)]
+        struct InterruptEnable {
+            lines: [bool; 32],
+        }
+
+        let mut interrupts = InterruptEnable::new();
+        interrupts.set_lines_at(0, true);
+        interrupts.set_lines_at(31, true);
+        assert!(interrupts.lines_at(31));
+        assert!(!interrupts.lines_at(30));
+        assert!(interrupts.checked_lines_at(32).is_err());
+        assert_eq!(interrupts.into_bits(), 0x8000_0001);
+
+        let lines = InterruptEnable::from_bits(0x0000_0005).lines();
+        assert!(lines[0] && !lines[1] && lines[2]);
+        assert_eq!(InterruptEnable::LINES_ELEMENT_BITS, 1);
+        assert_eq!(InterruptEnable::LINES_LEN, 32);
+        assert_eq!(InterruptEnable::new().lines_inverted(), [true; 32]);
+    }
+
+    #[test]
+    fn u16_array_field() {
+        #[bitfield(u64)]
+        struct Words {
+            words: [u16; 3],
+            tag: u16,
+        }
+
+        let mut words = Words::new();
+        words.set_words([0x1122, 0x3344, 0x5566]);
+        words.set_tag(0x7788);
+        assert_eq!(words.words(), [0x1122, 0x3344, 0x5566]);
+        assert_eq!(words.into_bits(), 0x7788_5566_3344_1122);
+
+        words.set_words_at(1, 0xABCD);
+        assert_eq!(words.words_at(1), 0xABCD);
+        assert_eq!(words.checked_words_at(3), Err("The index is outside the array range."));
+        assert_eq!(Words::WORDS_BITS, 48);
+        assert_eq!(Words::TAG_OFFSET, 48);
+    }
+
+    #[test]
+    fn u32_array_field() {
+        #[bitfield(u64)]
+        struct Pair {
+            values: [u32; 2],
+        }
+
+        let pair = PairBuilder::new().with_values([0xDEAD_BEEF, 0x0123_4567]).build();
+        assert_eq!(pair.values(), [0xDEAD_BEEF, 0x0123_4567]);
+        assert_eq!(pair.into_bits(), 0x0123_4567_DEAD_BEEF);
+    }
+
+    #[test]
+    fn u16_array_field_endian() {
+        #[bitfield(u32, from_endian = little, into_endian = little)]
+        struct Words {
+            words: [u16; 2],
+        }
+
+        // Each element follows the endian of the bitfield.
+        let words = Words::from_bits(0x1122_3344);
+        assert_eq!(words.words(), [0x2211, 0x4433]);
+        assert_eq!(words.into_bits(), 0x1122_3344);
+    }
+
+    #[test]
+    fn signed_array_field() {
+        #[bitfield(u32)]
+        struct Samples {
+            samples: [i8; 4],
+        }
+
+        let mut samples = Samples::new();
+        samples.set_samples([-1, 2, -128, 127]);
+        assert_eq!(samples.samples(), [-1, 2, -128, 127]);
+        assert_eq!(samples.into_bits(), 0x7F80_02FF);
+
+        samples.set_samples_at(1, -2);
+        assert_eq!(samples.samples_at(1), -2);
+        assert_eq!(samples.into_bits(), 0x7F80_FEFF);
+    }
+
+    #[test]
+    fn signed_packed_array_field() {
+        #[bitfield(u16)]
+        struct Deltas {
+            #[bits(element = 4)]
+            deltas: [i8; 4],
+        }
+
+        let mut deltas = Deltas::new();
+        deltas.set_deltas([-1, 7, -8, 0]);
+        assert_eq!(deltas.deltas(), [-1, 7, -8, 0]);
+        assert_eq!(deltas.into_bits(), 0x087F);
+
+        assert_eq!(deltas.checked_set_deltas_at(3, -8), Ok(()));
+        assert_eq!(deltas.deltas_at(3), -8);
+        assert!(deltas.checked_set_deltas_at(3, 8).is_err());
+        assert!(deltas.checked_set_deltas_at(3, -9).is_err());
+        assert!(deltas.checked_set_deltas([0, 0, 0, 8]).is_err());
+        assert_eq!(deltas.checked_set_deltas([0, 0, 0, -1]), Ok(()));
+        assert_eq!(deltas.into_bits(), 0xF000);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn compile_error_cases_array_fields() {
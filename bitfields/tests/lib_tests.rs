@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
+    use bitfields::AsciiStr;
+    use bitfields::BitfieldField;
     use bitfields::bitfield;
     use bitfields::bitflag;
+    use bitfields::{Int, UInt, i4, i12, u2, u4, u12};
 
     #[test]
     fn bitfield_arguments() {
@@ -187,6 +190,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bitfield_field_const_expression_bits() {
+        const ADDR_BITS: u32 = 20;
+
+        mod chip {
+            pub const FLAGS_BITS: u32 = 2;
+        }
+
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(ADDR_BITS)]
+            addr: u32,
+            #[bits(chip::FLAGS_BITS, default = 1)]
+            flags: u8,
+            #[bits(ADDR_BITS / 4 + 1)]
+            level: i8,
+            #[bits(2 * 2)]
+            _reserved: u8,
+        }
+
+        assert_eq!(Bitfield::ADDR_BITS, 20);
+        assert_eq!(Bitfield::FLAGS_OFFSET, 20);
+        assert_eq!(Bitfield::LEVEL_OFFSET, 22);
+        assert_eq!(Bitfield::LEVEL_BITS, 6);
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.flags(), 1);
+
+        bitfield.set_addr(0xFFFFF);
+        bitfield.set_level(-32);
+        assert_eq!(bitfield.addr(), 0xFFFFF);
+        assert_eq!(bitfield.level(), -32);
+        assert_eq!(bitfield.into_bits(), 0x081FFFFF);
+
+        assert!(bitfield.checked_set_addr(0x100000).is_err());
+        assert!(bitfield.checked_set_level(32).is_err());
+        assert!(bitfield.checked_set_level(-33).is_err());
+        assert_eq!(bitfield.checked_set_level(31), Ok(()));
+        assert_eq!(bitfield.level(), 31);
+
+        let bitfield = BitfieldBuilder::new().with_addr(0x12345).with_flags(2).build();
+        assert_eq!(bitfield.into_bits(), 0x00212345);
+    }
+
+    #[test]
+    fn bitfield_signed_backed() {
+        #[bitfield(i32)]
+        pub struct Bitfield {
+            #[bits(16)]
+            offset: i16,
+            #[bits(15)]
+            count: u16,
+            negative: bool,
+        }
+
+        let mut bitfield = Bitfield::from_bits(-2);
+        assert_eq!(bitfield.offset(), -2);
+        assert_eq!(bitfield.count(), 0x7FFF);
+        assert!(bitfield.negative());
+        assert_eq!(bitfield.into_bits(), -2);
+        assert_eq!(bitfield.into_le_bits(), (-2i32).swap_bytes());
+
+        bitfield.set_count(0);
+        assert_eq!(bitfield.into_bits(), i32::MIN | 0xFFFE);
+
+        bitfield.write_bits(0x0001_0001);
+        assert_eq!(bitfield.offset(), 1);
+        assert_eq!(bitfield.count(), 1);
+        assert!(!bitfield.negative());
+
+        let bitfield = Bitfield::from(i32::MIN);
+        assert!(bitfield.negative());
+        assert_eq!(i32::from(bitfield), i32::MIN);
+        assert_eq!(Bitfield::from_le_bits(0x0100_0000).offset(), 1);
+        assert_eq!(Bitfield::from_be_bits(-1).count(), 0x7FFF);
+    }
+
     #[test]
     fn bitfield_fields_default_value() {
         #[bitfield(u32)]
@@ -617,6 +697,176 @@ mod tests {
         assert_eq!(bitfield.into_bits(), 0x78CFFFF);
     }
 
+    #[test]
+    fn bitfield_overflow_policy() {
+        #[bitfield(u32, overflow = saturate)]
+        pub struct Bitfield {
+            #[bits(4)]
+            a: u8,
+            #[bits(6)]
+            b: i8,
+            #[bits(4, overflow = truncate)]
+            c: u8,
+            #[bits(10, overflow = debug_assert)]
+            d: u16,
+            #[bits(8)]
+            e: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_a(0xFF);
+        bitfield.set_b(100);
+        bitfield.set_c(0x1F);
+        bitfield.set_e(0xFF);
+        assert_eq!(bitfield.a(), 0xF);
+        assert_eq!(bitfield.b(), 31);
+        assert_eq!(bitfield.c(), 0xF);
+        assert_eq!(bitfield.e(), 0xFF);
+
+        bitfield.set_b(-100);
+        assert_eq!(bitfield.b(), -32);
+
+        let bitfield = BitfieldBuilder::new().with_a(16).with_b(-33).with_d(0x3FF).build();
+        assert_eq!(bitfield.a(), 0xF);
+        assert_eq!(bitfield.b(), -32);
+        assert_eq!(bitfield.d(), 0x3FF);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value is too big to fit within the field bits.")]
+    fn bitfield_overflow_policy_panic() {
+        #[bitfield(u16, overflow = panic)]
+        pub struct Bitfield {
+            #[bits(4)]
+            a: u8,
+            #[bits(12)]
+            b: u16,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_a(0xF);
+        assert_eq!(bitfield.a(), 0xF);
+        bitfield.set_b(0x1000);
+    }
+
+    #[test]
+    fn bitfield_overflow_policy_debug_assert() {
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(4, overflow = debug_assert)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_a(0xA);
+        assert_eq!(bitfield.a(), 0xA);
+
+        let result = std::panic::catch_unwind(|| {
+            let mut bitfield = Bitfield::new();
+            bitfield.set_a(0x1F);
+            bitfield.into_bits()
+        });
+        if cfg!(debug_assertions) {
+            assert!(result.is_err());
+        } else {
+            assert_eq!(result.ok(), Some(0x0F));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Value is outside the valid values of the field.")]
+    fn bitfield_overflow_policy_panic_constraint() {
+        #[bitfield(u8, overflow = panic)]
+        pub struct Bitfield {
+            #[bits(4, range = 1..=12, default = 1)]
+            divider: u8,
+            #[bits(4)]
+            b: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_divider(12);
+        assert_eq!(bitfield.divider(), 12);
+        // Fits within the field bits, but is outside the range.
+        bitfield.set_divider(13);
+    }
+
+    #[test]
+    fn bitfield_overflow_policy_debug_assert_constraint() {
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(5, overflow = debug_assert, values = [4, 8, 16], default = 4)]
+            burst_length: u8,
+            #[bits(3)]
+            b: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_burst_length(16);
+        assert_eq!(bitfield.burst_length(), 16);
+
+        let result = std::panic::catch_unwind(|| {
+            let mut bitfield = Bitfield::new();
+            bitfield.set_burst_length(6);
+            bitfield.into_bits()
+        });
+        if cfg!(debug_assertions) {
+            assert!(result.is_err());
+        } else {
+            assert_eq!(result.ok(), Some(6));
+        }
+    }
+
+    #[test]
+    fn bitfield_field_getter_setter_visibility() {
+        mod driver {
+            use bitfields::bitfield;
+
+            #[bitfield(u16)]
+            pub struct StatusRegister {
+                #[bits(get = pub, set = pub(self))]
+                status: u8,
+                #[bits(4, set = pub(crate))]
+                mode: u8,
+                #[bits(4, get = pub(super), access = ro)]
+                revision: u8,
+            }
+
+            impl StatusRegister {
+                pub fn from_device(status: u8, revision: u8) -> Self {
+                    let mut register = StatusRegisterBuilder::new().with_revision(revision).build();
+                    register.set_status(status);
+                    register
+                }
+
+                pub fn acknowledge(&mut self) {
+                    self.clear_status();
+                }
+            }
+        }
+
+        let mut register = driver::StatusRegister::from_device(0x81, 3);
+        assert_eq!(register.status(), 0x81);
+        assert_eq!(register.revision(), 3);
+
+        register.set_mode(0xA);
+        assert_eq!(
+            register.checked_set_mode(0x10),
+            Err("Value is too big to fit within the field bits.")
+        );
+        assert_eq!(register.mode(), 0xA);
+        assert_eq!(register.into_bits(), 0x3A81);
+
+        register.acknowledge();
+        assert_eq!(register.status(), 0);
+        assert_eq!(register.status_inverted(), 0xFF);
+
+        let register = driver::StatusRegisterBuilder::new().with_mode(5).build();
+        assert_eq!(register.into_bits(), 0x0500);
+    }
+
     #[test]
     fn bitfield_field_nested_bitfield() {
         #[bitfield(u16)]
@@ -655,8 +905,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -664,7 +917,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -700,15 +953,20 @@ mod tests {
                     b: 4,
                 }
             }
+        }
 
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self {
                     a: bits as i8,
                     b: bits,
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self.b
             }
         }
@@ -741,15 +999,20 @@ mod tests {
                     b: 4,
                 }
             }
+        }
 
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self {
                     a: bits as i8,
                     b: bits,
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self.b
             }
         }
@@ -779,8 +1042,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -788,7 +1054,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -824,8 +1090,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -833,7 +1102,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -869,8 +1138,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -878,7 +1150,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -959,8 +1231,11 @@ mod tests {
             B = 0x4,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -968,7 +1243,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -1280,158 +1555,675 @@ mod tests {
     }
 
     #[test]
-    fn bitfield_debug() {
+    fn bitfield_explicit_bit_positions() {
         #[bitfield(u32)]
         pub struct Bitfield {
-            #[bits(default = 0x12)]
+            #[bits(24..=31, default = 0x12)]
             a: u8,
-            #[bits(default = 0x34)]
+            #[bits(4..=7, default = 0x3)]
             b: u8,
-            #[bits(default = 0x56)]
+            #[bits(at = 12)]
             c: u8,
-            #[bits(default = 0x78)]
-            d: u8,
+            #[bits(1, at = 0, default = true)]
+            d: bool,
         }
 
-        let bitfield = Bitfield::new();
-        let debug_str = format!("{:?}", bitfield);
+        assert_eq!(Bitfield::A_BITS, 8);
+        assert_eq!(Bitfield::A_OFFSET, 24);
+        assert_eq!(Bitfield::B_BITS, 4);
+        assert_eq!(Bitfield::B_OFFSET, 4);
+        assert_eq!(Bitfield::C_BITS, 8);
+        assert_eq!(Bitfield::C_OFFSET, 12);
+        assert_eq!(Bitfield::D_BITS, 1);
+        assert_eq!(Bitfield::D_OFFSET, 0);
 
-        assert_eq!(debug_str, "Bitfield { a: 18, b: 52, c: 86, d: 120 }");
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.into_bits(), 0x1200_0031);
+
+        bitfield.set_c(0xAB);
+        assert_eq!(bitfield.c(), 0xAB);
+        assert_eq!(bitfield.into_bits(), 0x120A_B031);
     }
 
     #[test]
-    fn bitfield_debug_msb() {
-        #[bitfield(u32, order = Msb)]
+    fn bitfield_explicit_bit_positions_implicit_reserved_bits() {
+        #[bitfield(u16)]
         pub struct Bitfield {
-            #[bits(default = 0x12)]
+            #[bits(0..=3)]
             a: u8,
-            #[bits(default = 0x34)]
+            #[bits(12..=15)]
             b: u8,
-            #[bits(default = 0x56)]
-            c: u8,
-            #[bits(default = 0x78)]
-            d: u8,
         }
 
-        let bitfield = Bitfield::new();
-        let debug_str = format!("{:?}", bitfield);
+        let mut bitfield = Bitfield::from_bits(0xFFFF);
+        assert_eq!(bitfield.a(), 0xF);
+        assert_eq!(bitfield.b(), 0xF);
 
-        assert_eq!(debug_str, "Bitfield { a: 18, b: 52, c: 86, d: 120 }");
+        bitfield.set_a(0);
+        bitfield.set_b(0);
+        assert_eq!(bitfield.into_bits(), 0x0FF0);
+        assert_eq!(Bitfield::new().into_bits(), 0);
+
+        let bitfield = BitfieldBuilder::new().with_a(0x1).with_b(0x2).build();
+        assert_eq!(bitfield.into_bits(), 0x2001);
     }
 
     #[test]
-    fn bitfield_field_access_write_only_can_write() {
-        #[bitfield(u32)]
+    fn bitfield_explicit_bit_positions_following_fields() {
+        #[bitfield(u16)]
         pub struct Bitfield {
-            #[bits(default = 0x12, access = wo)]
-            a: u32,
+            #[bits(8..=11)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+            #[bits(6, at = 2)]
+            c: u8,
         }
 
-        Bitfield::new().set_a(0x34);
-        BitfieldBuilder::new().with_a(0x34).build();
+        assert_eq!(Bitfield::B_OFFSET, 12);
+        assert_eq!(Bitfield::C_OFFSET, 2);
+
+        let bitfield = Bitfield::from_bits(0xA5FD);
+        assert_eq!(bitfield.a(), 0x5);
+        assert_eq!(bitfield.b(), 0xA);
+        assert_eq!(bitfield.c(), 0x3F);
+        assert_eq!(bitfield.into_bits(), 0xA5FD);
     }
 
     #[test]
-    fn bitfield_field_access_read_only_can_read() {
-        #[bitfield(u32)]
+    fn bitfield_explicit_bit_positions_msb() {
+        #[bitfield(u16, order = Msb)]
         pub struct Bitfield {
-            #[bits(default = 0x12, access = ro)]
-            a: u32,
+            #[bits(12..=15)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+            #[bits(0..=3)]
+            c: u8,
         }
 
-        assert_eq!(Bitfield::new().a(), 0x12);
-        assert_eq!(BitfieldBuilder::new().build().a(), 0x12)
+        assert_eq!(Bitfield::A_OFFSET, 12);
+        assert_eq!(Bitfield::B_OFFSET, 8);
+        assert_eq!(Bitfield::C_OFFSET, 0);
+
+        let bitfield = Bitfield::from_bits(0x1234);
+        assert_eq!(bitfield.a(), 0x1);
+        assert_eq!(bitfield.b(), 0x2);
+        assert_eq!(bitfield.c(), 0x4);
     }
 
     #[test]
-    fn bitfield_from_bits_field_access_read_only() {
-        #[bitfield(u8)]
-        struct Bitfield {
+    fn bitfield_fill_reserved() {
+        #[bitfield(u32, fill = reserved)]
+        pub struct Bitfield {
+            a: u8,
             #[bits(4)]
-            rw: u8,
-            #[bits(4, access = ro)]
-            ro: u8,
+            b: u8,
         }
 
-        let bar1 = Bitfield::from_bits(0xFF);
-        assert_eq!(0xF, bar1.rw());
-        assert_eq!(0xF, bar1.ro());
+        let mut bitfield = Bitfield::from_bits(0xFFFF_FFFF);
+        assert_eq!(bitfield.a(), 0xFF);
+        assert_eq!(bitfield.b(), 0xF);
 
-        let bar2 = BitfieldBuilder::new().with_ro(0xF).build();
-        assert_eq!(0xF, bar2.ro());
-    }
+        bitfield.set_a(0);
+        assert_eq!(bitfield.into_bits(), 0xFFFF_FF00);
+        assert_eq!(Bitfield::new().into_bits(), 0);
 
-    #[test]
-    fn bitfield_from_bits_sets_read_only_fields() {
-        #[bitfield(u32)]
-        pub struct Bitfield {
-            #[bits(default = 0x12)]
-            rw_field: u8,
-            #[bits(default = 0x34, access = ro)]
-            ro_field: u8,
-            no_default_field: u8,
-            #[bits(default = 0x78)]
-            another_field: u8,
+        #[bitfield(u16, order = msb, fill = reserved)]
+        pub struct MsbBitfield {
+            #[bits(4)]
+            a: u8,
         }
 
-        let bitfield = Bitfield::from_bits(0x11_22_33_44);
-        assert_eq!(bitfield.rw_field(), 0x44);
-        assert_eq!(bitfield.ro_field(), 0x33);
-        assert_eq!(bitfield.no_default_field(), 0x22);
-        assert_eq!(bitfield.another_field(), 0x11);
-        assert_eq!(bitfield.into_bits(), 0x11_22_33_44);
+        assert_eq!(MsbBitfield::A_OFFSET, 12);
+        assert_eq!(MsbBitfield::from_bits(0x1234).a(), 0x1);
     }
 
     #[test]
-    fn bitfield_from_bits_sets_read_only_bool_fields() {
-        #[bitfield(u16)]
+    fn bitfield_fill_reserved_ones() {
+        #[bitfield(u16, fill = reserved_ones)]
         pub struct Bitfield {
-            #[bits(4)]
+            #[bits(0..=3)]
             a: u8,
-            #[bits(access = ro)]
-            ro_bool: bool,
-            #[bits(3)]
+            #[bits(8..=11)]
             b: u8,
-            #[bits(8)]
-            c: u8,
         }
 
-        let bitfield_true = Bitfield::from_bits(0xFFFF);
-        assert!(bitfield_true.ro_bool());
-        let bitfield_false = Bitfield::from_bits(0xFFEF);
-        assert!(!bitfield_false.ro_bool());
-    }
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.into_bits(), 0xF0F0);
 
-    #[test]
-    fn bitfield_field_access_na() {
-        #[bitfield(u32)]
-        pub struct Bitfield {
-            #[bits(default = 0x12, access = na)]
-            a: u32,
+        bitfield.set_a(0x1);
+        bitfield.set_b(0x2);
+        assert_eq!(bitfield.into_bits(), 0xF2F1);
+        assert_eq!(Bitfield::from_bits_with_defaults(0).into_bits(), 0xF0F0);
+
+        let bitfield = BitfieldBuilder::new().with_a(0x3).build();
+        assert_eq!(bitfield.into_bits(), 0xF0F3);
+
+        #[bitfield([u8; 3], fill = reserved_ones)]
+        pub struct ArrayBitfield {
+            a: u8,
         }
 
-        let bitfield = Bitfield::new();
-        assert_eq!(bitfield.into_bits(), 0x12);
+        assert_eq!(ArrayBitfield::new().into_bytes(), [0xFF, 0xFF, 0x00]);
     }
 
     #[test]
-    fn bitfield_from_type() {
-        #[bitfield(u32)]
-        pub struct Bitfield {
-            #[bits(default = 0x12)]
+    fn bitfield_layout_assertions() {
+        const CRC_BITS: u32 = 8;
+
+        #[bitfield(u32, assert(mode.offset = 0, enable.offset = 7, crc.bits = CRC_BITS))]
+        pub struct Register {
+            #[bits(7)]
+            mode: u8,
+            enable: bool,
+            crc: u8,
+            #[bits(16)]
+            _reserved: u16,
+        }
+
+        assert_eq!(Register::MODE_OFFSET, 0);
+        assert_eq!(Register::ENABLE_OFFSET, 7);
+        assert_eq!(Register::CRC_BITS, 8);
+
+        #[bitfield(u16, assert(b.offset = 4, _reserved.offset = 12, _reserved.bits = 4))]
+        pub struct ReservedBitfield {
+            #[bits(4)]
             a: u8,
-            #[bits(default = 0x34)]
             b: u8,
-            #[bits(default = 0x56)]
-            c: u8,
-            #[bits(default = 0x78)]
-            d: u8,
+            #[bits(4)]
+            _reserved: u8,
         }
 
-        let bitfield = Bitfield::from(0x11_22_33_44);
+        let bitfield = ReservedBitfieldBuilder::new().with_a(0x1).with_b(0x23).build();
+        assert_eq!(bitfield.into_bits(), 0x0231);
+    }
 
-        assert_eq!(bitfield.a(), 0x44);
-        assert_eq!(bitfield.b(), 0x33);
+    #[test]
+    fn bitfield_enumerated_values() {
+        #[bitfield(u8)]
+        pub struct DisplayControl {
+            #[bits(2, values(Picture = 0, Video = 1, Sleep = 2, Game = 3))]
+            bg_mode: BgMode,
+            #[bits(3, default = Palette::Warm, values(Mono = 0b001, Warm = 0b010, Cold = 0b100))]
+            palette: Palette,
+            #[bits(2, access = ro, default = Status::Ready, values(Idle = 0, Ready = 2))]
+            status: Status,
+            #[bits(1)]
+            _reserved: u8,
+        }
+
+        let mut display_control = DisplayControl::new();
+        assert_eq!(display_control.bg_mode(), BgMode::Picture);
+        assert_eq!(display_control.palette(), Palette::Warm);
+        assert_eq!(display_control.status(), Status::Ready);
+        assert_eq!(display_control.into_bits(), 0b0100_1000);
+
+        display_control.set_bg_mode(BgMode::Game);
+        display_control.set_palette(Palette::Cold);
+        assert_eq!(display_control.bg_mode(), BgMode::Game);
+        assert_eq!(display_control.bg_mode_raw(), 3);
+        assert_eq!(display_control.palette_raw(), 0b100);
+        assert_eq!(display_control.into_bits(), 0b0101_0011);
+
+        // Bits without a variant are read as the first variant.
+        let display_control = DisplayControl::from_bits(0b0011_1100);
+        assert_eq!(display_control.palette(), Palette::Mono);
+        assert_eq!(display_control.palette_raw(), 0b111);
+        assert_eq!(display_control.status(), Status::Idle);
+        assert_eq!(display_control.status_raw(), 0b01);
+
+        let display_control = DisplayControlBuilder::new().with_bg_mode(BgMode::Sleep).build();
+        assert_eq!(display_control.into_bits(), 0b0100_1010);
+        assert_eq!(<BgMode as BitfieldField>::BITS, 2);
+        assert_eq!(BgMode::Video as u8, 1);
+    }
+
+    #[test]
+    fn bitfield_ascii_str_fields() {
+        #[bitfield(u128)]
+        pub struct RomHeader {
+            #[bits(96, default = "NONAME")]
+            title: AsciiStr<12>,
+            maker_code: AsciiStr<2>,
+            version: u8,
+            checksum: u8,
+        }
+
+        let mut rom_header = RomHeader::new();
+        assert_eq!(rom_header.title().as_str(), Ok("NONAME"));
+        assert_eq!(rom_header.maker_code(), "");
+        assert_eq!(rom_header.into_bits() & 0xFF, u128::from(b'N'));
+
+        rom_header.set_title(AsciiStr::new("ZELDA").unwrap());
+        rom_header.set_maker_code("01".try_into().unwrap());
+        assert_eq!(rom_header.title(), "ZELDA");
+        assert_eq!(rom_header.title().as_bytes(), b"ZELDA\0\0\0\0\0\0\0");
+        assert_eq!(rom_header.maker_code().into_bytes(), *b"01");
+        assert_eq!(format!("{:?}", rom_header.title()), "\"ZELDA\"");
+
+        assert_eq!(
+            AsciiStr::<12>::new("THE LEGEND OF ZELDA"),
+            Err("The string is longer than the ASCII string length.")
+        );
+        assert_eq!(AsciiStr::<12>::new("ÉPÉE"), Err("The string contains non-ASCII characters."));
+        assert!(AsciiStr::<2>::from_bytes([b'0', 0xFF]).is_err());
+
+        // Non-ASCII bytes are read as is.
+        let rom_header = RomHeader::from_bits(0x0041_FF42);
+        assert_eq!(rom_header.title().as_bytes()[..4], [b'B', 0xFF, b'A', 0]);
+        assert!(!rom_header.title().is_ascii());
+        assert_eq!(rom_header.title().as_str(), Err("The bytes contain non-ASCII characters."));
+        assert_eq!(format!("{:?}", rom_header.title()), "\"B\\xffA\"");
+
+        let rom_header =
+            RomHeaderBuilder::new().with_maker_code(AsciiStr::from_ascii("8P")).build();
+        assert_eq!(rom_header.title(), "NONAME");
+        assert_eq!(rom_header.maker_code(), "8P");
+        assert_eq!(
+            format!("{rom_header:?}"),
+            "RomHeader { title: \"NONAME\", maker_code: \"8P\", version: 0, checksum: 0 }"
+        );
+        assert_eq!(<AsciiStr<12> as BitfieldField>::BITS, 96);
+    }
+
+    #[test]
+    fn bitfield_arbitrary_int_fields() {
+        #[bitfield(u32)]
+        pub struct Sprite {
+            #[bits(default = 9)]
+            palette: u4,
+            tile: u12,
+            #[bits(default = -3)]
+            x_velocity: i4,
+            y_velocity: Int<i16, 10>,
+            priority: u2,
+        }
+
+        assert_eq!(Sprite::PALETTE_BITS, 4);
+        assert_eq!(Sprite::TILE_OFFSET, 4);
+        assert_eq!(Sprite::Y_VELOCITY_BITS, 10);
+        assert_eq!(Sprite::PRIORITY_OFFSET, 30);
+
+        let mut sprite = Sprite::new();
+        assert_eq!(sprite.palette(), u4::new(9));
+        assert_eq!(sprite.x_velocity().value(), -3);
+        assert_eq!(sprite.into_bits(), 0x000D_0009);
+
+        sprite.set_tile(u12::new(0xABC));
+        sprite.set_y_velocity(Int::<i16, 10>::new(-512));
+        sprite.set_priority(u2::MAX);
+        assert_eq!(sprite.tile().value(), 0xABC);
+        assert_eq!(sprite.y_velocity(), Int::<i16, 10>::MIN);
+        assert_eq!(sprite.priority().value(), 3);
+        assert_eq!(sprite.into_bits(), 0xE00D_ABC9);
+
+        let sprite = Sprite::from_bits(0x3FFF_FFFF);
+        assert_eq!(sprite.x_velocity(), i4::new(-1));
+        assert_eq!(sprite.y_velocity().value(), -1);
+        assert_eq!(sprite.priority(), u2::new(0));
+
+        let sprite = SpriteBuilder::new().with_tile(u12::new(1)).with_palette(u4::new(2)).build();
+        assert_eq!(sprite.into_bits(), 0x000D_0012);
+        assert_eq!(
+            format!("{sprite:?}"),
+            "Sprite { palette: 2, tile: 1, x_velocity: -3, y_velocity: 0, priority: 0 }"
+        );
+        assert_eq!(<u4 as BitfieldField>::BITS, 4);
+        assert_eq!(<UInt<u16, 12> as BitfieldField>::BITS, 12);
+    }
+
+    #[test]
+    fn bitfield_arbitrary_int_fields_const() {
+        #[bitfield(u16)]
+        pub struct Motor {
+            #[bits(default = 5)]
+            speed: u4,
+            #[bits(default = -3)]
+            trim: i4,
+            #[bits(none = 0xF)]
+            gear: Option<u4>,
+            mode: u4,
+        }
+
+        const MOTOR: Motor = Motor::new();
+        const SPEED: u4 = MOTOR.speed();
+        const TRIM: i4 = MOTOR.trim();
+        const fn with_trim(mut motor: Motor, trim: i4) -> Motor {
+            motor.set_trim(trim);
+            motor
+        }
+
+        assert_eq!(SPEED, u4::new(5));
+        assert_eq!(TRIM, i4::new(-3));
+        assert_eq!(MOTOR.gear(), None);
+        assert_eq!(MOTOR.into_bits(), 0x0FD5);
+        assert_eq!(format!("{MOTOR:?}"), "Motor { speed: 5, trim: -3, gear: None, mode: 0 }");
+
+        let motor = with_trim(MOTOR, i4::MIN);
+        assert_eq!(motor.trim().value(), -8);
+        assert_eq!(motor.into_bits(), 0x0F85);
+    }
+
+    #[test]
+    fn arbitrary_int_values() {
+        assert_eq!(u4::MAX.value(), 15);
+        assert_eq!(i4::MIN.value(), -8);
+        assert_eq!(i12::MAX.value(), 2047);
+        assert_eq!(u4::try_new(16), Err("The value doesn't fit in the bits of the integer."));
+        assert_eq!(i4::try_from(-9), Err("The value doesn't fit in the bits of the integer."));
+        assert_eq!(u4::new_truncated(0x1F), u4::new(0xF));
+        assert_eq!(i4::new_truncated(0xF), i4::new(-1));
+
+        assert_eq!(u4::new(7) + u4::new(8), u4::MAX);
+        assert_eq!(u4::new(7) * u4::new(2), u4::new(14));
+        assert_eq!(u4::new(7) / u4::new(2), u4::new(3));
+        assert_eq!(u4::new(7) % u4::new(2), u4::new(1));
+        assert_eq!(i4::new(-7) - i4::new(1), i4::MIN);
+        assert_eq!(-i4::new(7), i4::new(-7));
+        assert_eq!(!u4::new(0b0101), u4::new(0b1010));
+        assert_eq!(!i4::new(0), i4::new(-1));
+        assert_eq!(u4::new(0b0110) & u4::new(0b0011), u4::new(0b0010));
+        assert_eq!(u4::new(0b0110) | u4::new(0b0011), u4::new(0b0111));
+        assert_eq!(u4::new(0b0110) ^ u4::new(0b0011), u4::new(0b0101));
+
+        let mut value = u4::new(14);
+        value += u4::new(1);
+        assert_eq!(value, u4::MAX);
+        assert_eq!(value.checked_add(u4::new(1)), None);
+        assert_eq!(value.wrapping_add(u4::new(2)), u4::new(1));
+        assert_eq!(value.saturating_add(u4::new(2)), u4::MAX);
+        assert_eq!(i4::MIN.saturating_sub(i4::new(1)), i4::MIN);
+        assert_eq!(i4::MAX.wrapping_add(i4::new(1)), i4::MIN);
+        assert_eq!(u4::new(3).checked_div(u4::new(0)), None);
+
+        assert_eq!(u16::from(u4::new(9)), 9);
+        assert_eq!(i64::from(i12::new(-100)), -100);
+        assert_eq!(
+            format!("{:?} {} {:#X}", u12::new(0xABC), i4::new(-2), u4::new(10)),
+            "2748 -2 0xA"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn arbitrary_int_overflow() {
+        let _ = u4::new(15) + u4::new(1);
+    }
+
+    #[test]
+    fn bitfield_alias_fields() {
+        #[bitfield(u32)]
+        pub struct Registers {
+            ax: u16,
+            #[bits(8, alias_of = ax)]
+            al: u8,
+            #[bits(alias_of = ax, 8..=15)]
+            ah: u8,
+            #[bits(4, alias_of = flags, at = 20)]
+            flags_high: u8,
+            flags: u16,
+        }
+
+        assert_eq!(Registers::AX_OFFSET, 0);
+        assert_eq!(Registers::AL_BITS, 8);
+        assert_eq!(Registers::AL_OFFSET, 0);
+        assert_eq!(Registers::AH_BITS, 8);
+        assert_eq!(Registers::AH_OFFSET, 8);
+        assert_eq!(Registers::FLAGS_OFFSET, 16);
+        assert_eq!(Registers::FLAGS_HIGH_OFFSET, 20);
+
+        let mut registers = Registers::new();
+        registers.set_ax(0x1234);
+        assert_eq!(registers.al(), 0x34);
+        assert_eq!(registers.ah(), 0x12);
+
+        registers.set_ah(0xAB);
+        assert_eq!(registers.ax(), 0xAB34);
+        registers.set_al(0xCD);
+        assert_eq!(registers.ax(), 0xABCD);
+
+        registers.set_flags_high(0xF);
+        assert_eq!(registers.flags(), 0xF0);
+        assert_eq!(registers.into_bits(), 0x00F0_ABCD);
+
+        let registers = RegistersBuilder::new().with_ax(0x1111).with_ah(0x22).build();
+        assert_eq!(registers.ax(), 0x2211);
+        assert_eq!(format!("{:?}", registers), "Registers { ax: 8721, flags: 0 }");
+    }
+
+    #[test]
+    fn bitfield_alias_field_defaults() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(default = 0x1234)]
+            a: u16,
+            #[bits(4, alias_of = a, at = 12)]
+            a_high: u8,
+        }
+
+        let bitfield = Bitfield::new();
+        assert_eq!(bitfield.a_high(), 0x1);
+        assert_eq!(Bitfield::from_bits(0xF000).a_high(), 0xF);
+    }
+
+    #[test]
+    fn bitfield_split_fields() {
+        #[bitfield(u32)]
+        pub struct BranchInstruction {
+            #[bits(7)]
+            opcode: u8,
+            #[bits(3, at = 12)]
+            funct3: u8,
+            #[bits(5, at = 15)]
+            rs1: u8,
+            #[bits(5, at = 20)]
+            rs2: u8,
+            #[bits(segments = [31, 7, 30..=25, 11..=8], shift = 1)]
+            imm: i16,
+        }
+
+        // beq x1, x2, -4
+        let instruction = BranchInstruction::from_bits(0xFE20_8EE3);
+        assert_eq!(instruction.opcode(), 0x63);
+        assert_eq!(instruction.funct3(), 0);
+        assert_eq!(instruction.rs1(), 1);
+        assert_eq!(instruction.rs2(), 2);
+        assert_eq!(instruction.imm(), -4);
+
+        let mut instruction = BranchInstruction::new();
+        instruction.set_opcode(0x63);
+        instruction.set_rs1(1);
+        instruction.set_rs2(2);
+        instruction.set_imm(-4);
+        assert_eq!(instruction.into_bits(), 0xFE20_8EE3);
+
+        instruction.set_imm(2048);
+        assert_eq!(instruction.imm(), 2048);
+        assert_eq!(instruction.into_bits(), 0x0020_80E3);
+        assert!(instruction.checked_set_imm(4096).is_err());
+        assert_eq!(instruction.checked_set_imm(-4096), Ok(()));
+        assert_eq!(instruction.imm(), -4096);
+        assert_eq!(
+            instruction.checked_set_imm(3),
+            Err("Value has bits set below the shift of the field.")
+        );
+        assert_eq!(instruction.imm(), -4096);
+        assert!(BranchInstructionBuilder::new().checked_with_imm(-3).is_err());
+
+        let instruction = BranchInstructionBuilder::new().with_opcode(0x63).with_imm(-4).build();
+        assert_eq!(instruction.into_bits(), 0xFE00_0EE3);
+    }
+
+    #[test]
+    fn bitfield_split_field_defaults() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(segments = [3..=0, 15..=12], default = 0xAB)]
+            a: u8,
+            #[bits(4..=11, default = 0xCD)]
+            b: u8,
+        }
+
+        let bitfield = Bitfield::new();
+        assert_eq!(bitfield.a(), 0xAB);
+        assert_eq!(bitfield.into_bits(), 0xBCDA);
+        assert_eq!(Bitfield::from_bits(0x1002).a(), 0x21);
+    }
+
+    #[test]
+    fn bitfield_debug() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(default = 0x12)]
+            a: u8,
+            #[bits(default = 0x34)]
+            b: u8,
+            #[bits(default = 0x56)]
+            c: u8,
+            #[bits(default = 0x78)]
+            d: u8,
+        }
+
+        let bitfield = Bitfield::new();
+        let debug_str = format!("{:?}", bitfield);
+
+        assert_eq!(debug_str, "Bitfield { a: 18, b: 52, c: 86, d: 120 }");
+    }
+
+    #[test]
+    fn bitfield_debug_msb() {
+        #[bitfield(u32, order = Msb)]
+        pub struct Bitfield {
+            #[bits(default = 0x12)]
+            a: u8,
+            #[bits(default = 0x34)]
+            b: u8,
+            #[bits(default = 0x56)]
+            c: u8,
+            #[bits(default = 0x78)]
+            d: u8,
+        }
+
+        let bitfield = Bitfield::new();
+        let debug_str = format!("{:?}", bitfield);
+
+        assert_eq!(debug_str, "Bitfield { a: 18, b: 52, c: 86, d: 120 }");
+    }
+
+    #[test]
+    fn bitfield_field_access_write_only_can_write() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(default = 0x12, access = wo)]
+            a: u32,
+        }
+
+        Bitfield::new().set_a(0x34);
+        BitfieldBuilder::new().with_a(0x34).build();
+    }
+
+    #[test]
+    fn bitfield_field_access_read_only_can_read() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(default = 0x12, access = ro)]
+            a: u32,
+        }
+
+        assert_eq!(Bitfield::new().a(), 0x12);
+        assert_eq!(BitfieldBuilder::new().build().a(), 0x12)
+    }
+
+    #[test]
+    fn bitfield_from_bits_field_access_read_only() {
+        #[bitfield(u8)]
+        struct Bitfield {
+            #[bits(4)]
+            rw: u8,
+            #[bits(4, access = ro)]
+            ro: u8,
+        }
+
+        let bar1 = Bitfield::from_bits(0xFF);
+        assert_eq!(0xF, bar1.rw());
+        assert_eq!(0xF, bar1.ro());
+
+        let bar2 = BitfieldBuilder::new().with_ro(0xF).build();
+        assert_eq!(0xF, bar2.ro());
+    }
+
+    #[test]
+    fn bitfield_from_bits_sets_read_only_fields() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(default = 0x12)]
+            rw_field: u8,
+            #[bits(default = 0x34, access = ro)]
+            ro_field: u8,
+            no_default_field: u8,
+            #[bits(default = 0x78)]
+            another_field: u8,
+        }
+
+        let bitfield = Bitfield::from_bits(0x11_22_33_44);
+        assert_eq!(bitfield.rw_field(), 0x44);
+        assert_eq!(bitfield.ro_field(), 0x33);
+        assert_eq!(bitfield.no_default_field(), 0x22);
+        assert_eq!(bitfield.another_field(), 0x11);
+        assert_eq!(bitfield.into_bits(), 0x11_22_33_44);
+    }
+
+    #[test]
+    fn bitfield_from_bits_sets_read_only_bool_fields() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(4)]
+            a: u8,
+            #[bits(access = ro)]
+            ro_bool: bool,
+            #[bits(3)]
+            b: u8,
+            #[bits(8)]
+            c: u8,
+        }
+
+        let bitfield_true = Bitfield::from_bits(0xFFFF);
+        assert!(bitfield_true.ro_bool());
+        let bitfield_false = Bitfield::from_bits(0xFFEF);
+        assert!(!bitfield_false.ro_bool());
+    }
+
+    #[test]
+    fn bitfield_field_access_na() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(default = 0x12, access = na)]
+            a: u32,
+        }
+
+        let bitfield = Bitfield::new();
+        assert_eq!(bitfield.into_bits(), 0x12);
+    }
+
+    #[test]
+    fn bitfield_from_type() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(default = 0x12)]
+            a: u8,
+            #[bits(default = 0x34)]
+            b: u8,
+            #[bits(default = 0x56)]
+            c: u8,
+            #[bits(default = 0x78)]
+            d: u8,
+        }
+
+        let bitfield = Bitfield::from(0x11_22_33_44);
+
+        assert_eq!(bitfield.a(), 0x44);
+        assert_eq!(bitfield.b(), 0x33);
         assert_eq!(bitfield.c(), 0x22);
         assert_eq!(bitfield.d(), 0x11);
         assert_eq!(bitfield.into_bits(), 0x11_22_33_44);
@@ -2060,8 +2852,11 @@ mod tests {
             C(u8),
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -2069,7 +2864,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -2104,8 +2899,11 @@ mod tests {
             C(u8),
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -2113,7 +2911,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -2148,8 +2946,11 @@ mod tests {
             C(u8),
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -2157,7 +2958,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -2184,8 +2985,11 @@ mod tests {
             Black,
         }
 
-        impl Colour {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Colour {
+            const BITS: u32 = 1;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0 => Self::White,
                     1 => Self::Black,
@@ -2193,7 +2997,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self as u8
             }
         }
@@ -2208,8 +3012,11 @@ mod tests {
             Pawn,
         }
 
-        impl PieceType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for PieceType {
+            const BITS: u32 = 3;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0 => Self::King,
                     1 => Self::Queen,
@@ -2221,7 +3028,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 self as u8
             }
         }
@@ -2885,8 +3692,11 @@ mod tests {
             B,
         }
 
-        impl CustomType {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for CustomType {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 match bits {
                     0x3 => Self::A,
                     0x4 => Self::B,
@@ -2894,7 +3704,7 @@ mod tests {
                 }
             }
 
-            const fn into_bits(self) -> u8 {
+            fn into_raw(self) -> u8 {
                 match self {
                     Self::A => 0x3,
                     Self::B => 0x4,
@@ -4330,11 +5140,15 @@ mod tests {
     fn custom_numeric_type_newtype_getter() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4353,11 +5167,15 @@ mod tests {
     fn custom_numeric_type_newtype_setter() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4377,11 +5195,15 @@ mod tests {
     fn custom_numeric_type_newtype_bitfield_into_bits() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4408,11 +5230,15 @@ mod tests {
     fn custom_numeric_type_newtype_bitfield_from_bits() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4438,11 +5264,15 @@ mod tests {
     fn custom_numeric_type_newtype_round_trip() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u16);
-        impl Millivolts {
-            const fn from_bits(bits: u16) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 16;
+            type Raw = u16;
+
+            fn from_raw(bits: u16) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u16 {
+
+            fn into_raw(self) -> u16 {
                 self.0
             }
         }
@@ -4467,11 +5297,15 @@ mod tests {
     fn custom_numeric_type_non_trivial_transformation_inverted_bits() {
         #[derive(Debug, PartialEq)]
         struct InvertedBits(u8);
-        impl InvertedBits {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for InvertedBits {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(!bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 !self.0
             }
         }
@@ -4496,11 +5330,15 @@ mod tests {
     fn custom_numeric_type_non_trivial_transformation_half_resolution() {
         #[derive(Debug, PartialEq)]
         struct HalfRes(u8);
-        impl HalfRes {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for HalfRes {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits.wrapping_mul(2))
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0 / 2
             }
         }
@@ -4525,11 +5363,15 @@ mod tests {
     fn custom_numeric_type_default_value() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4551,11 +5393,15 @@ mod tests {
     fn custom_numeric_type_new_without_defaults() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4577,11 +5423,15 @@ mod tests {
     fn custom_numeric_type_builder() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4604,11 +5454,15 @@ mod tests {
     fn custom_numeric_type_checked_setter() {
         #[derive(Debug, PartialEq)]
         struct SmallValue(u8);
-        impl SmallValue {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for SmallValue {
+            const BITS: u32 = 4;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4634,11 +5488,15 @@ mod tests {
     fn custom_numeric_type_read_only_field() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4660,11 +5518,15 @@ mod tests {
     fn custom_numeric_type_write_only_field() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4686,11 +5548,15 @@ mod tests {
     fn custom_numeric_type_msb_order() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
                 self.0
             }
         }
@@ -4716,227 +5582,1148 @@ mod tests {
     fn custom_numeric_type_from_traits() {
         #[derive(Debug, PartialEq)]
         struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                self.0
+            }
+        }
+
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(8)]
+            voltage: Millivolts,
+            status: u8,
+        }
+
+        let bitfield = Bitfield::from(0x55_AA_u16);
+        assert_eq!(bitfield.voltage(), Millivolts(0xAA));
+        assert_eq!(bitfield.status(), 0x55);
+
+        let raw: u16 = bitfield.into();
+        assert_eq!(raw, 0x55_AA);
+    }
+
+    #[test]
+    fn custom_numeric_type_multiple_custom_fields() {
+        #[derive(Debug, PartialEq)]
+        struct Millivolts(u8);
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                self.0
+            }
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct MilliAmps(u8);
+        impl BitfieldField for MilliAmps {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                self.0
+            }
+        }
+
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(8)]
+            voltage: Millivolts,
+            #[bits(8)]
+            current: MilliAmps,
+            #[bits(16)]
+            reserved: u16,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_voltage(Millivolts(0x12));
+        bitfield.set_current(MilliAmps(0x34));
+
+        assert_eq!(bitfield.voltage(), Millivolts(0x12));
+        assert_eq!(bitfield.current(), MilliAmps(0x34));
+        assert_eq!(bitfield.into_bits(), 0x0000_3412);
+    }
+
+    #[test]
+    fn custom_numeric_type_mixed_with_integer_fields() {
+        #[derive(Debug, PartialEq)]
+        struct Celsius(i8);
+        impl BitfieldField for Celsius {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(bits as i8)
+            }
+
+            fn into_raw(self) -> u8 {
+                self.0 as u8
+            }
+        }
+
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(8)]
+            temperature: Celsius,
+            #[bits(8)]
+            humidity: u8,
+            #[bits(16)]
+            pressure: u16,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_temperature(Celsius(-10));
+        bitfield.set_humidity(75);
+        bitfield.set_pressure(1013);
+
+        assert_eq!(bitfield.temperature(), Celsius(-10));
+        assert_eq!(bitfield.humidity(), 75);
+        assert_eq!(bitfield.pressure(), 1013);
+
+        let raw = bitfield.into_bits();
+        assert_eq!((raw & 0xFF) as u8, (-10i8) as u8);
+    }
+
+    #[test]
+    fn custom_numeric_type_from_bits_with_defaults() {
+        #[derive(Debug, PartialEq)]
+        struct Millivolts(u8);
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                self.0
+            }
+        }
+
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(8, default = Millivolts(0xAB))]
+            voltage: Millivolts,
+            #[bits(8)]
+            status: u8,
+        }
+
+        let bitfield = Bitfield::from_bits_with_defaults(0x55_FF);
+        assert_eq!(bitfield.voltage(), Millivolts(0xAB));
+        assert_eq!(bitfield.status(), 0x55);
+    }
+
+    #[test]
+    fn custom_numeric_type_full_integration() {
+        #[derive(Debug, PartialEq)]
+        struct Millivolts(u8);
+        impl BitfieldField for Millivolts {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
                 Self(bits)
             }
-            const fn into_bits(self) -> u8 {
+
+            fn into_raw(self) -> u8 {
+                self.0
+            }
+        }
+
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(8)]
+            a: Millivolts,
+            b: u8,
+            #[bits(8)]
+            c: Millivolts,
+            d: u8,
+        }
+
+        let original = BitfieldBuilder::new()
+            .with_a(Millivolts(0x11))
+            .with_b(0x22)
+            .with_c(Millivolts(0x33))
+            .with_d(0x44)
+            .build();
+
+        let raw = original.into_bits();
+        assert_eq!(raw, 0x44_33_22_11);
+
+        let recovered = Bitfield::from_bits(raw);
+        assert_eq!(recovered.a(), Millivolts(0x11));
+        assert_eq!(recovered.b(), 0x22);
+        assert_eq!(recovered.c(), Millivolts(0x33));
+        assert_eq!(recovered.d(), 0x44);
+    }
+
+    #[test]
+    fn custom_numeric_type_clear_bits_uses_from_bits() {
+        #[derive(Debug, PartialEq)]
+        struct InvertedBits(u8);
+        impl BitfieldField for InvertedBits {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(!bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                !self.0
+            }
+        }
+
+        #[bitfield(u8, bit_ops = true)]
+        pub struct Bitfield {
+            #[bits(8)]
+            value: InvertedBits,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_value(InvertedBits(0xAA));
+        assert_eq!(bitfield.value(), InvertedBits(0xAA));
+
+        bitfield.clear_bits();
+        assert_eq!(bitfield.value(), InvertedBits(0xFF));
+    }
+
+    #[test]
+    fn custom_numeric_type_write_bits_uses_from_bits() {
+        #[derive(Debug, PartialEq)]
+        struct InvertedBits(u8);
+        impl BitfieldField for InvertedBits {
+            const BITS: u32 = 8;
+            type Raw = u8;
+
+            fn from_raw(bits: u8) -> Self {
+                Self(!bits)
+            }
+
+            fn into_raw(self) -> u8 {
+                !self.0
+            }
+        }
+
+        #[bitfield(u8, bit_ops = true)]
+        pub struct Bitfield {
+            #[bits(8)]
+            value: InvertedBits,
+        }
+
+        let mut bitfield = Bitfield::new();
+
+        bitfield.write_bits(0x55u8);
+        assert_eq!(bitfield.value(), InvertedBits(0xAA));
+
+        assert_eq!(bitfield.into_bits(), 0x55u8);
+    }
+
+    #[test]
+    fn custom_field_type_signed_raw() {
+        #[derive(Debug, PartialEq)]
+        struct Offset(i8);
+        impl BitfieldField for Offset {
+            const BITS: u32 = 4;
+            type Raw = i8;
+
+            fn from_raw(bits: i8) -> Self {
+                Self((bits << 4) >> 4)
+            }
+
+            fn into_raw(self) -> i8 {
                 self.0
             }
         }
 
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(4)]
+            offset: Offset,
+            #[bits(4)]
+            b: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_offset(Offset(-3));
+        bitfield.set_b(0xF);
+
+        assert_eq!(bitfield.offset(), Offset(-3));
+        assert_eq!(bitfield.b(), 0xF);
+        assert_eq!(bitfield.into_bits(), 0xFD);
+    }
+
+    #[test]
+    fn custom_field_type_bool_raw() {
+        #[derive(Debug, PartialEq)]
+        enum Power {
+            Off,
+            On,
+        }
+        impl BitfieldField for Power {
+            const BITS: u32 = 1;
+            type Raw = bool;
+
+            fn from_raw(bits: bool) -> Self {
+                if bits { Self::On } else { Self::Off }
+            }
+
+            fn into_raw(self) -> bool {
+                self == Self::On
+            }
+        }
+
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(1)]
+            power: Power,
+            #[bits(7)]
+            b: u8,
+        }
+
+        let mut bitfield = Bitfield::from_bits(0x80);
+        assert_eq!(bitfield.power(), Power::Off);
+        bitfield.set_power(Power::On);
+        assert_eq!(bitfield.power(), Power::On);
+        assert_eq!(bitfield.into_bits(), 0x81);
+    }
+
+    #[test]
+    fn nested_bitfield_field_trait() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            a: u8,
+            b: u8,
+        }
+
+        assert_eq!(<Bitfield as BitfieldField>::BITS, 16);
+
+        let bitfield = <Bitfield as BitfieldField>::from_raw(0x1234);
+        assert_eq!(bitfield.a(), 0x34);
+        assert_eq!(bitfield.b(), 0x12);
+        assert_eq!(bitfield.into_raw(), 0x1234);
+    }
+
+    #[test]
+    fn nested_bitfield_field_trait_without_from_into_bits() {
+        #[bitfield(u8, from_into_bits = false)]
+        pub struct NestedBitfield {
+            #[bits(4)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+        }
+
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(8)]
+            nested: NestedBitfield,
+            c: u8,
+        }
+
+        let bitfield = Bitfield::from_bits(0xAB_21);
+        assert_eq!(bitfield.nested().a(), 0x1);
+        assert_eq!(bitfield.nested().b(), 0x2);
+        assert_eq!(bitfield.c(), 0xAB);
+    }
+
+    #[test]
+    fn bitflag_field_trait() {
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum Mode {
+            #[base]
+            Off = 0,
+            Low = 1,
+            High = 5,
+        }
+
+        #[bitflag(u16)]
+        #[derive(Debug, PartialEq)]
+        enum Single {
+            #[base]
+            Only = 0,
+        }
+
+        assert_eq!(<Mode as BitfieldField>::BITS, 3);
+        assert_eq!(<Single as BitfieldField>::BITS, 1);
+        assert_eq!(<Mode as BitfieldField>::from_raw(5), Mode::High);
+        assert_eq!(<Mode as BitfieldField>::from_raw(7), Mode::Off);
+        assert_eq!(Mode::Low.into_raw(), 1);
+    }
+
+    #[test]
+    fn nested_bitfield_and_bitflag_fields_keep_other_accessors_const() {
+        #[bitfield(u8)]
+        pub struct Inner {
+            #[bits(4, default = 0x5)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+        }
+
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum Mode {
+            #[base]
+            Off = 0,
+            Low = 1,
+            High = 2,
+        }
+
+        #[bitfield(u16)]
+        pub struct Outer {
+            #[bits(default = Inner::new())]
+            inner: Inner,
+            #[bits(2, default = Mode::High)]
+            mode: Mode,
+            #[bits(2, values(Off = 0, On = 1))]
+            power: Power,
+            #[bits(4)]
+            flags: u8,
+        }
+
+        const fn flags_of(outer: &Outer) -> u8 {
+            outer.flags()
+        }
+
+        const fn power_of(outer: &Outer) -> Power {
+            outer.power()
+        }
+
+        const fn with_flags_and_power(mut outer: Outer) -> Outer {
+            outer.set_flags(0xA);
+            outer.set_power(Power::On);
+            outer
+        }
+
+        let outer = with_flags_and_power(Outer::new());
+        let from_bits = Outer::from_bits(0x0132);
+
+        assert_eq!(outer.into_bits(), 0xA605);
+        assert_eq!(outer.inner().a(), 0x5);
+        assert_eq!(outer.mode(), Mode::High);
+        assert_eq!(flags_of(&outer), 0xA);
+        assert_eq!(power_of(&outer), Power::On);
+        assert_eq!(from_bits.inner().b(), 0x3);
+        assert_eq!(from_bits.mode(), Mode::Low);
+    }
+
+    #[test]
+    fn nested_bitfield_wider_field_bits() {
+        #[bitfield(u8)]
+        pub struct Inner {
+            #[bits(4)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+        }
+
+        #[bitfield(u32)]
+        pub struct Outer {
+            #[bits(16)]
+            inner: Inner,
+            c: u16,
+        }
+
+        let mut outer = Outer::from_bits(0x1234_FF21);
+        assert_eq!(outer.inner().a(), 0x1);
+        assert_eq!(outer.inner().b(), 0x2);
+        assert_eq!(outer.c(), 0x1234);
+
+        outer.set_inner(Inner::from_bits(0x43));
+        assert_eq!(outer.into_bits(), 0x1234_0043);
+    }
+
+    #[test]
+    fn nested_bitfield_inferred_bits() {
+        #[bitfield(u8)]
+        pub struct NestedBitfield {
+            #[bits(4)]
+            a: u8,
+            #[bits(4)]
+            b: u8,
+        }
+
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            a: u8,
+            #[bits(default = NestedBitfield::from_bits(0x21))]
+            nested: NestedBitfield,
+            c: u16,
+        }
+
+        assert_eq!(Bitfield::NESTED_BITS, 8);
+        assert_eq!(Bitfield::NESTED_OFFSET, 8);
+        assert_eq!(Bitfield::C_BITS, 16);
+        assert_eq!(Bitfield::C_OFFSET, 16);
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.into_bits(), 0x2100);
+
+        bitfield.set_c(0xABCD);
+        bitfield.set_nested(NestedBitfield::from_bits(0x43));
+        assert_eq!(bitfield.nested().a(), 0x3);
+        assert_eq!(bitfield.nested().b(), 0x4);
+        assert_eq!(bitfield.into_bits(), 0xABCD_4300);
+    }
+
+    #[test]
+    fn nested_bitfield_inferred_bits_msb() {
+        #[bitfield(u16)]
+        pub struct NestedBitfield {
+            a: u8,
+            b: u8,
+        }
+
+        #[bitfield(u32, order = Msb)]
+        pub struct Bitfield {
+            a: u8,
+            nested: NestedBitfield,
+            c: u8,
+        }
+
+        assert_eq!(Bitfield::A_OFFSET, 24);
+        assert_eq!(Bitfield::NESTED_OFFSET, 8);
+        assert_eq!(Bitfield::C_OFFSET, 0);
+
+        let bitfield = Bitfield::from_bits(0x1234_5678);
+        assert_eq!(bitfield.a(), 0x12);
+        assert_eq!(bitfield.nested().into_bits(), 0x3456);
+        assert_eq!(bitfield.c(), 0x78);
+    }
+
+    #[test]
+    fn bitflag_inferred_bits() {
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum Mode {
+            #[base]
+            Off = 0,
+            Low = 1,
+            High = 5,
+        }
+
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(default = Mode::High)]
+            mode: Mode,
+            #[bits(5)]
+            _reserved: u8,
+        }
+
+        assert_eq!(Bitfield::MODE_BITS, 3);
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.mode(), Mode::High);
+        assert_eq!(bitfield.into_bits(), 0x5);
+
+        bitfield.set_mode(Mode::Low);
+        assert_eq!(bitfield.into_bits(), 0x1);
+    }
+
+    #[test]
+    fn bitfield_float_fields() {
+        #[bitfield(u128)]
+        pub struct Bitfield {
+            #[bits(default = 1.5)]
+            a: f32,
+            #[bits(default = -2.25)]
+            b: f64,
+            c: u32,
+        }
+
+        assert_eq!(Bitfield::A_BITS, 32);
+        assert_eq!(Bitfield::B_BITS, 64);
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.a(), 1.5);
+        assert_eq!(bitfield.b(), -2.25);
+        assert_eq!(bitfield.into_bits() as u32, 1.5f32.to_bits());
+        assert_eq!((bitfield.into_bits() >> 32) as u64, (-2.25f64).to_bits());
+
+        bitfield.set_a(f32::INFINITY);
+        bitfield.set_b(0.1);
+        bitfield.set_c(0x1234_5678);
+        assert_eq!(bitfield.a(), f32::INFINITY);
+        assert_eq!(bitfield.b(), 0.1);
+        assert_eq!(bitfield.c(), 0x1234_5678);
+
+        let bitfield = Bitfield::from_bits(f32::NAN.to_bits() as u128);
+        assert!(bitfield.a().is_nan());
+        assert_eq!(bitfield.b(), 0.0);
+
+        const BITFIELD: Bitfield = Bitfield::new();
+        const A: f32 = BITFIELD.a();
+        assert_eq!(A, 1.5);
+        assert_eq!(format!("{BITFIELD:?}"), "Bitfield { a: 1.5, b: -2.25, c: 0 }");
+    }
+
+    #[test]
+    fn bitfield_fixed_point_fields() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(12, fixed = 4)]
+            a: u16,
+            #[bits(12, fixed = 8)]
+            b: i16,
+            #[bits(8)]
+            c: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        bitfield.set_a(2.5);
+        assert_eq!(bitfield.a_raw(), 0x28);
+        assert_eq!(bitfield.a(), 2.5);
+
+        // Values are rounded to the nearest raw value.
+        bitfield.set_b(-1.0 / 3.0);
+        assert_eq!(bitfield.b_raw(), -85);
+        assert_eq!(bitfield.b(), -0.33203125);
+
+        bitfield.set_a_raw(0xFFF);
+        assert_eq!(bitfield.a(), 255.9375);
+        assert_eq!(
+            bitfield.checked_set_a(256.0),
+            Err("Value is too big to fit within the field bits.")
+        );
+        assert_eq!(
+            bitfield.checked_set_a(-0.5),
+            Err("Value is too big to fit within the field bits.")
+        );
+        assert_eq!(
+            bitfield.checked_set_b(8.0),
+            Err("Value is too big to fit within the field bits.")
+        );
+        assert_eq!(
+            bitfield.checked_set_b(f64::NAN),
+            Err("Value is too big to fit within the field bits.")
+        );
+        assert_eq!(bitfield.checked_set_b(-8.0), Ok(()));
+        assert_eq!(bitfield.b_raw(), -2048);
+        assert_eq!(bitfield.a(), 255.9375);
+    }
+
+    #[test]
+    fn bitfield_scaled_fields() {
+        #[bitfield(u32)]
+        pub struct Bitfield {
+            #[bits(10, scale = 0.1, offset = -40, unit = "C", default = 650)]
+            temperature: u16,
+            #[bits(12, scale = 2, offset = 1.5)]
+            pressure: u16,
+            #[bits(10, offset = 100)]
+            altitude: i16,
+        }
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.temperature_raw(), 650);
+        assert_eq!(bitfield.temperature(), 25.0);
+
+        bitfield.set_temperature(-12.34);
+        assert_eq!(bitfield.temperature_raw(), 277);
+        assert_eq!(
+            bitfield.checked_set_temperature(62.4),
+            Err("Value is too big to fit within the field bits.")
+        );
+        assert_eq!(bitfield.checked_set_temperature(-40.0), Ok(()));
+        assert_eq!(bitfield.temperature_raw(), 0);
+
+        bitfield.set_pressure(11.5);
+        assert_eq!(bitfield.pressure_raw(), 5);
+        assert_eq!(bitfield.pressure(), 11.5);
+
+        bitfield.set_altitude(-400.0);
+        assert_eq!(bitfield.altitude_raw(), -500);
+        assert_eq!(bitfield.altitude(), -400.0);
+        assert_eq!(
+            bitfield.checked_set_altitude(700.0),
+            Err("Value is too big to fit within the field bits.")
+        );
+
+        let bitfield = BitfieldBuilder::new().with_temperature(25.0).with_pressure_raw(3).build();
+        assert_eq!(bitfield.temperature_raw(), 650);
+        assert_eq!(bitfield.pressure(), 7.5);
+        assert!(BitfieldBuilder::new().checked_with_temperature(100.0).is_err());
+    }
+
+    #[test]
+    fn bitfield_scaled_fields_debug() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(8, scale = 0.5, offset = -10, unit = "mV", default = 30)]
+            voltage: u8,
+            #[bits(8, fixed = 2, default = -6)]
+            ratio: i8,
+        }
+
+        let bitfield = Bitfield::new();
+        assert_eq!(format!("{bitfield:?}"), "Bitfield { voltage: 5.0 mV, ratio: -1.5 }");
+    }
+
+    #[test]
+    fn bitfield_field_range_constraint() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(4, range = 1..=12, default = 1)]
+            divider: u8,
+            #[bits(6, range = -8..8)]
+            trim: i8,
+            #[bits(6)]
+            _reserved: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.checked_set_divider(12), Ok(()));
+        assert_eq!(
+            bitfield.checked_set_divider(0),
+            Err("Value is outside the valid values of the field.")
+        );
+        assert_eq!(
+            bitfield.checked_set_divider(13),
+            Err("Value is outside the valid values of the field.")
+        );
+        assert_eq!(bitfield.checked_set_trim(-8), Ok(()));
+        assert_eq!(
+            bitfield.checked_set_trim(8),
+            Err("Value is outside the valid values of the field.")
+        );
+        assert_eq!(bitfield.divider(), 12);
+        assert_eq!(bitfield.trim(), -8);
+
+        // Unchecked setters don't check the constraint.
+        bitfield.set_divider(0);
+        assert_eq!(bitfield.divider(), 0);
+
+        assert!(BitfieldBuilder::new().checked_with_divider(13).is_err());
+        assert_eq!(BitfieldBuilder::new().checked_with_divider(3).unwrap().build().divider(), 3);
+    }
+
+    #[test]
+    fn bitfield_field_values_constraint() {
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(5, values = [4, 8, 16], default = 8)]
+            burst_length: u8,
+            #[bits(3, access = ro, values = [0, 1])]
+            mode: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.burst_length(), 8);
+        assert_eq!(bitfield.checked_set_burst_length(16), Ok(()));
+        assert_eq!(
+            bitfield.checked_set_burst_length(12),
+            Err("Value is outside the valid values of the field.")
+        );
+
+        assert!(BitfieldBuilder::new().checked_with_mode(2).is_err());
+        assert_eq!(BitfieldBuilder::new().checked_with_mode(1).unwrap().build().mode(), 1);
+
+        assert_eq!(Bitfield::checked_from_bits(0b001_00100).unwrap().burst_length(), 4);
+        assert_eq!(
+            Bitfield::checked_from_bits(0b001_00101).unwrap_err(),
+            "A field is outside the valid values of its `range` or `values` constraint."
+        );
+        assert!(Bitfield::checked_from_bits(0b010_00100).is_err());
+    }
+
+    #[test]
+    fn bitfield_scaled_field_constraint() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(10, scale = 0.5, range = 0..=200)]
+            level: u16,
+            #[bits(6)]
+            _reserved: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.checked_set_level(100.0), Ok(()));
+        assert_eq!(
+            bitfield.checked_set_level(100.5),
+            Err("Value is outside the valid values of the field.")
+        );
+        assert_eq!(bitfield.level_raw(), 200);
+    }
+
+    #[test]
+    fn bitfield_sign_magnitude_field() {
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(5, encoding = sign_magnitude, default = -3)]
+            a: i8,
+            #[bits(3)]
+            _reserved: u8,
+        }
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.a(), -3);
+        assert_eq!(bitfield.into_bits(), 0b1_0011);
+
+        bitfield.set_a(15);
+        assert_eq!(bitfield.into_bits(), 0b0_1111);
+        assert_eq!(bitfield.checked_set_a(-15), Ok(()));
+        assert_eq!(bitfield.into_bits(), 0b1_1111);
+        assert_eq!(bitfield.a(), -15);
+        assert_eq!(
+            bitfield.checked_set_a(-16),
+            Err("Value is too big to fit within the field bits.")
+        );
+
+        // Negative zero is decoded as zero.
+        assert_eq!(Bitfield::from_bits(0b1_0000).a(), 0);
+    }
+
+    #[test]
+    fn bitfield_offset_binary_field() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(12, encoding = offset_binary)]
+            sample: i16,
+            #[bits(4)]
+            _reserved: u8,
+        }
+
+        assert_eq!(Bitfield::from_bits(0x800).sample(), 0);
+        assert_eq!(Bitfield::from_bits(0x000).sample(), -2048);
+        assert_eq!(Bitfield::from_bits(0xFFF).sample(), 2047);
+
+        // Fields without a default value start as zero.
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.sample(), 0);
+        assert_eq!(bitfield.into_bits(), 0x800);
+        assert_eq!(Bitfield::default().sample(), 0);
+        assert_eq!(BitfieldBuilder::new().build().sample(), 0);
+        assert_eq!(Bitfield::new_without_defaults().sample(), -2048);
+
+        bitfield.set_sample(-1);
+        assert_eq!(bitfield.into_bits(), 0x7FF);
+        assert_eq!(bitfield.checked_set_sample(2047), Ok(()));
+        assert_eq!(bitfield.into_bits(), 0xFFF);
+        assert_eq!(
+            bitfield.checked_set_sample(2048),
+            Err("Value is too big to fit within the field bits.")
+        );
+    }
+
+    #[test]
+    fn bitfield_bcd_fields() {
         #[bitfield(u16)]
         pub struct Bitfield {
-            #[bits(8)]
-            voltage: Millivolts,
-            status: u8,
+            #[bits(8, encoding = bcd, default = 59)]
+            minutes: u8,
+            #[bits(8, encoding = bcd, access = ro, default = 23)]
+            hours: u8,
         }
 
-        let bitfield = Bitfield::from(0x55_AA_u16);
-        assert_eq!(bitfield.voltage(), Millivolts(0xAA));
-        assert_eq!(bitfield.status(), 0x55);
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.minutes(), 59);
+        assert_eq!(bitfield.hours(), 23);
+        assert_eq!(bitfield.into_bits(), 0x23_59);
 
-        let raw: u16 = bitfield.into();
-        assert_eq!(raw, 0x55_AA);
+        bitfield.set_minutes(7);
+        assert_eq!(bitfield.into_bits(), 0x23_07);
+        assert_eq!(
+            bitfield.checked_set_minutes(100),
+            Err("Value is too big to fit within the field bits.")
+        );
+
+        let bitfield = BitfieldBuilder::new().with_minutes(42).build();
+        assert_eq!(bitfield.into_bits(), 0x23_42);
+        assert_eq!(Bitfield::from_bits(0x12_34).hours(), 12);
+        assert_eq!(bitfield.checked_minutes(), Ok(42));
+
+        let bitfield = Bitfield::from_bits(0x12_FF);
+        assert_eq!(bitfield.checked_minutes(), Err("The field bits contain a non-decimal digit."));
+        assert_eq!(
+            Bitfield::from_bits(0x1A_00).checked_hours(),
+            Err("The field bits contain a non-decimal digit.")
+        );
+        assert_eq!(Bitfield::from_bits(0x12_00).checked_hours(), Ok(12));
     }
 
     #[test]
-    fn custom_numeric_type_multiple_custom_fields() {
-        #[derive(Debug, PartialEq)]
-        struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
-                Self(bits)
-            }
-            const fn into_bits(self) -> u8 {
-                self.0
-            }
+    fn bitfield_gray_code_field() {
+        #[bitfield(u8)]
+        pub struct Bitfield {
+            #[bits(4, encoding = gray)]
+            position: u8,
+            #[bits(4)]
+            _reserved: u8,
         }
 
-        #[derive(Debug, PartialEq)]
-        struct MilliAmps(u8);
-        impl MilliAmps {
-            const fn from_bits(bits: u8) -> Self {
-                Self(bits)
-            }
-            const fn into_bits(self) -> u8 {
-                self.0
-            }
+        let mut bitfield = Bitfield::new();
+        for position in 0..16u8 {
+            bitfield.set_position(position);
+            assert_eq!(bitfield.into_bits(), position ^ (position >> 1));
+            assert_eq!(bitfield.position(), position);
         }
+        assert_eq!(Bitfield::from_bits(0b1000).position(), 15);
+        assert_eq!(
+            bitfield.checked_set_position(16),
+            Err("Value is too big to fit within the field bits.")
+        );
+    }
 
-        #[bitfield(u32)]
+    #[test]
+    fn bitfield_active_low_fields() {
+        #[bitfield(u8)]
         pub struct Bitfield {
-            #[bits(8)]
-            voltage: Millivolts,
-            #[bits(8)]
-            current: MilliAmps,
-            #[bits(16)]
-            reserved: u16,
+            #[bits(1, encoding = active_low, default = false)]
+            enable: bool,
+            #[bits(3, encoding = active_low)]
+            level: u8,
+            #[bits(1, encoding = active_low)]
+            reset: bool,
+            #[bits(3)]
+            _reserved: u8,
         }
 
         let mut bitfield = Bitfield::new();
-        bitfield.set_voltage(Millivolts(0x12));
-        bitfield.set_current(MilliAmps(0x34));
+        assert!(!bitfield.enable());
+        assert_eq!(bitfield.into_bits(), 0b0001_1111);
 
-        assert_eq!(bitfield.voltage(), Millivolts(0x12));
-        assert_eq!(bitfield.current(), MilliAmps(0x34));
-        assert_eq!(bitfield.into_bits(), 0x0000_3412);
+        // Fields without a default value start as `false` or zero.
+        assert!(!bitfield.reset());
+        assert_eq!(bitfield.level(), 0);
+        assert_eq!(Bitfield::default().into_bits(), 0b0001_1111);
+        assert_eq!(BitfieldBuilder::new().build().into_bits(), 0b0001_1111);
+
+        // Bits are used as is when not applying defaults.
+        assert!(Bitfield::from_bits(0).reset());
+        assert_eq!(Bitfield::new_without_defaults().level(), 7);
+
+        bitfield.set_enable(true);
+        bitfield.set_level(2);
+        bitfield.set_reset(false);
+        assert_eq!(bitfield.into_bits(), 0b0001_1010);
+
+        bitfield.clear_level();
+        assert_eq!(bitfield.level(), 7);
+        bitfield.invert_level();
+        assert_eq!(bitfield.level(), 0);
+        assert_eq!(bitfield.level_inverted(), 7);
     }
 
     #[test]
-    fn custom_numeric_type_mixed_with_integer_fields() {
-        #[derive(Debug, PartialEq)]
-        struct Celsius(i8);
-        impl Celsius {
-            const fn from_bits(bits: u8) -> Self {
-                Self(bits as i8)
-            }
-            const fn into_bits(self) -> u8 {
-                self.0 as u8
-            }
+    fn bitfield_encoded_fields_debug() {
+        #[bitfield(u16)]
+        pub struct Bitfield {
+            #[bits(8, encoding = bcd, default = 42)]
+            a: u8,
+            #[bits(4, encoding = sign_magnitude, default = -5)]
+            b: i8,
+            #[bits(4)]
+            c: u8,
         }
 
+        assert_eq!(format!("{:?}", Bitfield::new()), "Bitfield { a: 42, b: -5, c: 0 }");
+    }
+
+    #[test]
+    fn bitfield_field_endian() {
         #[bitfield(u32)]
         pub struct Bitfield {
-            #[bits(8)]
-            temperature: Celsius,
-            #[bits(8)]
-            humidity: u8,
-            #[bits(16)]
-            pressure: u16,
+            #[bits(16, endian = little, default = 0x1234)]
+            a: u16,
+            #[bits(16, endian = little)]
+            b: i32,
         }
 
         let mut bitfield = Bitfield::new();
-        bitfield.set_temperature(Celsius(-10));
-        bitfield.set_humidity(75);
-        bitfield.set_pressure(1013);
+        assert_eq!(bitfield.a(), 0x1234);
+        assert_eq!(bitfield.into_bits(), 0x0000_3412);
 
-        assert_eq!(bitfield.temperature(), Celsius(-10));
-        assert_eq!(bitfield.humidity(), 75);
-        assert_eq!(bitfield.pressure(), 1013);
+        bitfield.set_b(-2);
+        assert_eq!(bitfield.b(), -2);
+        assert_eq!(bitfield.into_bits(), 0xFEFF_3412);
+        assert_eq!(
+            bitfield.checked_set_b(0x8000),
+            Err("Value is too big to fit within the field bits.")
+        );
 
-        let raw = bitfield.into_bits();
-        assert_eq!((raw & 0xFF) as u8, (-10i8) as u8);
+        let bitfield = Bitfield::from_bits(0x0080_CDAB);
+        assert_eq!(bitfield.a(), 0xABCD);
+        assert_eq!(bitfield.b(), -32768);
+        assert_eq!(Bitfield::A_BITS, 16);
+        assert_eq!(Bitfield::B_OFFSET, 16);
     }
 
     #[test]
-    fn custom_numeric_type_from_bits_with_defaults() {
-        #[derive(Debug, PartialEq)]
-        struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
-                Self(bits)
-            }
-            const fn into_bits(self) -> u8 {
-                self.0
-            }
-        }
-
-        #[bitfield(u16)]
+    fn bitfield_half_precision_float_fields() {
+        #[bitfield(u32)]
         pub struct Bitfield {
-            #[bits(8, default = Millivolts(0xAB))]
-            voltage: Millivolts,
-            #[bits(8)]
-            status: u8,
+            #[bits(16, half = true, default = 1.5)]
+            a: f32,
+            #[bits(half = true, default = -65504.0)]
+            b: f64,
         }
 
-        let bitfield = Bitfield::from_bits_with_defaults(0x55_FF);
-        assert_eq!(bitfield.voltage(), Millivolts(0xAB));
-        assert_eq!(bitfield.status(), 0x55);
+        assert_eq!(Bitfield::A_BITS, 16);
+        assert_eq!(Bitfield::B_BITS, 16);
+
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.a(), 1.5);
+        assert_eq!(bitfield.b(), -65504.0);
+        assert_eq!(bitfield.into_bits(), 0xFBFF_3E00);
+
+        // Values are rounded to the nearest half-precision float.
+        bitfield.set_a(0.1);
+        assert_eq!(bitfield.a(), 0.099975586);
+        assert_eq!(bitfield.into_bits() & 0xFFFF, 0x2E66);
+
+        // Values too large for a half-precision float become infinite.
+        bitfield.set_b(100000.0);
+        assert_eq!(bitfield.b(), f64::INFINITY);
+
+        let bitfield = Bitfield::from_bits(0x7E00_0001);
+        assert!(bitfield.b().is_nan());
+        assert_eq!(bitfield.a(), 5.9604645e-8);
+        assert_eq!(format!("{:?}", Bitfield::new()), "Bitfield { a: 1.5, b: -65504.0 }");
     }
 
     #[test]
-    fn custom_numeric_type_full_integration() {
-        #[derive(Debug, PartialEq)]
-        struct Millivolts(u8);
-        impl Millivolts {
-            const fn from_bits(bits: u8) -> Self {
-                Self(bits)
-            }
-            const fn into_bits(self) -> u8 {
-                self.0
-            }
-        }
+    fn bitfield_non_zero_fields() {
+        use core::num::{NonZeroU8, NonZeroU16};
 
         #[bitfield(u32)]
         pub struct Bitfield {
-            #[bits(8)]
-            a: Millivolts,
-            b: u8,
-            #[bits(8)]
-            c: Millivolts,
-            d: u8,
+            #[bits(4)]
+            irq: NonZeroU8,
+            #[bits(12)]
+            _reserved: u16,
+            length: NonZeroU16,
         }
 
-        let original = BitfieldBuilder::new()
-            .with_a(Millivolts(0x11))
-            .with_b(0x22)
-            .with_c(Millivolts(0x33))
-            .with_d(0x44)
-            .build();
+        assert_eq!(Bitfield::IRQ_BITS, 4);
+        assert_eq!(Bitfield::LENGTH_BITS, 16);
 
-        let raw = original.into_bits();
-        assert_eq!(raw, 0x44_33_22_11);
+        let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.into_bits(), 0);
+        assert_eq!(bitfield.checked_irq(), Err("The field bits are zero."));
+        assert_eq!(bitfield.checked_length(), Err("The field bits are zero."));
+
+        bitfield.set_irq(NonZeroU8::new(9).unwrap());
+        bitfield.set_length(NonZeroU16::new(0x1234).unwrap());
+        assert_eq!(bitfield.irq().get(), 9);
+        assert_eq!(bitfield.checked_irq(), Ok(NonZeroU8::new(9).unwrap()));
+        assert_eq!(bitfield.length().get(), 0x1234);
+        assert_eq!(bitfield.into_bits(), 0x1234_0009);
+        assert_eq!(
+            bitfield.checked_set_irq(NonZeroU8::new(16).unwrap()),
+            Err("Value is too big to fit within the field bits.")
+        );
 
-        let recovered = Bitfield::from_bits(raw);
-        assert_eq!(recovered.a(), Millivolts(0x11));
-        assert_eq!(recovered.b(), 0x22);
-        assert_eq!(recovered.c(), Millivolts(0x33));
-        assert_eq!(recovered.d(), 0x44);
+        let bitfield = Bitfield::from_bits(0x0001_0000);
+        assert_eq!(bitfield.checked_irq(), Err("The field bits are zero."));
+        assert_eq!(bitfield.length().get(), 1);
+        assert_eq!(format!("{bitfield:?}"), "Bitfield { irq: 0, _reserved: 0, length: 1 }");
     }
 
     #[test]
-    fn custom_numeric_type_clear_bits_uses_from_bits() {
-        #[derive(Debug, PartialEq)]
-        struct InvertedBits(u8);
-        impl InvertedBits {
-            const fn from_bits(bits: u8) -> Self {
-                Self(!bits)
-            }
-            const fn into_bits(self) -> u8 {
-                !self.0
-            }
-        }
-
-        #[bitfield(u8, bit_ops = true)]
+    #[should_panic(expected = "The field bits are zero.")]
+    fn bitfield_non_zero_field_getter_panics_on_zero() {
+        #[bitfield(u8)]
         pub struct Bitfield {
-            #[bits(8)]
-            value: InvertedBits,
+            a: core::num::NonZeroU8,
         }
 
-        let mut bitfield = Bitfield::new();
-        bitfield.set_value(InvertedBits(0xAA));
-        assert_eq!(bitfield.value(), InvertedBits(0xAA));
-
-        bitfield.clear_bits();
-        assert_eq!(bitfield.value(), InvertedBits(0xFF));
+        Bitfield::new().a();
     }
 
     #[test]
-    fn custom_numeric_type_write_bits_uses_from_bits() {
-        #[derive(Debug, PartialEq)]
-        struct InvertedBits(u8);
-        impl InvertedBits {
-            const fn from_bits(bits: u8) -> Self {
-                Self(!bits)
-            }
-            const fn into_bits(self) -> u8 {
-                !self.0
-            }
-        }
+    fn bitfield_option_fields() {
+        use core::num::NonZeroU8;
 
-        #[bitfield(u8, bit_ops = true)]
+        #[bitfield(u32)]
         pub struct Bitfield {
-            #[bits(8)]
-            value: InvertedBits,
+            #[bits(8, none = 0xFF, default = None)]
+            slot: Option<u8>,
+            #[bits(4, none = 0)]
+            irq: Option<NonZeroU8>,
+            #[bits(4, none = 0xF)]
+            mode: Option<Mode>,
+            #[bits(16, none = 0xFFFF)]
+            length: Option<u16>,
+        }
+
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum Mode {
+            #[base]
+            Off = 0,
+            On = 1,
         }
 
+        assert_eq!(Bitfield::SLOT_BITS, 8);
+        assert_eq!(Bitfield::LENGTH_BITS, 16);
+
+        // Fields without a default value default to `None`.
         let mut bitfield = Bitfield::new();
+        assert_eq!(bitfield.slot(), None);
+        assert_eq!(bitfield.irq(), None);
+        assert_eq!(bitfield.mode(), None);
+        assert_eq!(bitfield.length(), None);
+        assert_eq!(bitfield.into_bits(), 0xFFFF_F0FF);
+        assert_eq!(Bitfield::new_without_defaults().length(), Some(0));
+
+        bitfield.set_slot(Some(3));
+        bitfield.set_irq(NonZeroU8::new(5));
+        bitfield.set_mode(None);
+        bitfield.set_length(None);
+        assert_eq!(bitfield.slot(), Some(3));
+        assert_eq!(bitfield.irq(), NonZeroU8::new(5));
+        assert_eq!(bitfield.mode(), None);
+        assert_eq!(bitfield.length(), None);
+        assert_eq!(bitfield.into_bits(), 0xFFFF_F503);
+
+        // Custom wrapped types show their bits.
+        assert_eq!(
+            format!("{bitfield:?}"),
+            "Bitfield { slot: Some(3), irq: Some(5), mode: 15, length: None }"
+        );
+        assert_eq!(
+            format!("{:?}", Bitfield::from_bits(0x0001_00FF)),
+            "Bitfield { slot: None, irq: None, mode: 0, length: Some(1) }"
+        );
 
-        bitfield.write_bits(0x55u8);
-        assert_eq!(bitfield.value(), InvertedBits(0xAA));
+        assert_eq!(
+            bitfield.checked_set_slot(Some(0xFF)),
+            Err("Value has the bits of the `none` value of the field.")
+        );
+        assert_eq!(bitfield.checked_set_slot(None), Ok(()));
+        assert_eq!(bitfield.slot(), None);
+        assert_eq!(
+            bitfield.checked_set_irq(NonZeroU8::new(16)),
+            Err("Value is too big to fit within the field bits.")
+        );
 
-        assert_eq!(bitfield.into_bits(), 0x55u8);
+        let bitfield = BitfieldBuilder::new().with_slot(Some(7)).with_mode(Some(Mode::On)).build();
+        assert_eq!(bitfield.into_bits(), 0xFFFF_1007);
+
+        let bitfield = Bitfield::from_bits(0xFFFF_0000);
+        assert_eq!(bitfield.slot(), Some(0));
+        assert_eq!(bitfield.irq(), None);
+        assert_eq!(bitfield.length(), None);
     }
 
     #[test]
@@ -5648,6 +7435,237 @@ mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn bitfield_generic_marker_parameter() {
+        trait Direction {}
+
+        #[derive(Debug)]
+        struct Rx;
+        impl Direction for Rx {}
+
+        #[derive(Debug)]
+        struct Tx;
+        impl Direction for Tx {}
+
+        #[bitfield(u16)]
+        struct Status<D: Direction> {
+            ready: bool,
+            #[bits(15, default = 3)]
+            count: u16,
+        }
+
+        let mut rx_status = Status::<Rx>::new();
+        rx_status.set_ready(true);
+        assert_eq!(rx_status.count(), 3);
+        assert_eq!(rx_status.into_bits(), 0x0007);
+
+        // The marker doesn't need to be `Copy` for the bitfield to be.
+        let rx_status_copy = rx_status;
+        assert_eq!(rx_status.into_bits(), rx_status_copy.into_bits());
+
+        let tx_status: Status<Tx> = StatusBuilder::new().with_ready(false).with_count(9).build();
+        assert_eq!(tx_status.into_bits(), 0x0012);
+        assert_eq!(Status::<Tx>::from_bits(0x0013).count(), 9);
+        assert_eq!(u16::from(Status::<Tx>::from(0x0013u16)), 0x0013);
+        assert_eq!(Status::<Rx>::default().count(), 3);
+        assert_eq!(format!("{:?}", tx_status), "Status { ready: 0, count: 9 }");
+        assert_eq!(Status::<Rx>::COUNT_OFFSET, 1);
+    }
+
+    #[test]
+    fn bitfield_generic_parameter_with_ignored_field() {
+        #[derive(Debug, Default, PartialEq, Clone)]
+        struct Port<const N: usize>;
+
+        #[bitfield(u8)]
+        struct Register<P: Default, const N: usize> {
+            value: u8,
+            #[bits(ignore = true)]
+            port: P,
+        }
+
+        let mut register = Register::<Port<2>, 2>::new();
+        register.set_value(0x12);
+        assert_eq!(register.value(), 0x12);
+        assert_eq!(register.port, Port::<2>);
+        assert_eq!(register.clone().into_bits(), 0x12);
+    }
+
+    #[test]
+    fn bitfield_const_generic_parameter() {
+        #[bitfield(u16)]
+        struct Lane<const N: u8> {
+            #[bits(default = N)]
+            id: u8,
+            width: u8,
+        }
+
+        let lane = Lane::<5>::new();
+        assert_eq!(lane.id(), 5);
+        assert_eq!(LaneBuilder::<7>::new().with_width(2).build().into_bits(), 0x0207);
+    }
+
+    #[test]
+    fn bitfield_generic_field_type() {
+        #[bitflag(u8)]
+        #[derive(Debug, PartialEq)]
+        enum DescriptorKind {
+            #[base]
+            Data = 0,
+            Code = 1,
+            System = 2,
+        }
+
+        #[bitfield(u8)]
+        struct Descriptor<T>
+        where
+            T: BitfieldField<Raw = u8>,
+        {
+            #[bits(4)]
+            kind: T,
+            #[bits(4)]
+            flags: u8,
+        }
+
+        let mut descriptor = Descriptor::<DescriptorKind>::new();
+        descriptor.set_kind(DescriptorKind::System);
+        descriptor.set_flags(0xA);
+        assert_eq!(descriptor.kind(), DescriptorKind::System);
+        assert_eq!(descriptor.into_bits(), 0xA2);
+        assert_eq!(format!("{:?}", descriptor), "Descriptor { kind: 2, flags: 10 }");
+        assert_eq!(
+            DescriptorBuilder::new().with_kind(DescriptorKind::Code).build().into_bits(),
+            0x01
+        );
+    }
+
+    #[test]
+    fn tagged_union_bitfield() {
+        #[bitfield(u32, tag_bits = 7)]
+        #[derive(PartialEq)]
+        enum Instruction {
+            #[tag = 0b0110011]
+            R {
+                #[bits(5)]
+                rd: u8,
+                #[bits(3)]
+                funct3: u8,
+                #[bits(5)]
+                rs1: u8,
+                #[bits(5)]
+                rs2: u8,
+                #[bits(7)]
+                funct7: u8,
+            },
+            #[tag = 0b0010011]
+            I {
+                #[bits(5)]
+                rd: u8,
+                #[bits(3)]
+                funct3: u8,
+                #[bits(5)]
+                rs1: u8,
+                #[bits(12)]
+                imm: i16,
+            },
+        }
+
+        // add x3, x1, x2
+        let add = Instruction::from_bits(0x002081B3).unwrap();
+        let Instruction::R(r) = add else {
+            panic!("Expected the R variant");
+        };
+        assert_eq!(r.tag(), 0b0110011);
+        assert_eq!(r.rd(), 3);
+        assert_eq!(r.rs1(), 1);
+        assert_eq!(r.rs2(), 2);
+        assert_eq!(add.tag(), 0b0110011);
+        assert_eq!(add.into_bits(), 0x002081B3);
+
+        // addi x1, x0, -1
+        let mut addi = InstructionI::new();
+        addi.set_rd(1);
+        addi.set_imm(-1);
+        assert_eq!(addi.tag(), 0b0010011);
+        let instruction = Instruction::from(addi);
+        assert_eq!(instruction.into_bits(), 0xFFF00093);
+        assert_eq!(Instruction::from_bits(0xFFF00093), Ok(Instruction::I(addi)));
+        assert_eq!(
+            format!("{:?}", instruction),
+            "I(InstructionI { tag: 19, rd: 1, funct3: 0, rs1: 0, imm: 4095 })"
+        );
+
+        assert_eq!(Instruction::from_bits(0x0000007F), Err("The tag doesn't select a variant."));
+        assert_eq!(Instruction::try_from(0x002081B3).unwrap().tag(), 0b0110011);
+        assert_eq!(u32::from(instruction), 0xFFF00093);
+    }
+
+    #[test]
+    fn tagged_union_bitfield_into_bits_writes_tag() {
+        #[bitfield(u16, tag_bits = 4, order = msb)]
+        enum Frame {
+            #[tag = 0x1]
+            Data {
+                payload: u8,
+                #[bits(4)]
+                channel: u8,
+            },
+            #[tag = 0x2]
+            Ack {
+                #[bits(12)]
+                sequence: u16,
+            },
+        }
+
+        let data = FrameData::new_without_defaults();
+        assert_eq!(data.tag(), 0);
+        assert_eq!(Frame::Data(data).into_bits(), 0x1000);
+
+        let ack = FrameAckBuilder::new().with_sequence(0xABC).build();
+        assert_eq!(Frame::from(ack).into_bits(), 0x2ABC);
+        assert_eq!(Frame::from_bits(0x2ABC).unwrap().tag(), 0x2);
+        assert!(
+            matches!(Frame::from_bits(0x1234), Ok(Frame::Data(data)) if data.payload() == 0x23)
+        );
+    }
+
+    #[test]
+    fn tagged_union_bitfield_declared_tag_field() {
+        #[bitfield(u16, tag_bits = 4)]
+        #[derive(PartialEq)]
+        enum Packet {
+            #[tag = 0xA]
+            Data {
+                #[bits(12)]
+                payload: u16,
+                /// The type of the packet.
+                tag: u8,
+            },
+            #[tag = 0x5]
+            Ack {
+                sequence: u8,
+                #[bits(4)]
+                _reserved: u8,
+                tag: u8,
+            },
+        }
+
+        assert_eq!(PacketData::TAG_OFFSET, 12);
+        assert_eq!(PacketAck::TAG_OFFSET, 12);
+
+        let data = PacketData::new();
+        assert_eq!(data.tag(), 0xA);
+        assert_eq!(data.into_bits(), 0xA000);
+
+        let packet = Packet::from_bits(0xA123).unwrap();
+        assert!(matches!(packet, Packet::Data(data) if data.payload() == 0x123));
+        assert_eq!(
+            Packet::from_bits(0x5042),
+            Ok(Packet::Ack(PacketAckBuilder::new().with_sequence(0x42).build()))
+        );
+        assert_eq!(Packet::from(PacketAck::new_without_defaults()).into_bits(), 0x5000);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn compile_error_cases() {
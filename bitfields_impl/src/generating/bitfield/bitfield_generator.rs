@@ -8,12 +8,16 @@ use crate::generating::bitfield::features::clear_bit_ops_feature::ClearBitOpsFea
 use crate::generating::bitfield::features::debug_trait_feature::DebugTraitFeature;
 use crate::generating::bitfield::features::default_trait_feature::DefaultTraitFeature;
 use crate::generating::bitfield::features::field_constants_feature::FieldConstantsFeature;
+use crate::generating::bitfield::features::field_enums_feature::FieldEnumsFeature;
 use crate::generating::bitfield::features::field_getters_feature::FieldGettersFeature;
 use crate::generating::bitfield::features::field_setters_feature::FieldSettersFeature;
+use crate::generating::bitfield::features::field_trait_feature::FieldTraitFeature;
 use crate::generating::bitfield::features::from_into_bits_feature::FromIntoBitsFeature;
 use crate::generating::bitfield::features::from_traits_feature::FromTraitsFeature;
 use crate::generating::bitfield::features::invert_bit_ops_feature::InvertBitOpsFeature;
+use crate::generating::bitfield::features::layout_assertions_feature::LayoutAssertionsFeature;
 use crate::generating::bitfield::features::new_functions_feature::NewFunctionsFeature;
+use crate::generating::bitfield::features::scaled_field_accessors_feature::ScaledFieldAccessorsFeature;
 use crate::generating::bitfield::features::set_get_bit_ops_feature::SetGetBitOpsFeature;
 use crate::generating::bitfield::features::write_bit_ops_feature::WriteBitOpsFeature;
 use crate::parsing::bitfields::bitfield::Bitfield;
@@ -42,14 +46,18 @@ fn get_enabled_features(bitfield: &Bitfield) -> Vec<Box<dyn Feature>> {
     let mut features: Vec<Box<dyn Feature>> = vec![
         Box::new(BitfieldStructFeatureGenerator),
         Box::new(FieldConstantsFeature),
+        Box::new(FieldEnumsFeature),
         Box::new(NewFunctionsFeature),
         Box::new(FieldSettersFeature),
         Box::new(FieldGettersFeature),
+        Box::new(ScaledFieldAccessorsFeature),
         Box::new(FromTraitsFeature),
         Box::new(DebugTraitFeature),
         Box::new(FromIntoBitsFeature),
         Box::new(BuilderFeature),
         Box::new(DefaultTraitFeature),
+        Box::new(FieldTraitFeature),
+        Box::new(LayoutAssertionsFeature),
         Box::new(WriteBitOpsFeature),
         Box::new(SetGetBitOpsFeature),
         Box::new(ClearBitOpsFeature),
@@ -86,7 +94,9 @@ fn do_generate_bitfield(
     bitfield: &Bitfield,
     generated_features: &GeneratedFeatures,
 ) -> TokenStream {
-    let bitfield_name_tokens = bitfield.name_tokens();
+    let bitfield_type_tokens = bitfield.type_tokens();
+    let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+    let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
 
     let features_above_impl_block = &generated_features.above_features;
     let features_inside_impl_block = &generated_features.inside_features;
@@ -95,7 +105,7 @@ fn do_generate_bitfield(
     quote! {
          #( #features_above_impl_block )*
             #[allow(clippy::manual_swap)]
-            impl #bitfield_name_tokens {
+            impl #impl_generics_tokens #bitfield_type_tokens #where_clause_tokens {
                 #( #features_inside_impl_block )*
             }
             #( #features_below_impl_block )*
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
-use crate::parsing::bitfields::bitfield::Bitfield;
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
 use crate::parsing::common::to_tokens::ToTokens;
 
 /// Generator for bitfield struct that hold the backend data.
@@ -43,11 +43,17 @@ impl BitfieldStructFeatureGenerator {
     /// based on whether the bitfield has ignored fields.
     fn generate_bitfield_struct_feature_tokens(bitfield: &Bitfield) -> TokenStream {
         let attributes_token_stream = Self::generate_struct_attributes_tokens(bitfield);
+        let clone_trait_tokens = Self::generate_clone_trait_tokens(bitfield);
 
-        if Self::should_generate_name_field_struct_tokens(bitfield) {
+        let struct_tokens = if Self::should_generate_name_field_struct_tokens(bitfield) {
             Self::generate_named_field_struct_tokens(&attributes_token_stream, bitfield)
         } else {
             Self::generate_tuple_struct_tokens(&attributes_token_stream, bitfield)
+        };
+
+        quote! {
+            #struct_tokens
+            #clone_trait_tokens
         }
     }
 
@@ -79,7 +85,11 @@ impl BitfieldStructFeatureGenerator {
             });
         }
 
-        if bitfield.arguments().derive_copy() {
+        // Derives would bound the type parameters, which are only held by a
+        // `PhantomData`.
+        if bitfield.arguments().derive_copy()
+            && !bitfield.generic_parameters().has_type_parameters()
+        {
             let is_heap_array = bitfield.arguments().array_heap() && !bitfield.is_integer_backed();
             if is_heap_array {
                 attributes_tokens.push(quote! {
@@ -95,6 +105,89 @@ impl BitfieldStructFeatureGenerator {
         attributes_tokens
     }
 
+    /// Generates the `Clone` and `Copy` implementations for bitfields with type
+    /// parameters. Unlike derives, only ignored fields typed by the type
+    /// parameters bound them.
+    fn generate_clone_trait_tokens(bitfield: &Bitfield) -> Option<TokenStream> {
+        if !bitfield.arguments().derive_copy()
+            || !bitfield.generic_parameters().has_type_parameters()
+        {
+            return None;
+        }
+
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let is_heap_array = bitfield.arguments().array_heap() && !bitfield.is_integer_backed();
+        let clone_where_clause_tokens = Self::generate_ignored_fields_where_clause_tokens(
+            bitfield,
+            &quote! { core::clone::Clone },
+        );
+
+        let bitfield_struct_clone_tokens = if bitfield.has_ignored_fields() {
+            let value_clone_tokens = if is_heap_array {
+                quote! { self.val.clone() }
+            } else {
+                quote! { self.val }
+            };
+            let ignored_field_name_tokens_list =
+                bitfield.ignored_fields().iter().map(Field::name_tokens).collect::<Vec<_>>();
+            quote! {
+                Self {
+                    val: #value_clone_tokens,
+                    #( #ignored_field_name_tokens_list: self.#ignored_field_name_tokens_list.clone(), )*
+                    __phantom: ::core::marker::PhantomData,
+                }
+            }
+        } else {
+            let value_clone_tokens = if is_heap_array {
+                quote! { self.0.clone() }
+            } else {
+                quote! { self.0 }
+            };
+            quote! {
+                Self(#value_clone_tokens, ::core::marker::PhantomData)
+            }
+        };
+
+        let copy_trait_tokens = (!is_heap_array).then(|| {
+            let copy_where_clause_tokens = Self::generate_ignored_fields_where_clause_tokens(
+                bitfield,
+                &quote! { std::marker::Copy },
+            );
+            quote! {
+                impl #impl_generics_tokens std::marker::Copy for #bitfield_type_tokens #copy_where_clause_tokens {}
+            }
+        });
+
+        Some(quote! {
+            impl #impl_generics_tokens core::clone::Clone for #bitfield_type_tokens #clone_where_clause_tokens {
+                fn clone(&self) -> Self {
+                    #bitfield_struct_clone_tokens
+                }
+            }
+
+            #copy_trait_tokens
+        })
+    }
+
+    /// Generates the where clause bounding the ignored fields typed by the type
+    /// parameters with the trait.
+    fn generate_ignored_fields_where_clause_tokens(
+        bitfield: &Bitfield,
+        trait_tokens: &TokenStream,
+    ) -> TokenStream {
+        let predicates_tokens = bitfield
+            .ignored_fields()
+            .iter()
+            .map(|field| field.spanned_data_type_token().to_tokens())
+            .filter(|field_type_tokens| {
+                bitfield.generic_parameters().is_type_parameter_dependent(field_type_tokens)
+            })
+            .map(|field_type_tokens| quote! { #field_type_tokens: #trait_tokens })
+            .collect::<Vec<_>>();
+        bitfield.generic_parameters().where_clause_tokens(&predicates_tokens)
+    }
+
     /// Returns if a named field struct should be generated for the bitfield.
     ///
     /// Required if we have named fields for ignored fields
@@ -125,13 +218,19 @@ impl BitfieldStructFeatureGenerator {
         bitfield: &Bitfield,
     ) -> TokenStream {
         let bitfield_name_tokens = bitfield.name_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
         let backing_field_type_tokens = Self::get_backing_field_type_tokens(bitfield);
+        let phantom_data_type_tokens = bitfield
+            .generic_parameters()
+            .phantom_data_type_tokens()
+            .map(|phantom_data_type_tokens| quote! { , #phantom_data_type_tokens });
         let visibility_tokens = bitfield.visibility().to_tokens();
 
         quote! {
             #( #attributes )*
             #[doc = #STRUCT_DOCUMENTATION]
-            #visibility_tokens struct #bitfield_name_tokens(#backing_field_type_tokens);
+            #visibility_tokens struct #bitfield_name_tokens #impl_generics_tokens (#backing_field_type_tokens #phantom_data_type_tokens) #where_clause_tokens;
         }
     }
 
@@ -151,7 +250,13 @@ impl BitfieldStructFeatureGenerator {
         bitfield: &Bitfield,
     ) -> TokenStream {
         let bitfield_name_tokens = bitfield.name_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
         let backing_field_type_tokens = Self::get_backing_field_type_tokens(bitfield);
+        let phantom_data_field_definition_tokens = bitfield
+            .generic_parameters()
+            .phantom_data_type_tokens()
+            .map(|phantom_data_type_tokens| quote! { __phantom: #phantom_data_type_tokens, });
         let visibility_tokens = bitfield.visibility().to_tokens();
 
         let ignored_fields_field_definitions_tokens =
@@ -160,9 +265,10 @@ impl BitfieldStructFeatureGenerator {
         quote! {
             #( #attributes )*
             #[doc = #STRUCT_DOCUMENTATION]
-            #visibility_tokens struct #bitfield_name_tokens {
+            #visibility_tokens struct #bitfield_name_tokens #impl_generics_tokens #where_clause_tokens {
                 val: #backing_field_type_tokens,
                 #( #ignored_fields_field_definitions_tokens, )*
+                #phantom_data_field_definition_tokens
              }
         }
     }
@@ -3,8 +3,10 @@ use quote::{ToTokens as QuoteToTokens, format_ident, quote};
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
 use crate::generating::bitfield::features::common::generator_helper::{
-    generate_new_function_implementation_tokens, generate_setting_field_from_variable_tokens,
-    get_function_modifier_tokens, get_setter_documentation,
+    generate_new_function_implementation_tokens,
+    generate_scaled_raw_value_overflow_condition_tokens, generate_scaled_value_to_raw_tokens,
+    generate_setting_field_from_variable_tokens, get_field_function_modifier_tokens,
+    get_function_modifier_tokens, get_scaling_documentation, get_setter_documentation,
 };
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
 use crate::parsing::common::to_tokens::ToTokens;
@@ -44,7 +46,10 @@ impl BuilderFeature {
             format_ident!("{}Builder", bitfield.name(), span = bitfield.name_ident().span())
                 .to_token_stream();
         let visibility_tokens = bitfield.visibility().to_tokens();
-        let bitfield_name_tokens = bitfield.name_tokens();
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let type_generics_tokens = bitfield.generic_parameters().type_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
         let builder_setters = Self::generate_builder_setters(bitfield);
         let new_implementation_tokens = Self::generate_builder_new_implementation_tokens(
@@ -57,17 +62,17 @@ impl BuilderFeature {
 
         quote! {
             #[doc = "A builder for the bitfield."]
-            #visibility_tokens struct #builder_ident_tokens {
-                this: #bitfield_name_tokens,
+            #visibility_tokens struct #builder_ident_tokens #impl_generics_tokens #where_clause_tokens {
+                this: #bitfield_type_tokens,
             }
 
-            impl Default for #builder_ident_tokens {
+            impl #impl_generics_tokens Default for #builder_ident_tokens #type_generics_tokens #where_clause_tokens {
                 fn default() -> Self {
                     Self::new()
                 }
             }
 
-            impl #builder_ident_tokens {
+            impl #impl_generics_tokens #builder_ident_tokens #type_generics_tokens #where_clause_tokens {
                 #[doc = "Creates a new bitfield builder instance."]
                 #visibility_tokens #function_modifier_tokens fn new() -> Self {
                     #new_implementation_tokens
@@ -82,7 +87,7 @@ impl BuilderFeature {
                 #builder_setters
 
                 #[doc = "Builds a bitfield instance from the builder."]
-                #visibility_tokens #function_modifier_tokens fn build(self) -> #bitfield_name_tokens {
+                #visibility_tokens #function_modifier_tokens fn build(self) -> #bitfield_type_tokens {
                     self.this
                 }
             }
@@ -138,15 +143,11 @@ impl BuilderFeature {
         bitfield: &Bitfield,
         field: &Field,
     ) -> TokenStream {
-        let visibility_tokens = field.visibility().to_tokens();
-        let function_modifier_tokens = get_function_modifier_tokens(bitfield);
+        let visibility_tokens = field.setter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
         let data_type_tokens = field.spanned_data_type_token().to_tokens();
-        let builder_setter_name_token_stream =
-            format_ident!("with_{}", field.name(), span = field.name_ident().span())
-                .to_token_stream();
-        let builder_checked_setter_name_token_stream =
-            format_ident!("checked_with_{}", field.name(), span = field.name_ident().span())
-                .to_token_stream();
+        let builder_setter_name_token_stream = field.builder_setter_ident_tokens();
+        let builder_checked_setter_name_token_stream = field.checked_builder_setter_ident_tokens();
         let set_bits_logic_tokens = generate_setting_field_from_variable_tokens(
             bitfield, field, /* use_setter= */ true, /* cast_bits= */ true,
             /* check_bit_size= */ false, /* builder_caller= */ true,
@@ -163,6 +164,9 @@ impl BuilderFeature {
             bitfield, field, /* checked_setter= */ true, /* builder_caller= */ true,
         );
 
+        let scaled_setters_tokens =
+            field.is_scaled().then(|| Self::generate_builder_scaled_setters(bitfield, field));
+
         quote! {
             #[doc = #setter_documentation]
             #visibility_tokens #function_modifier_tokens fn #builder_setter_name_token_stream(mut self, bits: #data_type_tokens) -> Self {
@@ -175,6 +179,54 @@ impl BuilderFeature {
                 #checked_set_bits_logic_tokens
                 Ok(self)
             }
+
+            #scaled_setters_tokens
+        }
+    }
+
+    /// Generates the scaled builder setters of a fixed-point or scaled field,
+    /// which convert into the raw builder setter.
+    fn generate_builder_scaled_setters(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.setter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let data_type_tokens = field.spanned_data_type_token().to_tokens();
+        let scaled_setter_ident_tokens = field.scaled_builder_setter_ident_tokens();
+        let checked_scaled_setter_ident_tokens = field.checked_scaled_builder_setter_ident_tokens();
+        let builder_setter_ident_tokens = field.builder_setter_ident_tokens();
+        let checked_builder_setter_ident_tokens = field.checked_builder_setter_ident_tokens();
+        let raw_value_tokens = generate_scaled_value_to_raw_tokens(field, &quote! { value });
+        let overflow_condition_tokens = generate_scaled_raw_value_overflow_condition_tokens(
+            bitfield,
+            field,
+            &quote! { raw },
+            /* builder_caller= */ true,
+        );
+        let scaling_documentation = get_scaling_documentation(field);
+        let documentation = format!(
+            "Sets builder `{}` {scaling_documentation}, rounded to the nearest raw value.",
+            field.name()
+        );
+        let checked_documentation = format!(
+            "Sets builder `{}` {scaling_documentation}, rounded to the nearest raw value. Returns \
+             an error if the raw value is too big to fit within the field bits.",
+            field.name()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #scaled_setter_ident_tokens(self, value: f64) -> Self {
+                let raw = #raw_value_tokens;
+                self.#builder_setter_ident_tokens(raw as #data_type_tokens)
+            }
+
+            #[doc = #checked_documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_scaled_setter_ident_tokens(self, value: f64) -> ::core::result::Result<Self, &'static str> {
+                let raw = #raw_value_tokens;
+                if #overflow_condition_tokens {
+                    return Err("Value is too big to fit within the field bits.");
+                }
+                self.#checked_builder_setter_ident_tokens(raw as #data_type_tokens)
+            }
         }
     }
 }
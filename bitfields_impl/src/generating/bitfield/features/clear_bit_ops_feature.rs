@@ -4,8 +4,9 @@ use quote::{ToTokens as QuoteTokens, format_ident, quote};
 use crate::generating::bitfield::feature::{Feature, FeaturePosition, is_bit_ops_feature_enabled};
 use crate::generating::bitfield::features::common::generator_helper::{
     generate_new_function_implementation_tokens, generate_setting_field_to_default_tokens,
-    generate_setting_field_to_zero_tokens, get_bits_or_bytes_term, get_field_unit_terms,
-    get_function_modifier_tokens,
+    generate_setting_field_to_zero_tokens, get_bits_or_bytes_term,
+    get_field_function_modifier_tokens, get_field_unit_terms, get_function_modifier_tokens,
+    get_split_field_segments_documentation,
 };
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
 use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitOrder;
@@ -90,8 +91,11 @@ impl ClearBitOpsFeature {
             .iter()
             .filter(|field| field.has_setter())
             .map(|field| {
-                let visibility_tokens = bitfield.visibility().to_tokens();
-                let function_modifier_tokens = get_function_modifier_tokens(bitfield);
+                let visibility_tokens = field
+                    .explicit_setter_visibility()
+                    .unwrap_or_else(|| bitfield.visibility())
+                    .to_tokens();
+                let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
                 let documentation = Self::get_clear_field_documentation(bitfield, field);
                 let clear_field_ident_tokens = format_ident!("clear_{}", field.name(), span = field.name_ident().span()).to_token_stream();
                 let clear_field_implementation_tokens =
@@ -108,10 +112,13 @@ impl ClearBitOpsFeature {
     }
 
     fn generate_clear_fields_to_defaults_bit_ops_tokens(bitfield: &Bitfield) -> TokenStream {
-        let visibility_tokens = bitfield.visibility().to_tokens();
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
 
         bitfield.fields().iter().filter(|field| field.has_setter()).filter(|field: &&Field| field.has_default_value()).map(|field| {
+            let visibility_tokens = field
+                .explicit_setter_visibility()
+                .unwrap_or_else(|| bitfield.visibility())
+                .to_tokens();
             let documentation = Self::get_clear_field_documentation(bitfield, field);
             let clear_field_to_default_ident_tokens = format_ident!("clear_{}_to_default", field.name(), span = field.name_ident().span()).to_token_stream();
             let clear_field_to_default_implementation_tokens = generate_setting_field_to_default_tokens(bitfield, field);
@@ -127,14 +134,19 @@ impl ClearBitOpsFeature {
 
     /// Returns clear field documentation.
     fn get_clear_field_documentation(bitfield: &Bitfield, field: &Field) -> String {
-        let offset = field.offset();
+        if let Some(segments_documentation) = get_split_field_segments_documentation(field) {
+            return format!("Clears {segments_documentation}.");
+        }
+        let (Some(offset), Some(bits)) = (field.offset().known(), field.bits().known()) else {
+            return format!("Clears `{}`.", field.name());
+        };
         let (unit, units) = get_field_unit_terms(field);
 
-        if field.bits() == 1 {
+        if bits == 1 {
             return format!("Clears {unit} `{offset}`.");
         }
 
-        let bits_end = offset + field.bits() - 1;
+        let bits_end = offset + bits - 1;
 
         let (documentation_bits_start, documentation_bits_end) =
             if bitfield.arguments().order() == BitOrder::Msb {
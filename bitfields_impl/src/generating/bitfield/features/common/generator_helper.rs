@@ -1,9 +1,16 @@
-use proc_macro2::{Literal, TokenStream};
-use quote::quote;
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
 
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
 use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitOrder;
-use crate::parsing::common::spanned_data_type::{DataType, IntegerType};
+use crate::parsing::bitfields::bits_attribute::bits_arguments::{FieldConstraint, FieldEncoding};
+use crate::parsing::common::bits_expr::BitsExpr;
+use crate::parsing::common::const_expr::ConstExpr;
+use crate::parsing::common::conversion_endian::ConversionEndian;
+use crate::parsing::common::overflow_policy::OverflowPolicy;
+use crate::parsing::common::spanned_data_type::{
+    ArbitraryIntType, ArrayElementType, DataType, IntegerType,
+};
 use crate::parsing::common::to_tokens::ToTokens;
 
 /// Represents the source of the bits being set on a field
@@ -17,8 +24,25 @@ pub enum BitsSource {
 
 /// Returns the function modifiers for generated functions (e.g., `const`).
 pub fn get_function_modifier_tokens(bitfield: &Bitfield) -> Option<TokenStream> {
+    get_bitfield_function_modifier_tokens(bitfield, !bitfield.has_non_const_fields())
+}
+
+/// Returns the function modifiers for generated functions of a single field
+/// (e.g., accessors), which stay `const` when only other fields can't be
+/// converted from const functions.
+pub fn get_field_function_modifier_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+) -> Option<TokenStream> {
+    get_bitfield_function_modifier_tokens(bitfield, !bitfield.is_non_const_field(field))
+}
+
+fn get_bitfield_function_modifier_tokens(
+    bitfield: &Bitfield,
+    const_fields: bool,
+) -> Option<TokenStream> {
     let is_heap_array = bitfield.arguments().array_heap() && !bitfield.is_integer_backed();
-    (!bitfield.has_ignored_fields() && !is_heap_array && supports_const_mut_refs())
+    (!bitfield.has_ignored_fields() && const_fields && !is_heap_array && supports_const_mut_refs())
         .then(|| quote::quote! { const })
 }
 
@@ -52,9 +76,9 @@ pub fn generate_bitfield_struct_initialization_tokens(
             }
         },
         DataType::Array {
-            length,
+            ..
         } => {
-            let length = length as usize;
+            let length = get_bytes_length_tokens(bitfield);
             if bitfield.arguments().array_heap() {
                 quote! {
                     ::std::boxed::Box::new([0u8; #length])
@@ -71,15 +95,26 @@ pub fn generate_bitfield_struct_initialization_tokens(
     };
     let bitfield_reference = get_bitfield_reference_tokens(bitfield, builder_caller);
 
+    let has_type_parameters = bitfield.generic_parameters().has_type_parameters();
     if bitfield.has_ignored_fields() {
         let ignored_fields_default_calls_token_list =
             generate_ignored_fields_default_calls_token_list(bitfield);
+        let phantom_data_tokens = has_type_parameters.then(|| {
+            quote! {
+                __phantom: ::core::marker::PhantomData,
+            }
+        });
         quote! {
             #bitfield_reference {
                 val: #bitfield_value_default,
                 #( #ignored_fields_default_calls_token_list, )*
+                #phantom_data_tokens
             }
         }
+    } else if has_type_parameters {
+        quote! {
+            #bitfield_reference(#bitfield_value_default, ::core::marker::PhantomData)
+        }
     } else {
         quote! {
             #bitfield_reference(#bitfield_value_default)
@@ -136,12 +171,34 @@ pub fn generate_setting_fields_to_default_value_tokens_list(
 
 /// Generates tokens for setting a field to default.
 pub fn generate_setting_field_to_default_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
-    let field_default_value_tokens = field
+    // Encoded fields without a declared default default to the logical zero,
+    // and `Option` fields to `None`.
+    let field_default_value_expr = field
         .arguments()
         .expect("Expected field arguments when setting default value")
-        .default_value_expr()
-        .expect("Expected default value when setting field defaults")
-        .to_tokens();
+        .default_value_expr();
+    let mut field_default_value_tokens = match &field_default_value_expr {
+        Some(field_default_value_expr) => field_default_value_expr.to_tokens(),
+        None if field.none_value().is_some() => quote! { ::core::option::Option::None },
+        None if matches!(
+            field.spanned_data_type_token().data_type(),
+            DataType::Integer(IntegerType::Bool)
+        ) =>
+        {
+            quote! { false }
+        },
+        None => quote! { 0 },
+    };
+
+    // Integer defaults of arbitrary-width integer fields are checked to fit
+    // while parsing.
+    if field.is_arbitrary_int()
+        && matches!(field_default_value_expr, Some(ConstExpr::Literal { .. }))
+    {
+        let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+        field_default_value_tokens =
+            quote! { <#field_data_type_tokens>::new(#field_default_value_tokens) };
+    }
 
     if field.has_setter() {
         return generate_field_setter_call_tokens(
@@ -152,6 +209,22 @@ pub fn generate_setting_field_to_default_tokens(bitfield: &Bitfield, field: &Fie
         );
     }
 
+    // Without a setter, the default value is converted into the bits of the
+    // field by setting the field from a variable.
+    if has_converted_bits(bitfield, field) || field.is_niche() {
+        let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+        let setting_field_from_variable_tokens = generate_setting_field_from_variable_tokens(
+            bitfield, field, /* use_setter= */ false, /* cast_bits= */ true,
+            /* check_bit_size= */ false, /* builder_caller= */ false,
+        );
+        return quote! {
+            {
+                let bits: #field_data_type_tokens = #field_default_value_tokens;
+                #setting_field_from_variable_tokens
+            }
+        };
+    }
+
     generate_setting_field_without_setter_tokens(
         bitfield,
         field,
@@ -188,23 +261,43 @@ pub fn generate_setting_field_to_zero_tokens(bitfield: &Bitfield, field: &Field)
                 quote! { 0 }
             }
         },
-        DataType::Custom => {
-            let custom_field_data_type_tokens = field.spanned_data_type_token().to_tokens();
-            quote! { #custom_field_data_type_tokens::from_bits(0) }
+        DataType::Custom if is_byte_ascii_str_field(bitfield, field) => {
+            let length_tokens = get_ascii_str_length_tokens(field);
+            generate_ascii_str_from_bytes_tokens(field, quote! { [0u8; #length_tokens] })
         },
+        DataType::Custom => generate_custom_field_from_raw_tokens(field, quote! { 0 }),
         DataType::Array {
-            length,
+            ..
         } => {
-            if bitfield.is_integer_backed() {
+            let len = get_array_length_tokens(field);
+            if field.is_packed_array() {
+                if field.has_custom_data_type() {
+                    let element_zero_tokens = generate_element_zero_tokens(field);
+                    quote! { ::core::array::from_fn::<_, #len, _>(|_| #element_zero_tokens) }
+                } else {
+                    let element_zero_tokens = generate_element_zero_tokens(field);
+                    quote! { [#element_zero_tokens; #len] }
+                }
+            } else if bitfield.is_integer_backed() {
                 quote! { 0 }
             } else {
-                let len = length as usize;
                 quote! { [0u8; #len] }
             }
         },
     };
 
-    if field.has_setter() {
+    // Niche-typed fields may have no zero value, their bits are zeroed as is.
+    if field.is_niche() {
+        return generate_setting_field_without_setter_tokens(
+            bitfield,
+            field,
+            quote! { 0 },
+            /* builder_caller= */ false,
+        );
+    }
+
+    // The bits of encoded fields are zeroed, instead of their encoded zero.
+    if field.has_setter() && field.encoding().is_none() {
         generate_field_setter_call_tokens(
             bitfield,
             field,
@@ -236,7 +329,8 @@ pub fn generate_setting_field_from_variable_tokens(
         matches!(field.spanned_data_type_token().data_type(), DataType::Integer(IntegerType::Bool));
 
     let is_array_field =
-        matches!(field.spanned_data_type_token().data_type(), DataType::Array { .. });
+        matches!(field.spanned_data_type_token().data_type(), DataType::Array { .. })
+            || is_byte_ascii_str_field(bitfield, field);
 
     let using_setter = field.has_setter() && use_setter;
 
@@ -244,16 +338,51 @@ pub fn generate_setting_field_from_variable_tokens(
         return generate_builder_direct_setter_call_tokens(field, check_bit_size);
     }
 
-    let pre_extract_check_tokens = (!using_setter && check_bit_size && is_signed_integer)
-        .then(|| generate_signed_bit_size_check_tokens(field));
+    let constraint_check_tokens = (!using_setter && check_bit_size)
+        .then(|| {
+            generate_field_constraint_check_tokens(
+                field,
+                &quote! { bits },
+                CONSTRAINT_SETTER_ERROR_MESSAGE,
+            )
+        })
+        .flatten();
+
+    // Fields with converted bits check the value before it's converted into
+    // `bits`.
+    let converting_bits = !using_setter && cast_bits && has_converted_bits(bitfield, field);
+
+    let pre_extract_check_tokens = if converting_bits {
+        check_bit_size.then(|| generate_converted_bit_size_check_tokens(field))
+    } else {
+        (!using_setter && check_bit_size && is_signed_integer)
+            .then(|| generate_signed_bit_size_check_tokens(field))
+    };
+
+    let converting_bits_tokens =
+        converting_bits.then(|| generate_converting_value_into_bits_tokens(bitfield, field));
+
+    let shifted_bits_check_tokens = (!using_setter && check_bit_size)
+        .then(|| generate_shifted_bits_check_tokens(field))
+        .flatten();
 
     let post_extract_check_tokens = (!using_setter
         && check_bit_size
+        && !converting_bits
         && !is_signed_integer
         && !is_bool_field
         && !is_array_field)
         .then(generate_bit_size_check_tokens);
 
+    let packed_array_check_tokens = (!using_setter && check_bit_size && field.is_packed_array())
+        .then(|| generate_packed_array_bit_size_check_tokens(field));
+
+    let (pre_extract_none_value_check_tokens, post_extract_none_value_check_tokens) =
+        (!using_setter && check_bit_size)
+            .then(|| generate_none_value_check_tokens(field))
+            .flatten()
+            .unzip();
+
     let extract_field_bits_into_variable_tokens =
         generate_extract_field_bits_from_source_into_variable_tokens(
             bitfield,
@@ -264,7 +393,7 @@ pub fn generate_setting_field_from_variable_tokens(
             builder_caller,
         );
 
-    let value_variable_tokens = get_value_variable_tokens(field);
+    let value_variable_tokens = get_value_variable_tokens(bitfield, field);
     let set_field_to_extracted_bits_from_variable_tokens = if using_setter {
         generate_field_setter_call_tokens(bitfield, field, value_variable_tokens, builder_caller)
     } else {
@@ -277,13 +406,34 @@ pub fn generate_setting_field_from_variable_tokens(
     };
 
     quote! {
+        #constraint_check_tokens
         #pre_extract_check_tokens
+        #shifted_bits_check_tokens
+        #pre_extract_none_value_check_tokens
+        #converting_bits_tokens
         #extract_field_bits_into_variable_tokens
         #post_extract_check_tokens
+        #post_extract_none_value_check_tokens
+        #packed_array_check_tokens
         #set_field_to_extracted_bits_from_variable_tokens
     }
 }
 
+/// Generates setting a field to the bits extracted into `value`, without
+/// calling its setter, which would convert the bits of fields with converted
+/// bits.
+pub fn generate_setting_field_to_raw_value_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+) -> TokenStream {
+    generate_setting_field_without_setter_tokens(
+        bitfield,
+        field,
+        get_value_variable_tokens(bitfield, field),
+        /* builder_caller= */ false,
+    )
+}
+
 fn generate_builder_direct_setter_call_tokens(field: &Field, check_bit_size: bool) -> TokenStream {
     let setter_ident = if check_bit_size {
         field.checked_setter_ident_tokens()
@@ -318,12 +468,21 @@ fn generate_builder_direct_setter_call_tokens(field: &Field, check_bit_size: boo
     }
 }
 
-fn get_value_variable_tokens(field: &Field) -> TokenStream {
-    let custom_field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+fn get_value_variable_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
     match field.spanned_data_type_token().data_type() {
-        DataType::Custom => {
+        DataType::Custom if is_byte_ascii_str_field(bitfield, field) => {
+            generate_ascii_str_from_bytes_tokens(field, quote! { value })
+        },
+        // The bits of niche-typed fields are set as is.
+        DataType::Custom if !field.is_niche() => {
+            let custom_field_from_raw_tokens =
+                generate_custom_field_from_raw_tokens(field, quote! { value });
             quote! {
-                #custom_field_data_type_tokens::from_bits(value as _)
+                {
+                    #[allow(clippy::unnecessary_cast)]
+                    let value = value as u128;
+                    #custom_field_from_raw_tokens
+                }
             }
         },
         DataType::Integer(IntegerType::Bool) => {
@@ -335,6 +494,216 @@ fn get_value_variable_tokens(field: &Field) -> TokenStream {
     }
 }
 
+/// The error of checked setters when the value doesn't satisfy the `range` or
+/// `values` constraint of the field.
+const CONSTRAINT_SETTER_ERROR_MESSAGE: &str = "Value is outside the valid values of the field.";
+
+/// Generates a check returning the error if the value doesn't satisfy the
+/// `range` or `values` constraint of the field, or `None` if the field has no
+/// constraint.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// if !matches!(bits, 1..=12) {
+///     return Err("Value is outside the valid values of the field.");
+/// }
+/// ```
+pub fn generate_field_constraint_check_tokens(
+    field: &Field,
+    value_tokens: &TokenStream,
+    error_message: &str,
+) -> Option<TokenStream> {
+    let pattern_tokens = match field.constraint()? {
+        FieldConstraint::Range {
+            start,
+            end,
+        } => {
+            let start_tokens = generate_constraint_value_tokens(start);
+            let end_tokens = generate_constraint_value_tokens(end);
+            quote! { #start_tokens..=#end_tokens }
+        },
+        FieldConstraint::Values(values) => {
+            let values_tokens = values.into_iter().map(generate_constraint_value_tokens);
+            quote! { #( #values_tokens )|* }
+        },
+    };
+
+    Some(quote! {
+        if !matches!(#value_tokens, #pattern_tokens) {
+            return Err(#error_message);
+        }
+    })
+}
+
+/// Generates an unsuffixed literal pattern of a constraint value, which can be
+/// negative.
+fn generate_constraint_value_tokens(value: i128) -> TokenStream {
+    let literal = Literal::u128_unsuffixed(value.unsigned_abs());
+    if value < 0 {
+        quote! { -#literal }
+    } else {
+        quote! { #literal }
+    }
+}
+
+/// Generates the checks returning an error if a `Some` value of an `Option`
+/// field has the bits of its `none` value, or `None` if the field isn't an
+/// `Option` field. The first check runs before the value is converted into its
+/// bits, the second once the bits are extracted into `value`.
+fn generate_none_value_check_tokens(field: &Field) -> Option<(TokenStream, TokenStream)> {
+    let none_value_tokens = Literal::u128_unsuffixed(field.none_value()?);
+    Some((
+        quote! {
+            let is_none = bits.is_none();
+        },
+        quote! {
+            if !is_none && value == #none_value_tokens {
+                return Err("Value has the bits of the `none` value of the field.");
+            }
+        },
+    ))
+}
+
+/// Returns what the setters of a field do with values that don't fit in the
+/// field bits, which is the policy of the bitfield unless the field overrides
+/// it.
+pub fn get_field_overflow_policy(bitfield: &Bitfield, field: &Field) -> OverflowPolicy {
+    field.overflow().unwrap_or_else(|| bitfield.arguments().overflow())
+}
+
+/// Generates clamping the value in `bits` to the bounds of the field, or `None`
+/// if the field isn't an integer or array of integers that can overflow.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let bits = { let max = u8::MAX >> (u8::BITS - 4u32); if bits > max { max } else { bits } };
+/// ```
+pub fn generate_saturating_field_value_tokens(field: &Field) -> Option<TokenStream> {
+    let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    match field.spanned_data_type_token().data_type() {
+        DataType::Integer(IntegerType::Bool) => None,
+        DataType::Integer(integer_type) => {
+            let field_bits = field.bits();
+            let saturated_value_tokens = match field.encoding() {
+                Some(FieldEncoding::Bcd) => {
+                    let bits = field_bits.known().expect("Expected known bits for encoded field");
+                    let max_lit = Literal::u128_unsuffixed(10u128.pow(bits / 4) - 1);
+                    quote! { if bits > #max_lit { #max_lit } else { bits } }
+                },
+                Some(FieldEncoding::SignMagnitude) => {
+                    let field_bits_tokens = field_bits.to_tokens();
+                    quote! {
+                        {
+                            let max = #field_data_type_tokens::MAX >> (#field_data_type_tokens::BITS - #field_bits_tokens);
+                            if bits > max { max } else if bits < -max { -max } else { bits }
+                        }
+                    }
+                },
+                _ if field_bits.known().is_some_and(|bits| bits >= integer_type.bit_size()) => {
+                    return None;
+                },
+                _ => generate_saturating_integer_tokens(
+                    &quote! { bits },
+                    &field_data_type_tokens,
+                    &field_bits.to_tokens(),
+                    !integer_type.is_unsigned(),
+                ),
+            };
+            Some(quote! {
+                let bits = #saturated_value_tokens;
+            })
+        },
+        DataType::Array {
+            element_type: ArrayElementType::Integer(integer_type), ..
+        } if integer_type != IntegerType::Bool && field.is_packed_array() => {
+            let len =
+                field.spanned_data_type_token().array_length().expect("Expected array length");
+            let element_data_type_tokens = get_array_element_data_type_tokens(field);
+            let element_bits = field.element_bits().expect("Expected element bits");
+            let saturated_element_tokens = generate_saturating_integer_tokens(
+                &quote! { bits[element_index] },
+                &element_data_type_tokens,
+                &quote! { #element_bits },
+                !integer_type.is_unsigned(),
+            );
+            Some(quote! {
+                let mut bits = bits;
+                let mut element_index: usize = 0;
+                while element_index < #len {
+                    bits[element_index] = #saturated_element_tokens;
+                    element_index += 1;
+                }
+            })
+        },
+        _ => None,
+    }
+}
+
+/// Generates clamping the value of an element of a packed array field in
+/// `bits` to the bounds of the element bits, or `None` if the elements aren't
+/// integers.
+pub fn generate_saturating_element_value_tokens(field: &Field) -> Option<TokenStream> {
+    let ArrayElementType::Integer(integer_type) = get_array_element_type(field) else {
+        return None;
+    };
+    if integer_type == IntegerType::Bool {
+        return None;
+    }
+
+    let element_bits = field.element_bits().expect("Expected element bits for packed array");
+    let saturated_element_tokens = generate_saturating_integer_tokens(
+        &quote! { bits },
+        &get_array_element_data_type_tokens(field),
+        &quote! { #element_bits },
+        !integer_type.is_unsigned(),
+    );
+    Some(quote! {
+        let bits = #saturated_element_tokens;
+    })
+}
+
+/// Generates clamping an integer to the bounds of an integer with the given
+/// bits of its type.
+fn generate_saturating_integer_tokens(
+    value_tokens: &TokenStream,
+    data_type_tokens: &TokenStream,
+    bits_tokens: &TokenStream,
+    signed: bool,
+) -> TokenStream {
+    if !signed {
+        return quote! {
+            {
+                let max = #data_type_tokens::MAX >> (#data_type_tokens::BITS - #bits_tokens);
+                if #value_tokens > max { max } else { #value_tokens }
+            }
+        };
+    }
+
+    quote! {
+        {
+            let max = #data_type_tokens::MAX >> (#data_type_tokens::BITS - #bits_tokens);
+            let min = #data_type_tokens::MIN >> (#data_type_tokens::BITS - #bits_tokens);
+            if #value_tokens > max { max } else if #value_tokens < min { min } else { #value_tokens }
+        }
+    }
+}
+
+/// Generates a check that the bits of a split field below its shift are zero,
+/// as setting the field would discard them, or `None` if it has no shift.
+fn generate_shifted_bits_check_tokens(field: &Field) -> Option<TokenStream> {
+    let shift = field.shift();
+    (field.is_split() && shift > 0).then(|| {
+        let shifted_bits_mask = Literal::u128_unsuffixed((1u128 << shift) - 1);
+        quote! {
+            if bits & #shifted_bits_mask != 0 {
+                return Err("Value has bits set below the shift of the field.");
+            }
+        }
+    })
+}
+
 /// Generates an overflow check for unsigned/custom fields.
 fn generate_bit_size_check_tokens() -> TokenStream {
     quote! {
@@ -344,12 +713,41 @@ fn generate_bit_size_check_tokens() -> TokenStream {
     }
 }
 
+/// Generates an overflow check for each element of a packed array field.
+fn generate_packed_array_bit_size_check_tokens(field: &Field) -> TokenStream {
+    let len = field.spanned_data_type_token().array_length().expect("Expected array length");
+    let element_overflow_condition_tokens =
+        generate_element_overflow_condition_tokens(field, &quote! { value[element_index] });
+
+    quote! {
+        let mut element_index: usize = 0;
+        while element_index < #len {
+            if #element_overflow_condition_tokens {
+                return Err("Value is too big to fit within the field bits.");
+            }
+            element_index += 1;
+        }
+    }
+}
+
 /// Generates an overflow check for signed integer fields.
 /// Must run before `bits` is reinterpreted as unsigned.
 /// Skipped when the field's bit-width equals the full type width.
 fn generate_signed_bit_size_check_tokens(field: &Field) -> TokenStream {
-    let field_bits = field.bits();
     let type_bits = field.spanned_data_type_token().data_type().bit_size();
+    let Some(field_bits) = field.bits().known() else {
+        // The bits are a const expression, so the bounds are calculated by the
+        // compiler.
+        let field_bits_tokens = field.bits().to_tokens();
+        return quote! {
+            if #field_bits_tokens < #type_bits {
+                let max = (1i128 << (#field_bits_tokens - 1)) - 1;
+                if (bits as i128) > max || (bits as i128) < -max - 1 {
+                    return Err("Value is too big to fit within the field bits.");
+                }
+            }
+        };
+    };
 
     if field_bits >= type_bits {
         return quote! {};
@@ -368,6 +766,143 @@ fn generate_signed_bit_size_check_tokens(field: &Field) -> TokenStream {
     }
 }
 
+/// Generates an overflow check for the value of fields with converted bits,
+/// which runs before the value is converted into the bits of the field. The
+/// value of encoded fields holds the values of the encoding.
+fn generate_converted_bit_size_check_tokens(field: &Field) -> TokenStream {
+    let field_bits = field.bits().known().expect("Expected known bits for converted field");
+    let type_bits = field.spanned_data_type_token().data_type().bit_size();
+
+    match field.encoding() {
+        Some(FieldEncoding::SignMagnitude) => {
+            let max_lit = make_signed_literal(field, ((1u128 << (field_bits - 1)) - 1) as i128);
+            if field_bits >= type_bits {
+                quote! {
+                    if bits < -#max_lit {
+                        return Err("Value is too big to fit within the field bits.");
+                    }
+                }
+            } else {
+                quote! {
+                    if bits > #max_lit || bits < -#max_lit {
+                        return Err("Value is too big to fit within the field bits.");
+                    }
+                }
+            }
+        },
+        Some(FieldEncoding::Bcd) => {
+            let max_lit = Literal::u128_unsuffixed(10u128.pow(field_bits / 4) - 1);
+            quote! {
+                if bits > #max_lit {
+                    return Err("Value is too big to fit within the field bits.");
+                }
+            }
+        },
+        _ if !field.spanned_data_type_token().data_type().unsigned() => {
+            generate_signed_bit_size_check_tokens(field)
+        },
+        _ => {
+            let is_bool_field = matches!(
+                field.spanned_data_type_token().data_type(),
+                DataType::Integer(IntegerType::Bool)
+            );
+            if is_bool_field || field_bits >= type_bits {
+                return quote! {};
+            }
+
+            let max_lit = Literal::u128_unsuffixed((1u128 << field_bits) - 1);
+            quote! {
+                if bits > #max_lit {
+                    return Err("Value is too big to fit within the field bits.");
+                }
+            }
+        },
+    }
+}
+
+/// Returns if the field is stored with the bytes of its value swapped, as its
+/// byte order isn't the byte order the bitfield is converted from.
+fn is_byte_swapped_field(bitfield: &Bitfield, field: &Field) -> bool {
+    field.endian().is_some_and(|endian| endian != bitfield.arguments().from_endian())
+}
+
+/// Returns if the value of the field is converted from and into its bits, by
+/// an encoding or a byte order.
+pub fn has_converted_bits(bitfield: &Bitfield, field: &Field) -> bool {
+    field.encoding().is_some() || is_byte_swapped_field(bitfield, field)
+}
+
+/// Generates converting the value of a field with converted bits in `bits`
+/// into the bits stored in the field, encoding it and then swapping its bytes.
+fn generate_converting_value_into_bits_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+    let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    let field_bits_tokens = field.bits().to_tokens();
+    let encoding_tokens = field.encoding().is_some().then(|| {
+        let encoded_value_tokens = generate_encoded_field_value_tokens(field);
+        quote! {
+            #[allow(clippy::unnecessary_cast)]
+            let bits = #encoded_value_tokens;
+        }
+    });
+    let byte_swap_tokens = is_byte_swapped_field(bitfield, field).then(|| {
+        quote! {
+            #[allow(clippy::unnecessary_cast)]
+            let bits = ((bits as u128).swap_bytes() >> (128 - #field_bits_tokens)) as #field_data_type_tokens;
+        }
+    });
+
+    quote! {
+        #encoding_tokens
+        #byte_swap_tokens
+    }
+}
+
+/// Generates encoding the value of an encoded field in `bits` into the bits
+/// stored in the field, as the type of the field.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// (bits as u128 ^ (1u128 << (8 - 1))) as i8
+/// ```
+fn generate_encoded_field_value_tokens(field: &Field) -> TokenStream {
+    let encoding = field.encoding().expect("Expected encoding for encoded field");
+    let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    let field_bits_tokens = field.bits().to_tokens();
+
+    match encoding {
+        FieldEncoding::SignMagnitude => quote! {
+            (if bits < 0 {
+                (1u128 << (#field_bits_tokens - 1)) | bits.unsigned_abs() as u128
+            } else {
+                bits as u128
+            }) as #field_data_type_tokens
+        },
+        FieldEncoding::OffsetBinary => quote! {
+            (bits as u128 ^ (1u128 << (#field_bits_tokens - 1))) as #field_data_type_tokens
+        },
+        FieldEncoding::Bcd => quote! {
+            {
+                let mut value = bits as u128;
+                let mut encoded = 0u128;
+                let mut digit: u32 = 0;
+                while digit < #field_bits_tokens / 4 {
+                    encoded |= (value % 10) << (digit * 4);
+                    value /= 10;
+                    digit += 1;
+                }
+                encoded as #field_data_type_tokens
+            }
+        },
+        FieldEncoding::Gray => quote! {
+            bits ^ (bits >> 1)
+        },
+        FieldEncoding::ActiveLow => quote! {
+            !bits
+        },
+    }
+}
+
 /// Creates a typed suffixed integer literal matching the signed integer type of
 /// `field`.
 fn make_signed_literal(field: &Field, value: i128) -> Literal {
@@ -392,6 +927,33 @@ pub fn generate_extract_field_bits_from_source_into_variable_tokens(
     invert_bits: bool,
     builder_caller: bool,
 ) -> TokenStream {
+    // Setters receive the value of split fields already assembled.
+    if field.is_split() && !(bits_source == BitsSource::IntegerVariable && cast_bits) {
+        return generate_extract_split_field_bits_from_source_into_variable_tokens(
+            bitfield,
+            field,
+            bits_source,
+            invert_bits,
+            builder_caller,
+        );
+    }
+
+    if field.is_packed_array() {
+        // Setters receive the elements of packed arrays already unpacked.
+        if bits_source == BitsSource::IntegerVariable && cast_bits {
+            return quote! {
+                let value = bits;
+            };
+        }
+        return generate_extract_packed_array_field_bits_from_source_into_variable_tokens(
+            bitfield,
+            field,
+            bits_source,
+            invert_bits,
+            builder_caller,
+        );
+    }
+
     match bits_source {
         BitsSource::Bitfield | BitsSource::IntegerVariable => {
             let bits_source_tokens = match bits_source {
@@ -418,16 +980,27 @@ pub fn generate_extract_field_bits_from_source_into_variable_tokens(
                 let casting_bits_to_bitfield_data_type_tokens =
                     (bits_source == BitsSource::IntegerVariable && cast_bits).then(|| {
                         if matches!(field.spanned_data_type_token().data_type(), DataType::Custom) {
+                            let custom_field_into_raw_tokens =
+                                generate_custom_field_into_raw_tokens(field, quote! { bits });
                             quote! {
                                 #[allow(clippy::unnecessary_cast)]
-                                let bits = bits.into_bits() as #bitfield_data_type_tokens;
+                                let bits = #custom_field_into_raw_tokens as #bitfield_data_type_tokens;
                             }
-                        } else if let DataType::Array {
-                            length,
-                        } = field.spanned_data_type_token().data_type()
-                        {
-                            let len = length as usize;
-                            let pack_len = (field.bits() as usize / 8).min(len);
+                        } else if matches!(
+                            field.spanned_data_type_token().data_type(),
+                            DataType::Array { .. }
+                        ) {
+                            // Arrays with a const length take the bits of all their bytes.
+                            let pack_len = match (
+                                field.spanned_data_type_token().array_length(),
+                                field.bits().known(),
+                            ) {
+                                (Some(len), Some(field_bits)) => {
+                                    let pack_len = (field_bits as usize / 8).min(len);
+                                    quote! { #pack_len }
+                                },
+                                _ => get_array_length_tokens(field),
+                            };
                             quote! {
                                 let bits = {
                                     let __arr = bits;
@@ -479,9 +1052,16 @@ pub fn generate_extract_field_bits_from_source_into_variable_tokens(
                         let value = bits;
                     };
                 }
+                if is_byte_ascii_str_field(bitfield, field) {
+                    return quote! {
+                        let value = bits.into_bytes();
+                    };
+                }
                 if matches!(field.spanned_data_type_token().data_type(), DataType::Custom) {
+                    let custom_field_into_raw_tokens =
+                        generate_custom_field_into_raw_tokens(field, quote! { bits });
                     return quote! {
-                        let bits = bits.into_bits() as u128;
+                        let bits = #custom_field_into_raw_tokens;
                         let mask = if #field_bits_tokens == 128 { u128::MAX } else { (1u128 << #field_bits_tokens) - 1 };
                         let value = {
                             let val = bits & mask;
@@ -498,11 +1078,16 @@ pub fn generate_extract_field_bits_from_source_into_variable_tokens(
                     };
                 }
             } else {
-                if let DataType::Array {
-                    length,
-                } = field.spanned_data_type_token().data_type()
-                {
-                    let len = length as usize;
+                let byte_length_tokens = match field.spanned_data_type_token().data_type() {
+                    DataType::Array {
+                        ..
+                    } => Some(get_array_length_tokens(field)),
+                    DataType::Custom if is_byte_ascii_str_field(bitfield, field) => {
+                        Some(get_ascii_str_length_tokens(field))
+                    },
+                    _ => None,
+                };
+                if let Some(len) = byte_length_tokens {
                     return if invert_bits {
                         quote! {
                             let value = {
@@ -565,21 +1150,114 @@ pub fn generate_extract_field_bits_from_source_into_variable_tokens(
     }
 }
 
-/// Generates tokens for calling the field setter with the provided value.
-///
-/// # Example
-///
-/// - `this.set_a(99)`
-/// - `self.this.set_b(88)`
-fn generate_field_setter_call_tokens(
+/// Generates extracting the bits of each segment of a split field from a source
+/// and assembling them into a variable, starting from the most significant
+/// segment.
+fn generate_extract_split_field_bits_from_source_into_variable_tokens(
     bitfield: &Bitfield,
     field: &Field,
-    value_tokens: TokenStream,
+    bits_source: BitsSource,
+    invert_bits: bool,
     builder_caller: bool,
 ) -> TokenStream {
-    let bitfield_variable_reference = if builder_caller {
-        quote! { self.this }
-    } else {
+    let extract_segment_bits_tokens_list =
+        field.segment_fields().into_iter().map(|segment_field| {
+            let segment_bits_tokens = segment_field.bits().to_tokens();
+            let extract_segment_bits_into_variable_tokens =
+                generate_extract_field_bits_from_source_into_variable_tokens(
+                    bitfield,
+                    &segment_field,
+                    bits_source,
+                    /* cast_bits= */ false,
+                    invert_bits,
+                    builder_caller,
+                );
+            quote! {
+                let split_value = {
+                    #extract_segment_bits_into_variable_tokens
+                    #[allow(clippy::unnecessary_cast)]
+                    let value = value as u128;
+                    (split_value << #segment_bits_tokens) | value
+                };
+            }
+        });
+    let shift = field.shift();
+    let shift_tokens = (shift > 0).then(|| quote! { << #shift });
+
+    quote! {
+        let value = {
+            let split_value = 0u128;
+            #( #extract_segment_bits_tokens_list )*
+            split_value #shift_tokens
+        };
+    }
+}
+
+/// Generates extracting the bits of each element of a packed array field from a
+/// source into an array variable.
+fn generate_extract_packed_array_field_bits_from_source_into_variable_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+    bits_source: BitsSource,
+    invert_bits: bool,
+    builder_caller: bool,
+) -> TokenStream {
+    let element_data_type_tokens = get_array_element_data_type_tokens(field);
+    let len = field.spanned_data_type_token().array_length().expect("Expected array length");
+    let field_offset_tokens = get_field_offset_tokens(bitfield, field, builder_caller);
+    let element_field = field.element_field(&field_offset_tokens, &quote! { element_index });
+    let extract_element_bits_into_variable_tokens =
+        generate_extract_field_bits_from_source_into_variable_tokens(
+            bitfield,
+            &element_field,
+            bits_source,
+            /* cast_bits= */ false,
+            invert_bits,
+            builder_caller,
+        );
+
+    let element_from_raw_tokens = generate_element_from_raw_tokens(field, &quote! { value });
+    let element_zero_tokens = generate_element_zero_tokens(field);
+
+    // Custom types may not have a zero value to initialize the array with.
+    if field.has_custom_data_type() {
+        return quote! {
+            let value = ::core::array::from_fn::<#element_data_type_tokens, #len, _>(|element_index| {
+                #extract_element_bits_into_variable_tokens
+                #element_from_raw_tokens
+            });
+        };
+    }
+
+    quote! {
+        let value = {
+            let mut elements: [#element_data_type_tokens; #len] = [#element_zero_tokens; #len];
+            let mut element_index: usize = 0;
+            while element_index < #len {
+                #extract_element_bits_into_variable_tokens
+                elements[element_index] = #element_from_raw_tokens;
+                element_index += 1;
+            }
+            elements
+        };
+    }
+}
+
+/// Generates tokens for calling the field setter with the provided value.
+///
+/// # Example
+///
+/// - `this.set_a(99)`
+/// - `self.this.set_b(88)`
+fn generate_field_setter_call_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+    value_tokens: TokenStream,
+    builder_caller: bool,
+) -> TokenStream {
+    let bitfield_variable_reference = if builder_caller {
+        quote! { self.this }
+    } else {
         quote! { this }
     };
 
@@ -603,10 +1281,10 @@ fn generate_field_setter_call_tokens(
             }
         },
         DataType::Array {
-            length,
+            ..
         } => {
-            let len = length as usize;
-            if bitfield.is_integer_backed() {
+            if bitfield.is_integer_backed() && !field.is_packed_array() {
+                let len = get_array_length_tokens(field);
                 quote! {
                     #bitfield_variable_reference.#field_setter_ident_tokens({
                         let __int_val = (#value_tokens) as u128;
@@ -641,14 +1319,36 @@ fn generate_setting_field_without_setter_tokens(
     let field_bits_tokens = get_field_bits_tokens(bitfield, field, builder_caller);
     let field_offset_tokens = get_field_offset_tokens(bitfield, field, builder_caller);
 
+    if field.is_split() {
+        return generate_setting_split_field_without_setter_tokens(
+            bitfield,
+            field,
+            value_tokens,
+            builder_caller,
+        );
+    }
+
+    if field.is_packed_array() {
+        return generate_setting_packed_array_field_without_setter_tokens(
+            bitfield,
+            field,
+            value_tokens,
+            builder_caller,
+        );
+    }
+
     if !bitfield.is_integer_backed() {
-        if let DataType::Array {
-            ..
-        } = field.spanned_data_type_token().data_type()
-        {
+        let is_byte_array_field =
+            matches!(field.spanned_data_type_token().data_type(), DataType::Array { .. });
+        if is_byte_array_field || is_byte_ascii_str_field(bitfield, field) {
+            let src_tokens = if is_byte_array_field {
+                value_tokens
+            } else {
+                quote! { (#value_tokens).into_bytes() }
+            };
             return quote! {
                 {
-                    let __src = #value_tokens;
+                    let __src = #src_tokens;
                     let mut __i: u32 = 0;
                     while __i < #field_bits_tokens {
                         let bit_index = #field_offset_tokens + __i;
@@ -670,8 +1370,8 @@ fn generate_setting_field_without_setter_tokens(
             DataType::Integer(IntegerType::Bool) => quote! {
                 if #value_tokens { 1u128 } else { 0u128 }
             },
-            DataType::Custom => quote! {
-                #value_tokens.into_bits() as u128
+            DataType::Custom if !field.is_niche() => {
+                generate_custom_field_into_raw_tokens(field, value_tokens.clone())
             },
             _ => quote! {
                 #value_tokens as u128
@@ -708,11 +1408,14 @@ fn generate_setting_field_without_setter_tokens(
         };
     }
 
-    if matches!(field.spanned_data_type_token().data_type(), DataType::Custom) {
+    if matches!(field.spanned_data_type_token().data_type(), DataType::Custom) && !field.is_niche()
+    {
+        let custom_field_into_raw_tokens =
+            generate_custom_field_into_raw_tokens(field, value_tokens);
         return quote! {
             let mask = #bitfield_data_type_tokens::MAX >> (#bitfield_data_type_tokens::BITS - #field_bits_tokens);
             #[allow(clippy::unnecessary_cast)]
-            let field_bits = #value_tokens.into_bits() as #bitfield_data_type_tokens;
+            let field_bits = #custom_field_into_raw_tokens as #bitfield_data_type_tokens;
             #bitfield_internal_value_ident_tokens = (#bitfield_internal_value_ident_tokens & !(mask << #field_offset_tokens)) | ((field_bits & mask) << #field_offset_tokens);
         };
     }
@@ -724,6 +1427,525 @@ fn generate_setting_field_without_setter_tokens(
     }
 }
 
+/// Generates tokens to bitwise set each segment of a split field to its part of
+/// a value, starting from the least significant segment.
+fn generate_setting_split_field_without_setter_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+    value_tokens: TokenStream,
+    builder_caller: bool,
+) -> TokenStream {
+    let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+    let shift = field.shift();
+    let shift_tokens = (shift > 0).then(|| quote! { >> #shift });
+
+    let segment_fields = field.segment_fields();
+    let setting_segment_tokens_list =
+        segment_fields.iter().rev().enumerate().map(|(index, segment_field)| {
+            let segment_bits =
+                segment_field.bits().known().expect("Expected known bits for segment");
+            let segment_mask = u128::MAX >> (u128::BITS - segment_bits);
+            let segment_value_tokens = if bitfield.is_integer_backed() {
+                quote! { ((split_value & #segment_mask) as #bitfield_data_type_tokens) }
+            } else {
+                quote! { (split_value & #segment_mask) }
+            };
+            let setting_segment_tokens = generate_setting_field_without_setter_tokens(
+                bitfield,
+                segment_field,
+                segment_value_tokens,
+                builder_caller,
+            );
+            // The value is shifted to the next segment, if there is one.
+            let next_segment_tokens = (index + 1 < segment_fields.len()).then(|| {
+                quote! {
+                    let split_value = split_value >> #segment_bits;
+                }
+            });
+            quote! {
+                {
+                    #setting_segment_tokens
+                }
+                #next_segment_tokens
+            }
+        });
+
+    quote! {
+        {
+            #[allow(clippy::unnecessary_cast)]
+            let split_value = (#value_tokens as #field_data_type_tokens) as u128 #shift_tokens;
+            #( #setting_segment_tokens_list )*
+        }
+    }
+}
+
+/// Generates tokens to bitwise set each element of a packed array field.
+fn generate_setting_packed_array_field_without_setter_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+    value_tokens: TokenStream,
+    builder_caller: bool,
+) -> TokenStream {
+    let len = field.spanned_data_type_token().array_length().expect("Expected array length");
+    let field_offset_tokens = get_field_offset_tokens(bitfield, field, builder_caller);
+    let element_field = field.element_field(&field_offset_tokens, &quote! { element_index });
+    let setting_element_tokens = generate_setting_element_without_setter_tokens(
+        bitfield,
+        &element_field,
+        &generate_element_into_raw_tokens(field, &quote! { element_value }),
+        builder_caller,
+    );
+
+    if field.has_custom_data_type() {
+        return quote! {
+            {
+                let elements = #value_tokens;
+                for (element_index, element_value) in elements.into_iter().enumerate() {
+                    #setting_element_tokens
+                }
+            }
+        };
+    }
+
+    quote! {
+        {
+            let elements = #value_tokens;
+            let mut element_index: usize = 0;
+            while element_index < #len {
+                let element_value = elements[element_index];
+                #setting_element_tokens
+                element_index += 1;
+            }
+        }
+    }
+}
+
+/// Generates tokens to bitwise set an element of a packed array field, from
+/// the field returned by [`Field::element_field`].
+pub fn generate_setting_element_without_setter_tokens(
+    bitfield: &Bitfield,
+    element_field: &Field,
+    element_value_tokens: &TokenStream,
+    builder_caller: bool,
+) -> TokenStream {
+    let element_value_data_type_tokens = if bitfield.is_integer_backed() {
+        bitfield.spanned_data_type_token().to_tokens()
+    } else {
+        quote! { u128 }
+    };
+    let setting_element_tokens = generate_setting_field_without_setter_tokens(
+        bitfield,
+        element_field,
+        quote! { element },
+        builder_caller,
+    );
+
+    quote! {
+        {
+            #[allow(clippy::unnecessary_cast)]
+            let element = #element_value_tokens as #element_value_data_type_tokens;
+            #setting_element_tokens
+        }
+    }
+}
+
+/// Returns the element type tokens of an array field.
+pub fn get_array_element_data_type_tokens(field: &Field) -> TokenStream {
+    field
+        .spanned_data_type_token()
+        .array_element_type_tokens()
+        .expect("Expected array field for element type")
+}
+
+/// Generates tokens converting an element of a packed array field into its raw
+/// bits, through the `BitfieldField` trait for custom element types.
+pub fn generate_element_into_raw_tokens(field: &Field, value_tokens: &TokenStream) -> TokenStream {
+    if get_array_element_type(field) == ArrayElementType::Integer(IntegerType::Bool) {
+        return quote! { (#value_tokens as u8) };
+    }
+
+    if !field.has_custom_data_type() {
+        return value_tokens.clone();
+    }
+
+    let element_data_type_tokens = get_array_element_data_type_tokens(field);
+    generate_custom_type_into_raw_tokens(&element_data_type_tokens, value_tokens.clone())
+}
+
+/// Generates tokens converting raw bits into an element of a packed array
+/// field, through the `BitfieldField` trait for custom element types.
+pub fn generate_element_from_raw_tokens(
+    field: &Field,
+    raw_bits_tokens: &TokenStream,
+) -> TokenStream {
+    let element_data_type_tokens = get_array_element_data_type_tokens(field);
+    let ArrayElementType::Integer(integer_type) = get_array_element_type(field) else {
+        let custom_type_from_raw_tokens =
+            generate_custom_type_from_raw_tokens(&element_data_type_tokens, quote! { raw_bits });
+        return quote! {
+            {
+                #[allow(clippy::unnecessary_cast)]
+                let raw_bits = #raw_bits_tokens as u128;
+                #custom_type_from_raw_tokens
+            }
+        };
+    };
+
+    if integer_type == IntegerType::Bool {
+        return quote! { (#raw_bits_tokens != 0) };
+    }
+
+    if integer_type.is_unsigned() {
+        return quote! { #raw_bits_tokens as #element_data_type_tokens };
+    }
+
+    // Sign extend elements narrower than their type.
+    let element_bits = field.element_bits().expect("Expected element bits for packed array");
+    quote! {
+        {
+            let element = #raw_bits_tokens as #element_data_type_tokens;
+            (element << (#element_data_type_tokens::BITS - #element_bits))
+                >> (#element_data_type_tokens::BITS - #element_bits)
+        }
+    }
+}
+
+/// Generates a zeroed element of a packed array field.
+pub fn generate_element_zero_tokens(field: &Field) -> TokenStream {
+    match get_array_element_type(field) {
+        ArrayElementType::Integer(IntegerType::Bool) => quote! { false },
+        ArrayElementType::Integer(_) => quote! { 0 },
+        ArrayElementType::Custom => generate_element_from_raw_tokens(field, &quote! { 0 }),
+    }
+}
+
+/// Generates a condition that is true when an element of a packed array field
+/// doesn't fit within the element bits.
+pub fn generate_element_overflow_condition_tokens(
+    field: &Field,
+    value_tokens: &TokenStream,
+) -> TokenStream {
+    let element_bits = field.element_bits().expect("Expected element bits for packed array");
+    let element_data_type_tokens = get_array_element_data_type_tokens(field);
+    match get_array_element_type(field) {
+        ArrayElementType::Integer(IntegerType::Bool) => quote! { false },
+        ArrayElementType::Integer(integer_type) if !integer_type.is_unsigned() => quote! {
+            {
+                let element = #value_tokens;
+                (element << (#element_data_type_tokens::BITS - #element_bits))
+                    >> (#element_data_type_tokens::BITS - #element_bits)
+                    != element
+            }
+        },
+        ArrayElementType::Integer(_) => quote! {
+            #value_tokens > #element_data_type_tokens::MAX >> (#element_data_type_tokens::BITS - #element_bits)
+        },
+        ArrayElementType::Custom => {
+            let element_mask = u128::MAX >> (u128::BITS - element_bits);
+            let element_into_raw_tokens = generate_element_into_raw_tokens(field, value_tokens);
+            quote! {
+                #element_into_raw_tokens as u128 > #element_mask
+            }
+        },
+    }
+}
+
+/// Returns the element type of an array field.
+fn get_array_element_type(field: &Field) -> ArrayElementType {
+    match field.spanned_data_type_token().data_type() {
+        DataType::Array {
+            element_type, ..
+        } => element_type,
+        _ => unreachable!("Expected array field for element type"),
+    }
+}
+
+/// Returns if the field is an ASCII string stored as bytes instead of `u128`
+/// bits, which is the case in array-backed bitfields, so strings can be longer
+/// than 16 bytes.
+pub fn is_byte_ascii_str_field(bitfield: &Bitfield, field: &Field) -> bool {
+    !bitfield.is_integer_backed()
+        && field.spanned_data_type_token().ascii_str_length_expr().is_some()
+}
+
+/// Returns the length of an array field, which is the const expression of
+/// arrays with a const length (e.g. `[u8; LENGTH]`).
+pub fn get_array_length_tokens(field: &Field) -> TokenStream {
+    field.spanned_data_type_token().array_length_tokens().expect("Expected length for array field")
+}
+
+/// Returns the number of bytes of an array-backed bitfield, which is the const
+/// expression of bitfields with a const length (e.g. `[u8; LENGTH]`).
+pub fn get_bytes_length_tokens(bitfield: &Bitfield) -> TokenStream {
+    bitfield
+        .spanned_data_type_token()
+        .array_length_tokens()
+        .expect("Expected length for array-backed bitfield")
+}
+
+/// Returns half of the number of bytes of an array-backed bitfield and the
+/// index of its last byte, which bound the loops reversing its bytes.
+pub fn get_bytes_reversal_bounds_tokens(bitfield: &Bitfield) -> (TokenStream, TokenStream) {
+    if let Some(array_len) = bitfield.spanned_data_type_token().array_length() {
+        let half_len = array_len / 2;
+        let last_idx = array_len - 1;
+        return (quote! { #half_len }, quote! { #last_idx });
+    }
+
+    let array_len = get_bytes_length_tokens(bitfield);
+    (quote! { #array_len / 2 }, quote! { #array_len - 1 })
+}
+
+/// Returns the length expression of an ASCII string field.
+fn get_ascii_str_length_tokens(field: &Field) -> TokenStream {
+    let length_expr = field
+        .spanned_data_type_token()
+        .ascii_str_length_expr()
+        .expect("Expected length for ASCII string field");
+    quote! { #length_expr }
+}
+
+/// Generates tokens converting bytes into an ASCII string field, keeping the
+/// bytes as is.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// <AsciiStr<21>>::from_raw_bytes(value)
+/// ```
+pub fn generate_ascii_str_from_bytes_tokens(
+    field: &Field,
+    bytes_tokens: TokenStream,
+) -> TokenStream {
+    let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    quote! {
+        <#field_data_type_tokens>::from_raw_bytes(#bytes_tokens)
+    }
+}
+
+/// Generates tokens converting `u128` bits into a custom field type through the
+/// `BitfieldField` trait, through `::bitfields::half` for half-precision float
+/// fields, or by comparing them to the `none` value for `Option` fields.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// <Custom as ::bitfields::BitfieldField>::from_raw(
+///     <<Custom as ::bitfields::BitfieldField>::Raw as ::bitfields::BitfieldRaw>::from_u128(value),
+/// )
+/// ```
+pub fn generate_custom_field_from_raw_tokens(
+    field: &Field,
+    raw_bits_tokens: TokenStream,
+) -> TokenStream {
+    let custom_field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    if field.is_half_precision() {
+        return quote! {
+            ::bitfields::half::f16_bits_to_f32(#raw_bits_tokens as u16) as #custom_field_data_type_tokens
+        };
+    }
+
+    if let Some(none_value) = field.none_value() {
+        let none_value_tokens = Literal::u128_unsuffixed(none_value);
+        let inner_from_raw_tokens = generate_option_inner_from_raw_tokens(field);
+        return quote! {
+            {
+                let raw_bits = #raw_bits_tokens;
+                if raw_bits == #none_value_tokens {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(#inner_from_raw_tokens)
+                }
+            }
+        };
+    }
+
+    // The enums of fields with enumerated values are generated with the
+    // bitfield, so they're converted through their const functions.
+    if field.has_enumerated_values() {
+        let raw_type_tokens = get_enumerated_values_raw_type_tokens(field);
+        return quote! {
+            #custom_field_data_type_tokens::from_bits(#raw_bits_tokens as #raw_type_tokens)
+        };
+    }
+
+    if field.is_arbitrary_int() {
+        return generate_arbitrary_int_from_raw_tokens(
+            &custom_field_data_type_tokens,
+            raw_bits_tokens,
+        );
+    }
+
+    if let Some(float_bits) = field.spanned_data_type_token().float_bits() {
+        return generate_float_from_raw_tokens(
+            &custom_field_data_type_tokens,
+            float_bits,
+            raw_bits_tokens,
+        );
+    }
+
+    generate_custom_type_from_raw_tokens(&custom_field_data_type_tokens, raw_bits_tokens)
+}
+
+/// Generates tokens converting the `u128` bits in `raw_bits` into the wrapped
+/// type of an `Option` field.
+fn generate_option_inner_from_raw_tokens(field: &Field) -> TokenStream {
+    let inner_spanned_data_type_token =
+        field.option_inner_data_type_token().expect("Expected wrapped type for option field");
+    let inner_data_type_tokens = inner_spanned_data_type_token.to_tokens();
+    match (inner_spanned_data_type_token.data_type(), inner_spanned_data_type_token.float_bits()) {
+        (DataType::Integer(_), _) => quote! { raw_bits as #inner_data_type_tokens },
+        _ if inner_spanned_data_type_token.arbitrary_int().is_some() => {
+            generate_arbitrary_int_from_raw_tokens(&inner_data_type_tokens, quote! { raw_bits })
+        },
+        (_, Some(float_bits)) => {
+            generate_float_from_raw_tokens(&inner_data_type_tokens, float_bits, quote! { raw_bits })
+        },
+        _ => generate_custom_type_from_raw_tokens(&inner_data_type_tokens, quote! { raw_bits }),
+    }
+}
+
+/// Generates tokens converting `u128` bits into an arbitrary-width integer
+/// through its const `new_truncated` function, which sign extends the bits of
+/// signed integers.
+fn generate_arbitrary_int_from_raw_tokens(
+    arbitrary_int_data_type_tokens: &TokenStream,
+    raw_bits_tokens: TokenStream,
+) -> TokenStream {
+    quote! { <#arbitrary_int_data_type_tokens>::new_truncated(#raw_bits_tokens as _) }
+}
+
+/// Generates tokens converting `u128` bits into a float through its const
+/// `from_bits` function.
+fn generate_float_from_raw_tokens(
+    float_data_type_tokens: &TokenStream,
+    float_bits: u32,
+    raw_bits_tokens: TokenStream,
+) -> TokenStream {
+    let float_raw_type_tokens = format_ident!("u{float_bits}");
+    quote! { <#float_data_type_tokens>::from_bits(#raw_bits_tokens as #float_raw_type_tokens) }
+}
+
+/// Generates tokens converting a float value into `u128` bits through its
+/// const `to_bits` function.
+fn generate_float_into_raw_tokens(value_tokens: TokenStream) -> TokenStream {
+    quote! { ((#value_tokens).to_bits() as u128) }
+}
+
+/// Generates tokens converting an arbitrary-width integer value into `u128`
+/// bits through its const `value` function, masking the sign extension of
+/// signed integers.
+fn generate_arbitrary_int_into_raw_tokens(
+    arbitrary_int: ArbitraryIntType,
+    value_tokens: TokenStream,
+) -> TokenStream {
+    let mask_tokens = Literal::u128_unsuffixed(u128::MAX >> (u128::BITS - arbitrary_int.bits()));
+    quote! { ((#value_tokens).value() as u128 & #mask_tokens) }
+}
+
+/// Generates tokens converting `u128` bits into a custom type through the
+/// `BitfieldField` trait. The trait path is spanned at the type, so types
+/// without the trait fail with the unsatisfied trait bound at the field type.
+fn generate_custom_type_from_raw_tokens(
+    custom_field_data_type_tokens: &TokenStream,
+    raw_bits_tokens: TokenStream,
+) -> TokenStream {
+    let span = custom_type_span(custom_field_data_type_tokens);
+    quote_spanned! {span=>
+        <#custom_field_data_type_tokens as ::bitfields::BitfieldField>::from_raw(
+            <<#custom_field_data_type_tokens as ::bitfields::BitfieldField>::Raw as ::bitfields::BitfieldRaw>::from_u128(#raw_bits_tokens),
+        )
+    }
+}
+
+/// Generates tokens converting a custom field type value into `u128` bits
+/// through the `BitfieldField` trait, through `::bitfields::half` for
+/// half-precision float fields, or into the `none` value for `None` values of
+/// `Option` fields.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ::bitfields::BitfieldRaw::into_u128(<Custom as ::bitfields::BitfieldField>::into_raw(value))
+/// ```
+pub fn generate_custom_field_into_raw_tokens(
+    field: &Field,
+    value_tokens: TokenStream,
+) -> TokenStream {
+    if field.is_half_precision() {
+        return quote! {
+            ::bitfields::half::f32_to_f16_bits(#value_tokens as f32) as u128
+        };
+    }
+
+    if let Some(none_value) = field.none_value() {
+        let none_value_tokens = Literal::u128_unsuffixed(none_value);
+        let inner_spanned_data_type_token =
+            field.option_inner_data_type_token().expect("Expected wrapped type for option field");
+        let inner_into_raw_tokens = match (
+            inner_spanned_data_type_token.data_type(),
+            inner_spanned_data_type_token.arbitrary_int(),
+        ) {
+            (DataType::Integer(_), _) => quote! { inner_value as u128 },
+            (_, Some(arbitrary_int)) => {
+                generate_arbitrary_int_into_raw_tokens(arbitrary_int, quote! { inner_value })
+            },
+            _ if inner_spanned_data_type_token.float_bits().is_some() => {
+                generate_float_into_raw_tokens(quote! { inner_value })
+            },
+            _ => generate_custom_type_into_raw_tokens(
+                &inner_spanned_data_type_token.to_tokens(),
+                quote! { inner_value },
+            ),
+        };
+        return quote! {
+            match #value_tokens {
+                ::core::option::Option::Some(inner_value) => #inner_into_raw_tokens,
+                ::core::option::Option::None => #none_value_tokens,
+            }
+        };
+    }
+
+    if field.has_enumerated_values() {
+        return quote! { (#value_tokens).into_bits() as u128 };
+    }
+
+    if let Some(arbitrary_int) = field.spanned_data_type_token().arbitrary_int() {
+        return generate_arbitrary_int_into_raw_tokens(arbitrary_int, value_tokens);
+    }
+
+    if field.spanned_data_type_token().float_bits().is_some() {
+        return generate_float_into_raw_tokens(value_tokens);
+    }
+
+    generate_custom_type_into_raw_tokens(&field.spanned_data_type_token().to_tokens(), value_tokens)
+}
+
+/// Returns the span of the custom type, so conversion errors of types without
+/// the `BitfieldField` trait point at the field type.
+fn custom_type_span(custom_field_data_type_tokens: &TokenStream) -> Span {
+    custom_field_data_type_tokens
+        .clone()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |token| token.span())
+}
+
+/// Generates tokens converting a custom type value into `u128` bits through the
+/// `BitfieldField` trait.
+fn generate_custom_type_into_raw_tokens(
+    custom_field_data_type_tokens: &TokenStream,
+    value_tokens: TokenStream,
+) -> TokenStream {
+    let span = custom_type_span(custom_field_data_type_tokens);
+    quote_spanned! {span=>
+        ::bitfields::BitfieldRaw::into_u128(
+            <#custom_field_data_type_tokens as ::bitfields::BitfieldField>::into_raw(#value_tokens),
+        )
+    }
+}
+
 /// Specifies which fields to protect during an operation.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum ProtectionType {
@@ -741,14 +1963,19 @@ pub fn generate_extract_all_field_bits_from_variable_into_variable_tokens_and_se
     bitfield
         .fields()
         .iter()
+        // Alias fields share the bits of the aliased field, which is already set.
+        .filter(|field| !field.is_alias())
         .filter(|field| match protection_type {
             ProtectionType::ReadOnly => field.has_setter(),
             ProtectionType::WriteOnly => field.has_read_access(),
             ProtectionType::None => true,
         })
         .map(|field: &Field| {
+            // The bits of fields with converted bits and niche-typed fields are
+            // set as is, setters would convert them.
+            let use_setter = !has_converted_bits(bitfield, field) && !field.is_niche();
             generate_setting_field_from_variable_tokens(
-                bitfield, field, /* use_setter= */ true, /* cast_bits= */ false,
+                bitfield, field, use_setter, /* cast_bits= */ false,
                 /* check_bit_size= */ false, /* builder_caller= */ false,
             )
         })
@@ -769,11 +1996,7 @@ pub fn get_field_bits_tokens(
             #bitfield_reference::#field_bits_constant_ident_tokens
         }
     } else {
-        let field_bits = field.bits();
-
-        quote! {
-            #field_bits
-        }
+        field.bits().to_tokens()
     }
 }
 
@@ -791,11 +2014,7 @@ pub fn get_field_offset_tokens(
             #bitfield_reference::#field_offset_constant_ident_tokens
         }
     } else {
-        let field_offset = field.offset();
-
-        quote! {
-            #field_offset
-        }
+        field.offset().to_tokens()
     }
 }
 
@@ -803,8 +2022,9 @@ pub fn get_field_offset_tokens(
 fn get_bitfield_reference_tokens(bitfield: &Bitfield, builder_caller: bool) -> TokenStream {
     if builder_caller {
         let bitfield_name_tokens = bitfield.name_tokens();
+        let turbofish_tokens = bitfield.generic_parameters().turbofish_tokens();
         quote! {
-            #bitfield_name_tokens
+            #bitfield_name_tokens #turbofish_tokens
         }
     } else {
         quote! {
@@ -813,13 +2033,25 @@ fn get_bitfield_reference_tokens(bitfield: &Bitfield, builder_caller: bool) -> T
     }
 }
 
-/// Returns `("bit", "bits")` for integer/custom fields and `("byte", "bytes")`
-/// for array fields.
+/// Returns the raw type of a field with enumerated values, which is the
+/// smallest unsigned integer type holding the bits of the field.
+pub fn get_enumerated_values_raw_type_tokens(field: &Field) -> TokenStream {
+    match field.bits().known().expect("Expected known bits for enumerated values") {
+        0..=8 => quote! { u8 },
+        9..=16 => quote! { u16 },
+        17..=32 => quote! { u32 },
+        33..=64 => quote! { u64 },
+        _ => quote! { u128 },
+    }
+}
+
+/// Returns `("bit", "bits")` for integer/custom fields and packed array fields
+/// and `("byte", "bytes")` for other array fields.
 pub fn get_field_unit_terms(field: &Field) -> (&'static str, &'static str) {
     match field.spanned_data_type_token().data_type() {
         DataType::Array {
             ..
-        } => ("byte", "bytes"),
+        } if !field.is_packed_array() => ("byte", "bytes"),
         _ => ("bit", "bits"),
     }
 }
@@ -831,11 +2063,100 @@ pub fn get_setter_documentation(
     checked_setter: bool,
     builder_caller: bool,
 ) -> String {
-    let offset = field.offset();
+    let mut documentation =
+        get_setter_bits_documentation(bitfield, field, checked_setter, builder_caller);
+    if let Some(byte_order_documentation) = get_byte_order_documentation(field) {
+        documentation = format!("{documentation} {byte_order_documentation}");
+    }
+    if let Some(encoding) = field.encoding() {
+        documentation =
+            format!("{documentation} The value is encoded with the `{encoding}` encoding.");
+    }
+    if let Some(none_value) = field.none_value() {
+        documentation = format!("{documentation} `None` sets the bits to `{none_value:#X}`.");
+        if checked_setter {
+            documentation = format!(
+                "{documentation} Returns an error if a `Some` value has the bits \
+                 `{none_value:#X}`."
+            );
+        }
+    }
+    if let Some(overflow_documentation) =
+        (!checked_setter).then(|| get_overflow_policy_documentation(bitfield, field)).flatten()
+    {
+        documentation = format!("{documentation} {overflow_documentation}");
+    }
+    match field.constraint() {
+        Some(constraint) if checked_setter => {
+            format!("{documentation} Returns an error if the value doesn't satisfy `{constraint}`.")
+        },
+        _ => documentation,
+    }
+}
+
+/// Returns the documentation of what a setter does with values that don't fit
+/// in the field bits, if they aren't truncated.
+pub fn get_overflow_policy_documentation(
+    bitfield: &Bitfield,
+    field: &Field,
+) -> Option<&'static str> {
+    match get_field_overflow_policy(bitfield, field) {
+        OverflowPolicy::Truncate => None,
+        OverflowPolicy::Saturate => {
+            Some("Values too big to fit within the field bits are clamped to the field bounds.")
+        },
+        OverflowPolicy::Panic if field.constraint().is_some() => Some(
+            "Panics where the checked setter returns an error, including values that don't \
+             satisfy the constraint.",
+        ),
+        OverflowPolicy::Panic => Some("Panics where the checked setter returns an error."),
+        OverflowPolicy::DebugAssert if field.constraint().is_some() => Some(
+            "Panics in debug builds where the checked setter returns an error, including values \
+             that don't satisfy the constraint.",
+        ),
+        OverflowPolicy::DebugAssert => {
+            Some("Panics in debug builds where the checked setter returns an error.")
+        },
+    }
+}
+
+/// Returns the documentation of the byte order of a field, if it has one.
+pub fn get_byte_order_documentation(field: &Field) -> Option<String> {
+    let byte_order = match field.endian()? {
+        ConversionEndian::Little => "little-endian",
+        ConversionEndian::Big => "big-endian",
+    };
+
+    Some(format!("The bytes of the value are in {byte_order} order."))
+}
+
+/// Returns the setter documentation of the bits of a field.
+fn get_setter_bits_documentation(
+    bitfield: &Bitfield,
+    field: &Field,
+    checked_setter: bool,
+    builder_caller: bool,
+) -> String {
     let builder_caller_prefix = if builder_caller { "builder" } else { "" };
+    if let Some(segments_documentation) = get_split_field_segments_documentation(field) {
+        let suffix = if checked_setter {
+            ". Returns an error if the value is too big to fit within the field bits"
+        } else {
+            ""
+        };
+        return format!("Sets {builder_caller_prefix} {segments_documentation}{suffix}.");
+    }
+    let (Some(offset), Some(bits)) = (field.offset().known(), field.bits().known()) else {
+        let suffix = if checked_setter {
+            ". Returns an error if the value is too big to fit within the field bits"
+        } else {
+            ""
+        };
+        return format!("Sets {builder_caller_prefix} `{}`{suffix}.", field.name());
+    };
     let (unit, units) = get_field_unit_terms(field);
 
-    if field.bits() == 1 {
+    if bits == 1 {
         let suffix = if checked_setter
             && !matches!(
                 field.spanned_data_type_token().data_type(),
@@ -848,7 +2169,7 @@ pub fn get_setter_documentation(
         return format!("Sets {builder_caller_prefix} {unit} `{offset}`{suffix}.");
     }
 
-    let bits_end = offset + field.bits() - 1;
+    let bits_end = offset + bits - 1;
 
     let (documentation_bits_start, documentation_bits_end) =
         if bitfield.arguments().order() == BitOrder::Msb {
@@ -868,6 +2189,33 @@ pub fn get_setter_documentation(
     )
 }
 
+/// Returns the segments of a split field for documentation, or `None` if the
+/// field isn't split.
+///
+/// Example: ``segments `31`, `7`, `30..=25`, `11..=8` (most significant first),
+/// shifted left by `1` bit``
+pub fn get_split_field_segments_documentation(field: &Field) -> Option<String> {
+    let segments_documentation = field
+        .segments()?
+        .iter()
+        .map(|segment| {
+            if segment.bits() == 1 {
+                format!("`{}`", segment.offset())
+            } else {
+                format!("`{}..={}`", segment.offset() + segment.bits() - 1, segment.offset())
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    let shift_documentation = match field.shift() {
+        0 => String::new(),
+        1 => ", shifted left by `1` bit".to_string(),
+        shift => format!(", shifted left by `{shift}` bits"),
+    };
+
+    Some(format!("segments {segments_documentation} (most significant first){shift_documentation}"))
+}
+
 /// Generates the internal implementation of a new function.
 pub fn generate_new_function_implementation_tokens(
     bitfield: &Bitfield,
@@ -936,15 +2284,209 @@ pub fn generate_sign_extend_bit_operation_tokens(field: &Field) -> TokenStream {
 
     let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
     let field_data_type_bit_size = field.spanned_data_type_token().data_type().bit_size();
-    let field_bits = field.bits();
+    let field_bits_tokens = field.bits().to_tokens();
 
     quote! {
-        let shift = #field_data_type_bit_size - #field_bits;
+        let shift = #field_data_type_bit_size - #field_bits_tokens;
         let value = value as #field_data_type_tokens;
         let value = (value << shift) >> shift;
     }
 }
 
+/// Generates converting the bits extracted into `value` into the value of the
+/// field, swapping the bytes of fields with a byte order, then sign-extending
+/// signed fields and decoding encoded fields.
+pub fn generate_decoding_field_value_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+    let field_bits_tokens = field.bits().to_tokens();
+    let byte_swap_tokens = is_byte_swapped_field(bitfield, field).then(|| {
+        quote! {
+            #[allow(clippy::unnecessary_cast)]
+            let value = (value as u128).swap_bytes() >> (128 - #field_bits_tokens);
+        }
+    });
+    let decoding_value_tokens = generate_decoding_encoded_value_tokens(field);
+
+    quote! {
+        #byte_swap_tokens
+        #decoding_value_tokens
+    }
+}
+
+/// Generates decoding the bits in `value` of encoded fields, or sign-extending
+/// them otherwise.
+fn generate_decoding_encoded_value_tokens(field: &Field) -> TokenStream {
+    let Some(encoding) = field.encoding() else {
+        return generate_sign_extend_bit_operation_tokens(field);
+    };
+    let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+    let field_bits_tokens = field.bits().to_tokens();
+
+    match encoding {
+        FieldEncoding::SignMagnitude => quote! {
+            #[allow(clippy::unnecessary_cast)]
+            let value = value as u128;
+            let magnitude = (value & ((1u128 << (#field_bits_tokens - 1)) - 1)) as #field_data_type_tokens;
+            let value = if (value >> (#field_bits_tokens - 1)) & 1 == 1 { -magnitude } else { magnitude };
+        },
+        FieldEncoding::OffsetBinary => {
+            let sign_extend_tokens = generate_sign_extend_bit_operation_tokens(field);
+            quote! {
+                #[allow(clippy::unnecessary_cast)]
+                let value = value as u128 ^ (1u128 << (#field_bits_tokens - 1));
+                #sign_extend_tokens
+            }
+        },
+        FieldEncoding::Bcd => quote! {
+            let value = {
+                #[allow(clippy::unnecessary_cast)]
+                let encoded = value as u128;
+                let mut value = 0u128;
+                let mut place = 1u128;
+                let mut digit: u32 = 0;
+                while digit < #field_bits_tokens / 4 {
+                    value += ((encoded >> (digit * 4)) & 0xF) * place;
+                    place *= 10;
+                    digit += 1;
+                }
+                value
+            };
+        },
+        FieldEncoding::Gray => quote! {
+            let value = {
+                #[allow(clippy::unnecessary_cast)]
+                let mut value = value as u128;
+                let mut shifted = value >> 1;
+                while shifted != 0 {
+                    value ^= shifted;
+                    shifted >>= 1;
+                }
+                value
+            };
+        },
+        FieldEncoding::ActiveLow => quote! {
+            #[allow(clippy::unnecessary_cast)]
+            let value = !(value as u128) & (u128::MAX >> (128 - #field_bits_tokens));
+        },
+    }
+}
+
+/// Generates tokens converting the raw value of a scaled field into its scaled
+/// value.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// (raw as f64 * 0.1f64 - 40f64)
+/// ```
+pub fn generate_raw_to_scaled_value_tokens(field: &Field, raw_tokens: &TokenStream) -> TokenStream {
+    let scaling = field.scaling().expect("Expected scaling for scaled field");
+    let scale_tokens = (scaling.scale() != 1.0).then(|| {
+        let scale = Literal::f64_suffixed(scaling.scale());
+        quote! { * #scale }
+    });
+    let offset_tokens = (scaling.offset() != 0.0).then(|| {
+        let offset = Literal::f64_suffixed(scaling.offset().abs());
+        if scaling.offset() < 0.0 {
+            quote! { - #offset }
+        } else {
+            quote! { + #offset }
+        }
+    });
+
+    quote! {
+        (#raw_tokens as f64 #scale_tokens #offset_tokens)
+    }
+}
+
+/// Generates tokens converting a scaled value into the raw value of a scaled
+/// field, moved half a step away from zero so truncating it rounds to the
+/// nearest raw value.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// {
+///     let raw = (value + 40f64) / 0.1f64;
+///     if raw < 0.0 { raw - 0.5 } else { raw + 0.5 }
+/// }
+/// ```
+pub fn generate_scaled_value_to_raw_tokens(
+    field: &Field,
+    value_tokens: &TokenStream,
+) -> TokenStream {
+    let scaling = field.scaling().expect("Expected scaling for scaled field");
+    let offset_tokens = (scaling.offset() != 0.0).then(|| {
+        let offset = Literal::f64_suffixed(scaling.offset().abs());
+        if scaling.offset() < 0.0 {
+            quote! { + #offset }
+        } else {
+            quote! { - #offset }
+        }
+    });
+    let scale_tokens = (scaling.scale() != 1.0).then(|| {
+        let scale = Literal::f64_suffixed(scaling.scale());
+        quote! { / #scale }
+    });
+
+    quote! {
+        {
+            let raw = (#value_tokens #offset_tokens) #scale_tokens;
+            if raw < 0.0 { raw - 0.5 } else { raw + 0.5 }
+        }
+    }
+}
+
+/// Generates the condition that a raw value from
+/// [`generate_scaled_value_to_raw_tokens`] doesn't fit within the field bits
+/// once truncated. `NaN` values never fit.
+pub fn generate_scaled_raw_value_overflow_condition_tokens(
+    bitfield: &Bitfield,
+    field: &Field,
+    raw_tokens: &TokenStream,
+    builder_caller: bool,
+) -> TokenStream {
+    let field_bits_tokens = get_field_bits_tokens(bitfield, field, builder_caller);
+
+    if field.spanned_data_type_token().data_type().unsigned() {
+        quote! {
+            !(#raw_tokens > -1.0 && #raw_tokens < (u128::MAX >> (128 - #field_bits_tokens)) as f64 + 1.0)
+        }
+    } else {
+        quote! {
+            !(#raw_tokens > -((1u128 << (#field_bits_tokens - 1)) as f64) - 1.0
+                && #raw_tokens < (1u128 << (#field_bits_tokens - 1)) as f64)
+        }
+    }
+}
+
+/// Returns the scaling of a scaled field for documentation.
+///
+/// Example: ``in `C`, scaled from the raw value as `raw * 0.1 - 40` ``
+pub fn get_scaling_documentation(field: &Field) -> String {
+    let scaling = field.scaling().expect("Expected scaling for scaled field");
+    let unit_documentation =
+        scaling.unit().as_ref().map(|unit| format!("in `{unit}`, ")).unwrap_or_default();
+
+    if let Some(fixed) = scaling.fixed() {
+        return format!(
+            "{unit_documentation}as a fixed-point number with `{fixed}` fraction bits of the raw \
+             value"
+        );
+    }
+
+    let mut formula = String::from("raw");
+    if scaling.scale() != 1.0 {
+        formula.push_str(&format!(" * {}", scaling.scale()));
+    }
+    if scaling.offset() < 0.0 {
+        formula.push_str(&format!(" - {}", scaling.offset().abs()));
+    } else if scaling.offset() > 0.0 {
+        formula.push_str(&format!(" + {}", scaling.offset()));
+    }
+
+    format!("{unit_documentation}scaled from the raw value as `{formula}`")
+}
+
 pub fn generate_protected_bits_mask_tokens(
     bitfield: &Bitfield,
     protection_type: ProtectionType,
@@ -961,11 +2503,22 @@ pub fn generate_protected_bits_mask_tokens(
         })
         .collect();
 
+    let protected_bit_ranges: Vec<(BitsExpr, BitsExpr)> =
+        protected_fields.iter().flat_map(|field| field.bit_ranges()).collect();
+
+    if bitfield.spanned_data_type_token().has_const_array_length()
+        || protected_bit_ranges
+            .iter()
+            .any(|(offset, bits)| bits.known().is_none() || offset.known().is_none())
+    {
+        return generate_deferred_protected_bits_mask_tokens(bitfield, &protected_bit_ranges);
+    }
+
     if bitfield.is_integer_backed() {
         let mut protected_mask: u128 = 0;
-        for field in &protected_fields {
-            let field_offset = field.offset();
-            let field_end_bits = field_offset + field.bits();
+        for (offset, bits) in &protected_bit_ranges {
+            let field_offset = offset.known().expect("Expected known field offset");
+            let field_end_bits = field_offset + bits.known().expect("Expected known field bits");
             for bit in field_offset..field_end_bits {
                 protected_mask |= 1u128 << bit;
             }
@@ -976,9 +2529,9 @@ pub fn generate_protected_bits_mask_tokens(
     } else {
         let num_bytes = (bitfield.spanned_data_type_token().data_type().bit_size() / 8) as usize;
         let mut bytes_list = vec![0u8; num_bytes];
-        for field in &protected_fields {
-            let field_offset = field.offset();
-            let field_end_bits = field_offset + field.bits();
+        for (offset, bits) in &protected_bit_ranges {
+            let field_offset = offset.known().expect("Expected known field offset");
+            let field_end_bits = field_offset + bits.known().expect("Expected known field bits");
             for bit in field_offset..field_end_bits {
                 let byte_idx = (bit / 8) as usize;
                 let bit_in_byte = (bit % 8) as u8;
@@ -993,6 +2546,53 @@ pub fn generate_protected_bits_mask_tokens(
     }
 }
 
+/// Generates the protected bits mask as a const expression, used when the bits
+/// or offset of a protected field, or the length of the bitfield, are only
+/// known by the compiler.
+fn generate_deferred_protected_bits_mask_tokens(
+    bitfield: &Bitfield,
+    protected_bit_ranges: &[(BitsExpr, BitsExpr)],
+) -> TokenStream {
+    let bitfield_type = bitfield.spanned_data_type_token().to_tokens();
+
+    if bitfield.is_integer_backed() {
+        let setting_field_mask_tokens_list = protected_bit_ranges.iter().map(|(offset, bits)| {
+            let field_bits_tokens = bits.to_tokens();
+            let field_offset_tokens = offset.to_tokens();
+            quote! {
+                mask |= (#bitfield_type::MAX >> (#bitfield_type::BITS - #field_bits_tokens)) << #field_offset_tokens;
+            }
+        });
+        quote! {
+            let protected_mask = {
+                let mut mask: #bitfield_type = 0;
+                #( #setting_field_mask_tokens_list )*
+                mask
+            };
+        }
+    } else {
+        let num_bytes = get_bytes_length_tokens(bitfield);
+        let setting_field_mask_tokens_list = protected_bit_ranges.iter().map(|(offset, bits)| {
+            let field_bits_tokens = bits.to_tokens();
+            let field_offset_tokens = offset.to_tokens();
+            quote! {
+                let mut bit = #field_offset_tokens;
+                while bit < #field_offset_tokens + #field_bits_tokens {
+                    mask[(bit / 8) as usize] |= 1 << (bit % 8);
+                    bit += 1;
+                }
+            }
+        });
+        quote! {
+            let protected_mask: #bitfield_type = {
+                let mut mask = [0u8; #num_bytes];
+                #( #setting_field_mask_tokens_list )*
+                mask
+            };
+        }
+    }
+}
+
 /// Returns `bits` for integer-backed bitfields and `bytes` for array-backed
 /// bitfields.
 pub fn generate_backing_data_param_ident(bitfield: &Bitfield) -> TokenStream {
@@ -1007,3 +2607,89 @@ pub fn generate_backing_data_param_ident(bitfield: &Bitfield) -> TokenStream {
 pub const fn get_bits_or_bytes_term(bitfield: &Bitfield) -> &'static str {
     if bitfield.is_integer_backed() { "bits" } else { "bytes" }
 }
+
+/// Generates the tokens casting the `bits` parameter of a signed integer
+/// bitfield into the unsigned type holding the bits, or `None` for other
+/// bitfields.
+pub fn generate_signed_bits_param_cast_tokens(bitfield: &Bitfield) -> Option<TokenStream> {
+    bitfield.is_signed_integer_backed().then(|| {
+        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+        quote! {
+            let bits = bits as #bitfield_data_type_tokens;
+        }
+    })
+}
+
+/// Casts the bits into the declared type of a signed integer bitfield, or
+/// returns them as is for other bitfields.
+pub fn generate_signed_bits_cast_tokens(
+    bitfield: &Bitfield,
+    bits_tokens: &TokenStream,
+) -> TokenStream {
+    if bitfield.is_signed_integer_backed() {
+        let declared_data_type_tokens = bitfield.declared_data_type_token().to_tokens();
+        quote! { (#bits_tokens) as #declared_data_type_tokens }
+    } else {
+        bits_tokens.clone()
+    }
+}
+
+/// Generates the tokens converting the `words` variable of a word array
+/// bitfield into a `bytes` variable in the same endian, as each word is split
+/// into its bytes.
+pub fn generate_words_into_bytes_tokens(
+    bitfield: &Bitfield,
+    endian: ConversionEndian,
+) -> TokenStream {
+    let word_bytes = get_word_bytes(bitfield);
+    let bytes_length = get_bytes_length_tokens(bitfield);
+    let byte_shift_tokens = match endian {
+        ConversionEndian::Little => quote! { (i % #word_bytes) * 8 },
+        ConversionEndian::Big => quote! { (#word_bytes - 1 - i % #word_bytes) * 8 },
+    };
+
+    quote! {
+        let mut bytes = [0u8; #bytes_length];
+        let mut i = 0usize;
+        while i < #bytes_length {
+            bytes[i] = (words[i / #word_bytes] >> (#byte_shift_tokens)) as u8;
+            i += 1;
+        }
+    }
+}
+
+/// Generates the tokens converting the `bytes` variable of a word array
+/// bitfield into a `words` variable in the same endian, as each word is joined
+/// from its bytes.
+pub fn generate_bytes_into_words_tokens(
+    bitfield: &Bitfield,
+    endian: ConversionEndian,
+) -> TokenStream {
+    let word_type = bitfield.word_type().expect("Expected word array bitfield");
+    let word_type_tokens = word_type.to_tokens();
+    let word_bytes = get_word_bytes(bitfield);
+    let words_length = bitfield
+        .declared_data_type_token()
+        .array_length_tokens()
+        .expect("Expected length for word array bitfield");
+    let bytes_length = get_bytes_length_tokens(bitfield);
+    let byte_shift_tokens = match endian {
+        ConversionEndian::Little => quote! { (i % #word_bytes) * 8 },
+        ConversionEndian::Big => quote! { (#word_bytes - 1 - i % #word_bytes) * 8 },
+    };
+
+    quote! {
+        let mut words: [#word_type_tokens; #words_length] = [0; #words_length];
+        let mut i = 0usize;
+        while i < #bytes_length {
+            words[i / #word_bytes] |= (bytes[i] as #word_type_tokens) << (#byte_shift_tokens);
+            i += 1;
+        }
+    }
+}
+
+/// Returns the number of bytes of each word of a word array bitfield.
+fn get_word_bytes(bitfield: &Bitfield) -> usize {
+    let word_type = bitfield.word_type().expect("Expected word array bitfield");
+    (word_type.bit_size() / 8) as usize
+}
@@ -20,17 +20,69 @@ impl Bitfield {
 }
 
 impl Field {
-    /// Generates the setter identifier token stream for the field.
+    /// Generates the getter identifier token stream for the field, which is
+    /// suffixed with `_raw` for scaled fields.
+    pub fn getter_ident_tokens(&self) -> TokenStream {
+        format_ident!("{}", self.raw_accessor_name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
+    /// Generates the setter identifier token stream for the field, which is
+    /// suffixed with `_raw` for scaled fields.
     pub fn setter_ident_tokens(&self) -> TokenStream {
-        format_ident!("set_{}", self.name(), span = self.name_ident().span()).to_token_stream()
+        format_ident!("set_{}", self.raw_accessor_name(), span = self.name_ident().span())
+            .to_token_stream()
     }
 
-    /// Generates the checked setter identifier token stream for the field.
+    /// Generates the checked setter identifier token stream for the field,
+    /// which is suffixed with `_raw` for scaled fields.
     pub fn checked_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("checked_set_{}", self.raw_accessor_name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
+    /// Generates the builder setter identifier token stream for the field,
+    /// which is suffixed with `_raw` for scaled fields.
+    pub fn builder_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("with_{}", self.raw_accessor_name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
+    /// Generates the checked builder setter identifier token stream for the
+    /// field, which is suffixed with `_raw` for scaled fields.
+    pub fn checked_builder_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("checked_with_{}", self.raw_accessor_name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
+    /// Generates the scaled getter identifier tokens of a scaled field.
+    pub fn scaled_getter_ident_tokens(&self) -> TokenStream {
+        self.name_ident().to_token_stream()
+    }
+
+    /// Generates the scaled setter identifier tokens of a scaled field.
+    pub fn scaled_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("set_{}", self.name(), span = self.name_ident().span()).to_token_stream()
+    }
+
+    /// Generates the checked scaled setter identifier tokens of a scaled field.
+    pub fn checked_scaled_setter_ident_tokens(&self) -> TokenStream {
         format_ident!("checked_set_{}", self.name(), span = self.name_ident().span())
             .to_token_stream()
     }
 
+    /// Generates the scaled builder setter identifier tokens of a scaled field.
+    pub fn scaled_builder_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("with_{}", self.name(), span = self.name_ident().span()).to_token_stream()
+    }
+
+    /// Generates the checked scaled builder setter identifier tokens of a
+    /// scaled field.
+    pub fn checked_scaled_builder_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("checked_with_{}", self.name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
     /// Generate the bits constant identifier tokens.
     pub fn bits_constant_ident_tokens(&self) -> TokenStream {
         format_ident!("{}_BITS", self.name().to_uppercase(), span = self.name_ident().span())
@@ -42,4 +94,56 @@ impl Field {
         format_ident!("{}_OFFSET", self.name().to_uppercase(), span = self.name_ident().span())
             .to_token_stream()
     }
+
+    /// Generates the element bits constant identifier tokens of a packed array
+    /// field.
+    pub fn element_bits_constant_ident_tokens(&self) -> TokenStream {
+        format_ident!(
+            "{}_ELEMENT_BITS",
+            self.name().to_uppercase(),
+            span = self.name_ident().span()
+        )
+        .to_token_stream()
+    }
+
+    /// Generates the length constant identifier tokens of a packed array field.
+    pub fn length_constant_ident_tokens(&self) -> TokenStream {
+        format_ident!("{}_LEN", self.name().to_uppercase(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
+    /// Generates the checked getter identifier tokens of a non-zero field.
+    pub fn checked_getter_ident_tokens(&self) -> TokenStream {
+        format_ident!("checked_{}", self.name(), span = self.name_ident().span()).to_token_stream()
+    }
+
+    /// Generates the raw getter identifier tokens of a field with enumerated
+    /// values.
+    pub fn raw_getter_ident_tokens(&self) -> TokenStream {
+        format_ident!("{}_raw", self.name(), span = self.name_ident().span()).to_token_stream()
+    }
+
+    /// Generates the element getter identifier tokens of a packed array field.
+    pub fn element_getter_ident_tokens(&self) -> TokenStream {
+        format_ident!("{}_at", self.name(), span = self.name_ident().span()).to_token_stream()
+    }
+
+    /// Generates the checked element getter identifier tokens of a packed array
+    /// field.
+    pub fn checked_element_getter_ident_tokens(&self) -> TokenStream {
+        format_ident!("checked_{}_at", self.name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
+
+    /// Generates the element setter identifier tokens of a packed array field.
+    pub fn element_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("set_{}_at", self.name(), span = self.name_ident().span()).to_token_stream()
+    }
+
+    /// Generates the checked element setter identifier tokens of a packed array
+    /// field.
+    pub fn checked_element_setter_ident_tokens(&self) -> TokenStream {
+        format_ident!("checked_set_{}_at", self.name(), span = self.name_ident().span())
+            .to_token_stream()
+    }
 }
@@ -3,9 +3,14 @@ use quote::quote;
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
 use crate::generating::bitfield::features::common::generator_helper::{
-    BitsSource, generate_extract_field_bits_from_source_into_variable_tokens,
+    BitsSource, generate_ascii_str_from_bytes_tokens, generate_custom_field_from_raw_tokens,
+    generate_decoding_field_value_tokens,
+    generate_extract_field_bits_from_source_into_variable_tokens,
+    generate_raw_to_scaled_value_tokens, has_converted_bits, is_byte_ascii_str_field,
 };
-use crate::parsing::bitfields::bitfield::Bitfield;
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::common::spanned_data_type::{DataType, SpannedDataTypeToken};
+use crate::parsing::common::to_tokens::ToTokens;
 
 /// Generates `Debug` trait implementation.
 pub struct DebugTraitFeature;
@@ -31,12 +36,14 @@ impl Feature for DebugTraitFeature {
 impl DebugTraitFeature {
     /// Generates debug trait feature tokens.
     fn generate_default_trait_feature_tokens(bitfield: &Bitfield) -> TokenStream {
-        let bitfield_name_tokens = bitfield.name_tokens();
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
         let bitfield_name = bitfield.name();
         let set_debug_fields = Self::generate_debug_set_field_tokens(bitfield);
 
         quote! {
-            impl core::fmt::Debug for #bitfield_name_tokens {
+            impl #impl_generics_tokens core::fmt::Debug for #bitfield_type_tokens #where_clause_tokens {
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                     let this = self;
                     let mut debug = f.debug_struct(#bitfield_name);
@@ -51,6 +58,7 @@ impl DebugTraitFeature {
         bitfield
             .fields()
             .iter()
+            .filter(|field| !field.is_alias())
             .map(|field| {
                 let field_name = field.name();
                 let extract_field_bits_from_bitfield_into_variable =
@@ -63,11 +71,110 @@ impl DebugTraitFeature {
                         /* builder_caller= */ false,
                     );
 
+                if field.is_scaled() {
+                    return Self::generate_debug_set_scaled_field_tokens(
+                        bitfield,
+                        field,
+                        &extract_field_bits_from_bitfield_into_variable,
+                    );
+                }
+
+                // Fields with converted bits or of a field type of the `bitfields`
+                // crate show their value.
+                let decoding_field_value_tokens = if has_converted_bits(bitfield, field) {
+                    Some(generate_decoding_field_value_tokens(bitfield, field))
+                } else {
+                    Self::generate_debug_field_type_value_tokens(bitfield, field)
+                };
+
                 quote! {
                     #extract_field_bits_from_bitfield_into_variable
+                    #decoding_field_value_tokens
                     debug.field(#field_name, &value);
                 }
             })
             .collect()
     }
+
+    /// Generates converting the bits of a field into the value of its type, for
+    /// the field types of the `bitfields` crate shown as their value instead of
+    /// their bits. Other custom types aren't required to implement `Debug`.
+    fn generate_debug_field_type_value_tokens(
+        bitfield: &Bitfield,
+        field: &Field,
+    ) -> Option<TokenStream> {
+        if !Self::is_shown_as_value(field) {
+            return None;
+        }
+
+        if is_byte_ascii_str_field(bitfield, field) {
+            let ascii_str_from_bytes_tokens =
+                generate_ascii_str_from_bytes_tokens(field, quote! { value });
+            return Some(quote! {
+                let value = #ascii_str_from_bytes_tokens;
+            });
+        }
+
+        let custom_field_from_raw_tokens =
+            generate_custom_field_from_raw_tokens(field, quote! { value });
+        Some(quote! {
+            let value = {
+                #[allow(clippy::unnecessary_cast)]
+                let value = value as u128;
+                #custom_field_from_raw_tokens
+            };
+        })
+    }
+
+    /// Returns if the field is of a field type of the `bitfields` crate, which
+    /// is shown as its value: floats, ASCII strings, arbitrary-width integers,
+    /// and `Option` fields of integers, non-zero integers or of those types,
+    /// whose `none` value isn't decoded into the wrapped type.
+    fn is_shown_as_value(field: &Field) -> bool {
+        Self::is_shown_as_value_type(&field.spanned_data_type_token())
+            || field.option_inner_data_type_token().is_some_and(|inner_data_type_token| {
+                matches!(inner_data_type_token.data_type(), DataType::Integer(_))
+                    || inner_data_type_token.non_zero_bits().is_some()
+                    || Self::is_shown_as_value_type(&inner_data_type_token)
+            })
+    }
+
+    /// Returns if the type is a field type of the `bitfields` crate, which is
+    /// shown as its value.
+    fn is_shown_as_value_type(spanned_data_type_token: &SpannedDataTypeToken) -> bool {
+        spanned_data_type_token.float_bits().is_some()
+            || spanned_data_type_token.ascii_str_length_expr().is_some()
+            || spanned_data_type_token.arbitrary_int().is_some()
+    }
+
+    /// Generates setting the scaled value of a fixed-point or scaled field,
+    /// followed by its unit if it has one.
+    fn generate_debug_set_scaled_field_tokens(
+        bitfield: &Bitfield,
+        field: &Field,
+        extract_field_bits_from_bitfield_into_variable: &TokenStream,
+    ) -> TokenStream {
+        let field_name = field.name();
+        let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+        let sign_extend_tokens = generate_decoding_field_value_tokens(bitfield, field);
+        let scaled_value_tokens = generate_raw_to_scaled_value_tokens(
+            field,
+            &quote! { (value as #field_data_type_tokens) },
+        );
+        let set_debug_field_tokens =
+            match field.scaling().expect("Expected scaling for scaled field").unit() {
+                Some(unit) => quote! {
+                    debug.field(#field_name, &format_args!("{:?} {}", #scaled_value_tokens, #unit));
+                },
+                None => quote! {
+                    debug.field(#field_name, &#scaled_value_tokens);
+                },
+            };
+
+        quote! {
+            #extract_field_bits_from_bitfield_into_variable
+            #sign_extend_tokens
+            #set_debug_field_tokens
+        }
+    }
 }
@@ -31,13 +31,15 @@ impl Feature for DefaultTraitFeature {
 
 impl DefaultTraitFeature {
     fn generate_default_trait_feature_tokens(bitfield: &Bitfield) -> TokenStream {
-        let bitfield_name_tokens = bitfield.name_tokens();
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
         let default_trait_feature_implementation_tokens =
             Self::generate_default_trait_feature_implementation_tokens(bitfield);
 
         quote! {
-            impl core::default::Default for #bitfield_name_tokens {
-                fn default() -> #bitfield_name_tokens {
+            impl #impl_generics_tokens core::default::Default for #bitfield_type_tokens #where_clause_tokens {
+                fn default() -> #bitfield_type_tokens {
                     #default_trait_feature_implementation_tokens
                 }
             }
@@ -48,8 +48,8 @@ impl FieldConstantsFeature {
             .filter(|field| field.has_constants())
             .map(|field| {
                 let visibility_tokens = field.visibility().to_tokens();
-                let field_bits = field.bits();
-                let field_offset = field.offset();
+                let field_bits = field.bits().to_tokens();
+                let field_offset = field.offset().to_tokens();
                 let field_bits_constant_ident_tokens = field.bits_constant_ident_tokens();
                 let field_offset_constant_ident_tokens = field.offset_constant_ident_tokens();
 
@@ -58,11 +58,29 @@ impl FieldConstantsFeature {
                     format!("The number of {units} `{}` occupies in the bitfield.", field.name());
                 let offset_documentation =
                     format!("The {unit} offset of `{}` in the bitfield.", field.name());
+                let element_constants_tokens = field.element_bits().map(|element_bits| {
+                    let element_bits_constant_ident_tokens =
+                        field.element_bits_constant_ident_tokens();
+                    let length_constant_ident_tokens = field.length_constant_ident_tokens();
+                    let len =
+                        field.spanned_data_type_token().array_length().expect("Expected array length");
+                    let element_bits_documentation =
+                        format!("The number of bits of each element of `{}`.", field.name());
+                    let length_documentation =
+                        format!("The number of elements of `{}`.", field.name());
+                    quote! {
+                        #[doc = #element_bits_documentation]
+                        #visibility_tokens const #element_bits_constant_ident_tokens: u32 = #element_bits;
+                        #[doc = #length_documentation]
+                        #visibility_tokens const #length_constant_ident_tokens: usize = #len;
+                    }
+                });
                 quote! {
                     #[doc = #bits_documentation]
                     #visibility_tokens const #field_bits_constant_ident_tokens: u32 = #field_bits;
                     #[doc = #offset_documentation]
                     #visibility_tokens const #field_offset_constant_ident_tokens: u32 = #field_offset;
+                    #element_constants_tokens
         }
             })
             .collect()
@@ -0,0 +1,125 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::generating::bitfield::feature::{Feature, FeaturePosition};
+use crate::generating::bitfield::features::common::generator_helper::get_enumerated_values_raw_type_tokens;
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::common::to_tokens::ToTokens;
+
+/// Generates the enums of fields with enumerated values, their const
+/// `from_bits` and `into_bits` functions and their `BitfieldField` trait
+/// implementations, which convert bits without a variant into the first
+/// variant.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[repr(u8)]
+/// enum BgMode {
+///     Picture = 0,
+///     Video = 1,
+/// }
+///
+/// impl BgMode {
+///     const fn from_bits(bits: u8) -> Self { ... }
+///     const fn into_bits(self) -> u8 { ... }
+/// }
+///
+/// impl ::bitfields::BitfieldField for BgMode { ... }
+/// ```
+pub struct FieldEnumsFeature;
+
+impl Feature for FieldEnumsFeature {
+    fn generate_feature(&self, bitfield: &Bitfield) -> TokenStream {
+        Self::generate_field_enums_feature_tokens(bitfield)
+    }
+
+    fn enabled(&self, bitfield: &Bitfield) -> bool {
+        bitfield.fields().iter().any(Field::has_enumerated_values)
+    }
+
+    fn feature_position(&self) -> FeaturePosition {
+        FeaturePosition::Above
+    }
+
+    fn order_priority(&self) -> u32 {
+        1
+    }
+}
+
+impl FieldEnumsFeature {
+    fn generate_field_enums_feature_tokens(bitfield: &Bitfield) -> TokenStream {
+        bitfield
+            .fields()
+            .iter()
+            .filter(|field| field.has_enumerated_values())
+            .map(|field| Self::generate_field_enum_tokens(bitfield, field))
+            .collect()
+    }
+
+    fn generate_field_enum_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        // The enum is used by both the getters and setters of the field.
+        let visibility_tokens =
+            field.getter_visibility().widest(field.setter_visibility()).to_tokens();
+        let enum_name_tokens = field.spanned_data_type_token().to_tokens();
+        let raw_type_tokens = get_enumerated_values_raw_type_tokens(field);
+        let bits = field.bits().known().expect("Expected known bits for enumerated values");
+        let enumerated_values =
+            field.enumerated_values().expect("Expected enumerated values of the field");
+        let variant_name_idents: Vec<_> =
+            enumerated_values.iter().map(|value| value.name_ident().clone()).collect();
+        let variant_values_tokens: Vec<Literal> =
+            enumerated_values.iter().map(|value| Literal::u128_unsuffixed(value.value())).collect();
+        let fallback_variant_name_ident = &variant_name_idents[0];
+        let documentation = format!(
+            "The enumerated values of the `{}` field of `{}`.",
+            field.name(),
+            bitfield.name()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+            )]
+            #[repr(#raw_type_tokens)]
+            #visibility_tokens enum #enum_name_tokens {
+                #( #variant_name_idents = #variant_values_tokens, )*
+            }
+
+            impl #enum_name_tokens {
+                /// Creates the value from the field bits. Bits without a variant
+                /// become the first variant.
+                #visibility_tokens const fn from_bits(bits: #raw_type_tokens) -> Self {
+                    #[allow(unreachable_patterns)]
+                    match bits {
+                        #( #variant_values_tokens => Self::#variant_name_idents, )*
+                        _ => Self::#fallback_variant_name_ident,
+                    }
+                }
+
+                /// Returns the field bits of the value.
+                #visibility_tokens const fn into_bits(self) -> #raw_type_tokens {
+                    self as #raw_type_tokens
+                }
+            }
+
+            impl ::bitfields::BitfieldField for #enum_name_tokens {
+                const BITS: u32 = #bits;
+                type Raw = #raw_type_tokens;
+
+                fn from_raw(raw: #raw_type_tokens) -> Self {
+                    Self::from_bits(raw)
+                }
+
+                fn into_raw(self) -> #raw_type_tokens {
+                    self.into_bits()
+                }
+            }
+        }
+    }
+}
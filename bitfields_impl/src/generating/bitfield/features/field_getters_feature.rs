@@ -3,11 +3,17 @@ use quote::quote;
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
 use crate::generating::bitfield::features::common::generator_helper::{
-    BitsSource, generate_extract_field_bits_from_source_into_variable_tokens,
-    generate_sign_extend_bit_operation_tokens, get_field_unit_terms, get_function_modifier_tokens,
+    BitsSource, generate_ascii_str_from_bytes_tokens, generate_custom_field_from_raw_tokens,
+    generate_decoding_field_value_tokens, generate_element_from_raw_tokens,
+    generate_extract_field_bits_from_source_into_variable_tokens,
+    get_array_element_data_type_tokens, get_array_length_tokens, get_byte_order_documentation,
+    get_enumerated_values_raw_type_tokens, get_field_function_modifier_tokens,
+    get_field_offset_tokens, get_field_unit_terms, get_split_field_segments_documentation,
+    is_byte_ascii_str_field,
 };
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
 use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitOrder;
+use crate::parsing::bitfields::bits_attribute::bits_arguments::FieldEncoding;
 use crate::parsing::common::spanned_data_type::{DataType, IntegerType};
 use crate::parsing::common::to_tokens::ToTokens;
 
@@ -40,10 +46,10 @@ impl FieldGettersFeature {
             .iter()
             .filter(|field: &&Field| field.has_getter())
             .map(|field| {
-                let visibility_tokens = field.visibility().to_tokens();
+                let visibility_tokens = field.getter_visibility().to_tokens();
                 let documentation = Self::get_getter_documentation(bitfield, field);
-                let function_modifier_tokens = get_function_modifier_tokens(bitfield);
-                let name_tokens = field.name_tokens();
+                let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+                let name_tokens = field.getter_ident_tokens();
                 let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
                 let extract_field_bits_from_source_into_variable_tokens =
                     generate_extract_field_bits_from_source_into_variable_tokens(
@@ -54,8 +60,20 @@ impl FieldGettersFeature {
                         /* invert_bits= */ false,
                         /* builder_caller= */ false,
                     );
-                let sign_extend_tokens_stream = generate_sign_extend_bit_operation_tokens(field);
+                let sign_extend_tokens_stream = generate_decoding_field_value_tokens(bitfield, field);
                 let value_return_token_stream = Self::generate_value_return_tokens(bitfield, field);
+                let element_getters_tokens = field
+                    .is_packed_array()
+                    .then(|| Self::generate_element_getters_tokens(bitfield, field));
+                let checked_getter_tokens = if field.is_non_zero() {
+                    Some(Self::generate_checked_non_zero_getter_tokens(bitfield, field))
+                } else {
+                    (field.encoding() == Some(FieldEncoding::Bcd))
+                        .then(|| Self::generate_checked_bcd_getter_tokens(bitfield, field))
+                };
+                let raw_getter_tokens = field
+                    .has_enumerated_values()
+                    .then(|| Self::generate_enumerated_values_raw_getter_tokens(bitfield, field));
 
                 quote! {
                     #[doc = #documentation]
@@ -65,11 +83,180 @@ impl FieldGettersFeature {
                         #sign_extend_tokens_stream
                         #value_return_token_stream
                     }
+
+                    #element_getters_tokens
+                    #checked_getter_tokens
+                    #raw_getter_tokens
                 }
             })
             .collect()
     }
 
+    /// Generates the checked getter function of a non-zero field, which
+    /// returns an error instead of panicking when the bits are zero.
+    fn generate_checked_non_zero_getter_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.getter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let getter_ident_tokens = field.getter_ident_tokens();
+        let checked_getter_ident_tokens = field.checked_getter_ident_tokens();
+        let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+        let extract_field_bits_from_source_into_variable_tokens =
+            generate_extract_field_bits_from_source_into_variable_tokens(
+                bitfield,
+                field,
+                BitsSource::Bitfield,
+                /* cast_bits= */ false,
+                /* invert_bits= */ false,
+                /* builder_caller= */ false,
+            );
+        let documentation =
+            format!("Returns `{}`. Returns an error if the bits are zero.", field.name());
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_getter_ident_tokens(&self) -> ::core::result::Result<#field_data_type_tokens, &'static str> {
+                let this = self;
+                #extract_field_bits_from_source_into_variable_tokens
+                if value == 0 {
+                    return Err("The field bits are zero.");
+                }
+                Ok(self.#getter_ident_tokens())
+            }
+        }
+    }
+
+    /// Generates the checked getter function of a BCD field, which returns an
+    /// error instead of decoding digits greater than 9.
+    fn generate_checked_bcd_getter_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.getter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let getter_ident_tokens = field.getter_ident_tokens();
+        let checked_getter_ident_tokens = field.checked_getter_ident_tokens();
+        let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+        let field_bits_tokens = field.bits().to_tokens();
+        let extract_field_bits_from_source_into_variable_tokens =
+            generate_extract_field_bits_from_source_into_variable_tokens(
+                bitfield,
+                field,
+                BitsSource::Bitfield,
+                /* cast_bits= */ false,
+                /* invert_bits= */ false,
+                /* builder_caller= */ false,
+            );
+        let documentation = format!(
+            "Returns `{}`. Returns an error if a digit of the bits isn't decimal.",
+            field.name()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_getter_ident_tokens(&self) -> ::core::result::Result<#field_data_type_tokens, &'static str> {
+                let this = self;
+                #extract_field_bits_from_source_into_variable_tokens
+                let digits = value as u128;
+                let mut digit: u32 = 0;
+                while digit < #field_bits_tokens / 4 {
+                    if (digits >> (digit * 4)) & 0xF > 9 {
+                        return Err("The field bits contain a non-decimal digit.");
+                    }
+                    digit += 1;
+                }
+                Ok(self.#getter_ident_tokens())
+            }
+        }
+    }
+
+    /// Generates the raw getter function of a field with enumerated values,
+    /// which returns the bits including those without a variant.
+    fn generate_enumerated_values_raw_getter_tokens(
+        bitfield: &Bitfield,
+        field: &Field,
+    ) -> TokenStream {
+        let visibility_tokens = field.getter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let raw_getter_ident_tokens = field.raw_getter_ident_tokens();
+        let raw_type_tokens = get_enumerated_values_raw_type_tokens(field);
+        let extract_field_bits_from_source_into_variable_tokens =
+            generate_extract_field_bits_from_source_into_variable_tokens(
+                bitfield,
+                field,
+                BitsSource::Bitfield,
+                /* cast_bits= */ false,
+                /* invert_bits= */ false,
+                /* builder_caller= */ false,
+            );
+        let documentation = format!(
+            "Returns the raw bits of `{}`, including bits without a variant of `{}`.",
+            field.name(),
+            field.spanned_data_type_token()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #raw_getter_ident_tokens(&self) -> #raw_type_tokens {
+                let this = self;
+                #extract_field_bits_from_source_into_variable_tokens
+                #[allow(clippy::unnecessary_cast)]
+                let value = value as #raw_type_tokens;
+                value
+            }
+        }
+    }
+
+    /// Generates the element getter and checked element getter functions of a
+    /// packed array field.
+    fn generate_element_getters_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.getter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let element_getter_ident_tokens = field.element_getter_ident_tokens();
+        let checked_element_getter_ident_tokens = field.checked_element_getter_ident_tokens();
+        let element_data_type_tokens = get_array_element_data_type_tokens(field);
+        let len = field.spanned_data_type_token().array_length().expect("Expected array length");
+        let field_offset_tokens =
+            get_field_offset_tokens(bitfield, field, /* builder_caller= */ false);
+        let element_field = field.element_field(&field_offset_tokens, &quote! { index });
+        let extract_element_bits_from_source_into_variable_tokens =
+            generate_extract_field_bits_from_source_into_variable_tokens(
+                bitfield,
+                &element_field,
+                BitsSource::Bitfield,
+                /* cast_bits= */ false,
+                /* invert_bits= */ false,
+                /* builder_caller= */ false,
+            );
+        let element_from_raw_tokens = generate_element_from_raw_tokens(field, &quote! { value });
+        let documentation = format!(
+            "Returns the element of `{}` at `index`. Panics if the index is outside the array \
+             range.",
+            field.name()
+        );
+        let checked_documentation = format!(
+            "Returns the element of `{}` at `index`. Returns an error if the index is outside the \
+             array range.",
+            field.name()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #element_getter_ident_tokens(&self, index: usize) -> #element_data_type_tokens {
+                if index >= #len {
+                    ::core::panic!("The index is outside the array range.");
+                }
+                let this = self;
+                #extract_element_bits_from_source_into_variable_tokens
+                #element_from_raw_tokens
+            }
+
+            #[doc = #checked_documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_element_getter_ident_tokens(&self, index: usize) -> ::core::result::Result<#element_data_type_tokens, &'static str> {
+                if index >= #len {
+                    return Err("The index is outside the array range.");
+                }
+                Ok(self.#element_getter_ident_tokens(index))
+            }
+        }
+    }
+
     /// Generates tokens for `this` return value.
     fn generate_value_return_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
         match field.spanned_data_type_token().data_type() {
@@ -84,17 +271,25 @@ impl FieldGettersFeature {
                     value as _
                 }
             },
+            DataType::Custom if is_byte_ascii_str_field(bitfield, field) => {
+                generate_ascii_str_from_bytes_tokens(field, quote! { value })
+            },
             DataType::Custom => {
-                let custom_field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+                let custom_field_from_raw_tokens =
+                    generate_custom_field_from_raw_tokens(field, quote! { value });
                 quote! {
-                    #custom_field_data_type_tokens::from_bits(value as _)
+                    {
+                        #[allow(clippy::unnecessary_cast)]
+                        let value = value as u128;
+                        #custom_field_from_raw_tokens
+                    }
                 }
             },
             DataType::Array {
-                length,
+                ..
             } => {
-                let len = length as usize;
-                if bitfield.is_integer_backed() {
+                if bitfield.is_integer_backed() && !field.is_packed_array() {
+                    let len = get_array_length_tokens(field);
                     quote! {
                         {
                             let __val = value as u128;
@@ -116,10 +311,48 @@ impl FieldGettersFeature {
 
     /// Returns field getter documentation.
     fn get_getter_documentation(bitfield: &Bitfield, field: &Field) -> String {
-        let offset = field.offset();
+        let mut documentation = Self::get_getter_bits_documentation(bitfield, field);
+        if let Some(byte_order_documentation) = get_byte_order_documentation(field) {
+            documentation = format!("{documentation} {byte_order_documentation}");
+        }
+        if field.is_non_zero() {
+            documentation = format!("{documentation} Panics if the bits are zero.");
+        }
+        if let Some(enumerated_values) = field.enumerated_values() {
+            documentation = format!(
+                "{documentation} Returns `{}` if the bits have no variant, which are returned by \
+                 `{}_raw`.",
+                enumerated_values[0].name_ident(),
+                field.name()
+            );
+        }
+        if let Some(none_value) = field.none_value() {
+            documentation =
+                format!("{documentation} Returns `None` if the bits are `{none_value:#X}`.");
+        }
+        match field.encoding() {
+            Some(encoding) => {
+                format!("{documentation} The value is decoded from the `{encoding}` encoding.")
+            },
+            None => documentation,
+        }
+    }
+
+    /// Returns the getter documentation of the bits of a field.
+    fn get_getter_bits_documentation(bitfield: &Bitfield, field: &Field) -> String {
+        if let Some(segments_documentation) = get_split_field_segments_documentation(field) {
+            return if field.spanned_data_type_token().data_type().unsigned() {
+                format!("Returns {segments_documentation}.")
+            } else {
+                format!("Returns sign-extended {segments_documentation}.")
+            };
+        }
+        let (Some(offset), Some(bits)) = (field.offset().known(), field.bits().known()) else {
+            return format!("Returns `{}`.", field.name());
+        };
         let (unit, units) = get_field_unit_terms(field);
 
-        if field.bits() == 1 {
+        if bits == 1 {
             return if field.spanned_data_type_token().data_type().unsigned() {
                 format!("Returns {unit} `{offset}`.")
             } else {
@@ -127,7 +360,7 @@ impl FieldGettersFeature {
             };
         }
 
-        let bits_end = offset + field.bits() - 1;
+        let bits_end = offset + bits - 1;
 
         let (documentation_bits_start, documentation_bits_end) =
             if bitfield.arguments().order() == BitOrder::Msb {
@@ -3,10 +3,14 @@ use quote::quote;
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
 use crate::generating::bitfield::features::common::generator_helper::{
-    generate_setting_field_from_variable_tokens, get_function_modifier_tokens,
-    get_setter_documentation,
+    generate_element_into_raw_tokens, generate_element_overflow_condition_tokens,
+    generate_saturating_element_value_tokens, generate_saturating_field_value_tokens,
+    generate_setting_element_without_setter_tokens, generate_setting_field_from_variable_tokens,
+    get_array_element_data_type_tokens, get_field_function_modifier_tokens,
+    get_field_offset_tokens, get_field_overflow_policy, get_setter_documentation,
 };
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::common::overflow_policy::OverflowPolicy;
 use crate::parsing::common::to_tokens::ToTokens;
 
 /// Generates setters for fields.
@@ -49,30 +53,29 @@ impl FieldSettersFeature {
 
     /// Generates the setter and checked setter functions.
     fn generate_field_setters_functions(bitfield: &Bitfield, field: &Field) -> TokenStream {
-        let visibility_tokens = field.visibility().to_tokens();
+        let visibility_tokens = field.setter_visibility().to_tokens();
         let setter_documentation = get_setter_documentation(
             bitfield, field, /* checked_setter= */ false, /* builder_caller= */ false,
         );
         let checked_setter_documentation = get_setter_documentation(
             bitfield, field, /* checked_setter= */ true, /* builder_caller= */ false,
         );
-        let function_modifier_tokens = get_function_modifier_tokens(bitfield);
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
         let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
         let field_setter_ident_tokens = field.setter_ident_tokens();
         let checked_field_setter_ident_tokens = field.checked_setter_ident_tokens();
-        let set_bits_logic_tokens = generate_setting_field_from_variable_tokens(
-            bitfield, field, /* use_setter= */ false, /* cast_bits= */ true,
-            /* check_bit_size= */ false, /* builder_caller= */ false,
-        );
+        let set_bits_logic_tokens = Self::generate_setter_logic_tokens(bitfield, field);
         let checked_set_bits_logic_tokens = generate_setting_field_from_variable_tokens(
             bitfield, field, /* use_setter= */ false, /* cast_bits= */ true,
             /* check_bit_size= */ true, /* builder_caller= */ false,
         );
+        let element_setters_tokens = field
+            .is_packed_array()
+            .then(|| Self::generate_element_setters_functions(bitfield, field));
 
         quote! {
             #[doc = #setter_documentation]
             #visibility_tokens #function_modifier_tokens fn #field_setter_ident_tokens(&mut self, bits: #field_data_type_tokens) {
-                let this = self;
                 #set_bits_logic_tokens
             }
 
@@ -82,6 +85,151 @@ impl FieldSettersFeature {
                 #checked_set_bits_logic_tokens
                 Ok(())
             }
+
+            #element_setters_tokens
+        }
+    }
+
+    /// Generates the logic of the setter, which handles values that don't fit
+    /// in the field bits with the overflow policy of the field.
+    ///
+    /// Panicking setters go through the checked setter, so besides values too
+    /// big for the field bits, they panic on values that don't satisfy the
+    /// `range` or `values` constraint of the field and on `Some` values with
+    /// the bits of its `none` value.
+    fn generate_setter_logic_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let checked_field_setter_ident_tokens = field.checked_setter_ident_tokens();
+        let truncating_set_bits_logic_tokens = generate_setting_field_from_variable_tokens(
+            bitfield, field, /* use_setter= */ false, /* cast_bits= */ true,
+            /* check_bit_size= */ false, /* builder_caller= */ false,
+        );
+        let panicking_set_bits_logic_tokens = quote! {
+            if let ::core::result::Result::Err(err) = self.#checked_field_setter_ident_tokens(bits) {
+                ::core::panic!("{}", err);
+            }
+        };
+
+        match get_field_overflow_policy(bitfield, field) {
+            OverflowPolicy::Truncate => quote! {
+                let this = self;
+                #truncating_set_bits_logic_tokens
+            },
+            OverflowPolicy::Saturate => {
+                let saturating_value_tokens = generate_saturating_field_value_tokens(field);
+                quote! {
+                    #saturating_value_tokens
+                    let this = self;
+                    #truncating_set_bits_logic_tokens
+                }
+            },
+            OverflowPolicy::Panic => panicking_set_bits_logic_tokens,
+            OverflowPolicy::DebugAssert => quote! {
+                if cfg!(debug_assertions) {
+                    #panicking_set_bits_logic_tokens
+                    return;
+                }
+                let this = self;
+                #truncating_set_bits_logic_tokens
+            },
+        }
+    }
+
+    /// Generates the handling of element values that don't fit in the element
+    /// bits with the overflow policy of the field.
+    fn generate_element_overflow_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let element_overflow_condition_tokens =
+            generate_element_overflow_condition_tokens(field, &quote! { bits });
+        let panic_tokens = quote! {
+            ::core::panic!("Value is too big to fit within the field bits.");
+        };
+
+        match get_field_overflow_policy(bitfield, field) {
+            OverflowPolicy::Truncate => quote! {},
+            OverflowPolicy::Saturate => {
+                generate_saturating_element_value_tokens(field).unwrap_or_default()
+            },
+            OverflowPolicy::Panic => quote! {
+                if #element_overflow_condition_tokens {
+                    #panic_tokens
+                }
+            },
+            OverflowPolicy::DebugAssert => quote! {
+                if cfg!(debug_assertions) && #element_overflow_condition_tokens {
+                    #panic_tokens
+                }
+            },
+        }
+    }
+
+    /// Generates the element setter and checked element setter functions of a
+    /// packed array field.
+    fn generate_element_setters_functions(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.setter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let element_setter_ident_tokens = field.element_setter_ident_tokens();
+        let checked_element_setter_ident_tokens = field.checked_element_setter_ident_tokens();
+        let element_data_type_tokens = get_array_element_data_type_tokens(field);
+        let element_overflow_condition_tokens =
+            generate_element_overflow_condition_tokens(field, &quote! { bits });
+        let element_into_raw_tokens = generate_element_into_raw_tokens(field, &quote! { bits });
+        let element_overflow_tokens = Self::generate_element_overflow_tokens(bitfield, field);
+        let len = field.spanned_data_type_token().array_length().expect("Expected array length");
+        let field_offset_tokens =
+            get_field_offset_tokens(bitfield, field, /* builder_caller= */ false);
+        let element_field = field.element_field(&field_offset_tokens, &quote! { index });
+        let setting_element_tokens = generate_setting_element_without_setter_tokens(
+            bitfield,
+            &element_field,
+            &element_into_raw_tokens,
+            /* builder_caller= */ false,
+        );
+        let mut documentation = format!(
+            "Sets the element of `{}` at `index`. Panics if the index is outside the array range.",
+            field.name()
+        );
+        let overflow_documentation = match get_field_overflow_policy(bitfield, field) {
+            OverflowPolicy::Truncate => None,
+            OverflowPolicy::Saturate => {
+                Some("Values too big to fit within the element bits are clamped to the bounds.")
+            },
+            OverflowPolicy::Panic => {
+                Some("Panics if the value is too big to fit within the element bits.")
+            },
+            OverflowPolicy::DebugAssert => Some(
+                "Panics in debug builds if the value is too big to fit within the element bits.",
+            ),
+        };
+        if let Some(overflow_documentation) = overflow_documentation {
+            documentation = format!("{documentation} {overflow_documentation}");
+        }
+        let checked_documentation = format!(
+            "Sets the element of `{}` at `index`. Returns an error if the index is outside the \
+             array range or the value is too big to fit within the element bits.",
+            field.name()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #element_setter_ident_tokens(&mut self, index: usize, bits: #element_data_type_tokens) {
+                if index >= #len {
+                    ::core::panic!("The index is outside the array range.");
+                }
+                #element_overflow_tokens
+                let this = self;
+                #setting_element_tokens
+            }
+
+            #[doc = #checked_documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_element_setter_ident_tokens(&mut self, index: usize, bits: #element_data_type_tokens) -> ::core::result::Result<(), &'static str> {
+                if index >= #len {
+                    return Err("The index is outside the array range.");
+                }
+                if #element_overflow_condition_tokens {
+                    return Err("Value is too big to fit within the field bits.");
+                }
+                self.#element_setter_ident_tokens(index, bits);
+                Ok(())
+            }
         }
     }
 }
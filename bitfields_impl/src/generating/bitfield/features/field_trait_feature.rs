@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::generating::bitfield::feature::{Feature, FeaturePosition};
+use crate::generating::bitfield::features::common::generator_helper::{
+    ProtectionType, generate_bitfield_struct_initialization_tokens,
+    generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens,
+};
+use crate::generating::common::endian_conversion::generate_bits_variable_endian_conversion_tokens;
+use crate::parsing::bitfields::bitfield::Bitfield;
+use crate::parsing::common::to_tokens::ToTokens;
+
+/// Generates the `BitfieldField` trait implementation, allowing the bitfield
+/// to be nested in other bitfields.
+///
+/// Example:
+///
+/// - `impl ::bitfields::BitfieldField for Bitfield { ... }`
+pub struct FieldTraitFeature;
+
+impl Feature for FieldTraitFeature {
+    fn generate_feature(&self, bitfield: &Bitfield) -> TokenStream {
+        Self::generate_field_trait_feature_tokens(bitfield)
+    }
+
+    fn enabled(&self, bitfield: &Bitfield) -> bool {
+        bitfield.is_integer_backed()
+    }
+
+    fn feature_position(&self) -> FeaturePosition {
+        FeaturePosition::Below
+    }
+
+    fn order_priority(&self) -> u32 {
+        3
+    }
+}
+
+impl FieldTraitFeature {
+    fn generate_field_trait_feature_tokens(bitfield: &Bitfield) -> TokenStream {
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
+        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+        let bitfield_struct_initialization_tokens = generate_bitfield_struct_initialization_tokens(
+            bitfield, /* builder_caller= */ false,
+        );
+        let extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens =
+            generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens(
+                bitfield,
+                ProtectionType::None,
+            );
+        let from_bits_endian_conversion_tokens =
+            generate_bits_variable_endian_conversion_tokens(bitfield.arguments().from_endian());
+        let into_bits_endian_conversion_tokens =
+            generate_bits_variable_endian_conversion_tokens(bitfield.arguments().into_endian());
+        let bitfield_internal_value_ident_tokens =
+            bitfield.bitfield_internal_value_ident_tokens(/* builder_caller= */ false);
+
+        quote! {
+            impl #impl_generics_tokens ::bitfields::BitfieldField for #bitfield_type_tokens #where_clause_tokens {
+                const BITS: u32 = #bitfield_data_type_tokens::BITS;
+                type Raw = #bitfield_data_type_tokens;
+
+                fn from_raw(raw: #bitfield_data_type_tokens) -> Self {
+                    let bits = raw;
+                    #from_bits_endian_conversion_tokens
+                    let mut this = #bitfield_struct_initialization_tokens;
+                    #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
+                    this
+                }
+
+                fn into_raw(self) -> #bitfield_data_type_tokens {
+                    let this = self;
+                    let bits = #bitfield_internal_value_ident_tokens;
+                    #into_bits_endian_conversion_tokens
+                    bits
+                }
+            }
+        }
+    }
+}
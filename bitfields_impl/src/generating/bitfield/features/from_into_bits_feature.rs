@@ -3,16 +3,26 @@ use quote::quote;
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
 use crate::generating::bitfield::features::common::generator_helper::{
-    ProtectionType, generate_backing_data_param_ident,
-    generate_bitfield_struct_initialization_tokens,
+    BitsSource, ProtectionType, generate_backing_data_param_ident,
+    generate_bitfield_struct_initialization_tokens, generate_bytes_into_words_tokens,
+    generate_decoding_field_value_tokens,
     generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens,
-    generate_setting_fields_to_default_value_tokens_list, get_function_modifier_tokens,
+    generate_extract_field_bits_from_source_into_variable_tokens,
+    generate_field_constraint_check_tokens, generate_setting_fields_to_default_value_tokens_list,
+    generate_signed_bits_cast_tokens, generate_signed_bits_param_cast_tokens,
+    generate_words_into_bytes_tokens, get_bytes_length_tokens, get_bytes_reversal_bounds_tokens,
+    get_function_modifier_tokens,
 };
 use crate::generating::common::endian_conversion::generate_bits_variable_endian_conversion_tokens;
 use crate::parsing::bitfields::bitfield::Bitfield;
 use crate::parsing::common::conversion_endian::ConversionEndian;
 use crate::parsing::common::to_tokens::ToTokens;
 
+/// The error of the checked decoding functions when a field doesn't satisfy its
+/// `range` or `values` constraint.
+const CONSTRAINT_DECODING_ERROR_MESSAGE: &str =
+    "A field is outside the valid values of its `range` or `values` constraint.";
+
 /// Generates from/into bits/bytes functions.
 pub struct FromIntoBitsFeature;
 
@@ -47,14 +57,86 @@ impl FromIntoBitsFeature {
 impl FromIntoBitsFeature {
     fn generate_integer_backed_from_into_bits_feature_tokens(bitfield: &Bitfield) -> TokenStream {
         let from_bits_tokens = Self::generate_from_bits_tokens(bitfield);
+        let checked_from_bits_tokens = Self::generate_checked_from_tokens(
+            bitfield,
+            &quote! { checked_from_bits },
+            &quote! { from_bits },
+            "Creates a new bitfield instance from the given bits. Returns an error if a field \
+             doesn't satisfy its `range` or `values` constraint.",
+        );
         let into_bits_tokens = Self::generate_integer_backed_into_bits_tokens(bitfield);
 
         quote! {
             #from_bits_tokens
+            #checked_from_bits_tokens
             #into_bits_tokens
         }
     }
 
+    /// Generates the function decoding the bitfield and checking that every
+    /// field satisfies its `range` or `values` constraint. Only generated when
+    /// a field has a constraint.
+    fn generate_checked_from_tokens(
+        bitfield: &Bitfield,
+        checked_from_ident_tokens: &TokenStream,
+        from_ident_tokens: &TokenStream,
+        documentation: &str,
+    ) -> Option<TokenStream> {
+        let constraint_checks_tokens_list = bitfield
+            .fields()
+            .iter()
+            .filter(|field| field.constraint().is_some())
+            .map(|field| {
+                let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+                let extract_field_bits_into_variable_tokens =
+                    generate_extract_field_bits_from_source_into_variable_tokens(
+                        bitfield,
+                        field,
+                        BitsSource::Bitfield,
+                        /* cast_bits= */ false,
+                        /* invert_bits= */ false,
+                        /* builder_caller= */ false,
+                    );
+                let sign_extend_tokens = generate_decoding_field_value_tokens(bitfield, field);
+                let constraint_check_tokens = generate_field_constraint_check_tokens(
+                    field,
+                    &quote! { (value as #field_data_type_tokens) },
+                    CONSTRAINT_DECODING_ERROR_MESSAGE,
+                );
+
+                quote! {
+                    {
+                        #extract_field_bits_into_variable_tokens
+                        #sign_extend_tokens
+                        #constraint_check_tokens
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if constraint_checks_tokens_list.is_empty() {
+            return None;
+        }
+
+        let visibility_tokens = bitfield.visibility().to_tokens();
+        let function_modifier_tokens = get_function_modifier_tokens(bitfield);
+        // Word array bitfields are decoded from their bytes.
+        let bitfield_data_type_tokens = if bitfield.is_integer_backed() {
+            bitfield.declared_data_type_token().to_tokens()
+        } else {
+            bitfield.spanned_data_type_token().to_tokens()
+        };
+        let source_param = generate_backing_data_param_ident(bitfield);
+
+        Some(quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_from_ident_tokens(#source_param: #bitfield_data_type_tokens) -> ::core::result::Result<Self, &'static str> {
+                let this = Self::#from_ident_tokens(#source_param);
+                #( #constraint_checks_tokens_list )*
+                Ok(this)
+            }
+        })
+    }
+
     fn generate_from_bits_tokens(bitfield: &Bitfield) -> TokenStream {
         let visibility_tokens = bitfield.visibility().to_tokens();
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
@@ -66,7 +148,8 @@ impl FromIntoBitsFeature {
                 bitfield,
                 ProtectionType::None,
             );
-        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+        let bitfield_data_type_tokens = bitfield.declared_data_type_token().to_tokens();
+        let signed_bits_param_cast_tokens = generate_signed_bits_param_cast_tokens(bitfield);
         let setting_fields_to_default_value_tokens_list =
             generate_setting_fields_to_default_value_tokens_list(bitfield);
         let bits_variable_endian_conversion_tokens =
@@ -76,6 +159,7 @@ impl FromIntoBitsFeature {
         quote! {
             #[doc = "Creates a new bitfield instance from the given bits."]
             #visibility_tokens #function_modifier_tokens fn from_bits(#source_param: #bitfield_data_type_tokens) -> Self {
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #bits_variable_endian_conversion_tokens
                 let mut this = #bitfield_struct_initialization_tokens;
@@ -86,6 +170,7 @@ impl FromIntoBitsFeature {
             #[doc = "Creates a new bitfield instance from the given bits while \
                      respecting defaults."]
             #visibility_tokens #function_modifier_tokens fn from_bits_with_defaults(#source_param: #bitfield_data_type_tokens) -> Self {
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #bits_variable_endian_conversion_tokens
                 let mut this = #bitfield_struct_initialization_tokens;
@@ -96,6 +181,7 @@ impl FromIntoBitsFeature {
 
             #[doc = "Creates a new bitfield instance from the given little-endian bits."]
             #visibility_tokens #function_modifier_tokens fn from_le_bits(#source_param: #bitfield_data_type_tokens) -> Self {
+                #signed_bits_param_cast_tokens
                 let bits = #source_param.swap_bytes();
                 let mut this = #bitfield_struct_initialization_tokens;
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -105,6 +191,7 @@ impl FromIntoBitsFeature {
             #[doc = "Creates a new bitfield instance from the given little-endian \
                      bits while respecting defaults."]
             #visibility_tokens #function_modifier_tokens fn from_le_bits_with_defaults(#source_param: #bitfield_data_type_tokens) -> Self {
+                #signed_bits_param_cast_tokens
                 let bits = #source_param.swap_bytes();
                 let mut this = #bitfield_struct_initialization_tokens;
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -114,6 +201,7 @@ impl FromIntoBitsFeature {
 
             #[doc = "Creates a new bitfield instance from the given big-endian bits."]
             #visibility_tokens #function_modifier_tokens fn from_be_bits(#source_param: #bitfield_data_type_tokens) -> Self {
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 let mut this = #bitfield_struct_initialization_tokens;
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -123,6 +211,7 @@ impl FromIntoBitsFeature {
             #[doc = "Creates a new bitfield instance from the given big-endian bits \
                      while respecting defaults."]
             #visibility_tokens #function_modifier_tokens fn from_be_bits_with_defaults(#source_param: #bitfield_data_type_tokens) -> Self {
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 let mut this = #bitfield_struct_initialization_tokens;
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -135,7 +224,8 @@ impl FromIntoBitsFeature {
     fn generate_integer_backed_into_bits_tokens(bitfield: &Bitfield) -> TokenStream {
         let visibility_tokens = bitfield.visibility().to_tokens();
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
-        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+        let bitfield_data_type_tokens = bitfield.declared_data_type_token().to_tokens();
+        let bits_return_tokens = generate_signed_bits_cast_tokens(bitfield, &quote! { bits });
         let bits_variable_endian_conversion_tokens =
             generate_bits_variable_endian_conversion_tokens(bitfield.arguments().into_endian());
         let bitfield_internal_value_ident_tokens =
@@ -151,7 +241,7 @@ impl FromIntoBitsFeature {
                 let this = self;
                 let bits = #bitfield_internal_value_ident_tokens;
                 #bits_variable_endian_conversion_tokens
-                bits
+                #bits_return_tokens
             }
 
             #[doc = "Returns the bits of the bitfield in little-endian order."]
@@ -159,7 +249,7 @@ impl FromIntoBitsFeature {
                 let this = self;
                 let bits = #bitfield_internal_value_ident_tokens;
                 #into_bits_little_endian_conversion_tokens
-                bits
+                #bits_return_tokens
             }
 
             #[doc = "Returns the bits of the bitfield in big-endian order."]
@@ -167,7 +257,7 @@ impl FromIntoBitsFeature {
                 let this = self;
                 let bits = #bitfield_internal_value_ident_tokens;
                 #into_bits_big_endian_conversion_tokens
-                bits
+                #bits_return_tokens
             }
         }
     }
@@ -176,15 +266,109 @@ impl FromIntoBitsFeature {
 impl FromIntoBitsFeature {
     fn generate_array_backed_from_into_bits_feature_tokens(bitfield: &Bitfield) -> TokenStream {
         let from_bits_tokens = Self::generate_array_backed_from_bits_tokens(bitfield);
+        let checked_from_bytes_tokens = Self::generate_checked_from_tokens(
+            bitfield,
+            &quote! { checked_from_bytes },
+            &quote! { from_bytes },
+            "Creates a new bitfield instance from the given bytes. Returns an error if a field \
+             doesn't satisfy its `range` or `values` constraint.",
+        );
         let from_slice_tokens = Self::generate_array_backed_from_slice_tokens(bitfield);
         let into_bits_tokens = Self::generate_array_backed_into_bits_tokens(bitfield);
         let into_slice_tokens = Self::generate_array_backed_into_slice_tokens(bitfield);
+        let from_into_words_tokens = bitfield
+            .word_type()
+            .is_some()
+            .then(|| Self::generate_array_backed_from_into_words_tokens(bitfield));
 
         quote! {
             #from_bits_tokens
+            #checked_from_bytes_tokens
             #from_slice_tokens
             #into_bits_tokens
             #into_slice_tokens
+            #from_into_words_tokens
+        }
+    }
+
+    /// Generates the from/into words functions of word array bitfields, which
+    /// convert the words from and into bytes in the same endian.
+    fn generate_array_backed_from_into_words_tokens(bitfield: &Bitfield) -> TokenStream {
+        let visibility_tokens = bitfield.visibility().to_tokens();
+        let function_modifier_tokens = get_function_modifier_tokens(bitfield);
+        let words_data_type_tokens = bitfield.declared_data_type_token().to_tokens();
+        let words_into_bytes_tokens = |endian| generate_words_into_bytes_tokens(bitfield, endian);
+        let bytes_into_words_tokens = |endian| generate_bytes_into_words_tokens(bitfield, endian);
+
+        let default_words_into_bytes_tokens =
+            words_into_bytes_tokens(bitfield.arguments().from_endian());
+        let le_words_into_bytes_tokens = words_into_bytes_tokens(ConversionEndian::Little);
+        let be_words_into_bytes_tokens = words_into_bytes_tokens(ConversionEndian::Big);
+        let default_bytes_into_words_tokens =
+            bytes_into_words_tokens(bitfield.arguments().into_endian());
+        let le_bytes_into_words_tokens = bytes_into_words_tokens(ConversionEndian::Little);
+        let be_bytes_into_words_tokens = bytes_into_words_tokens(ConversionEndian::Big);
+
+        quote! {
+            #[doc = "Creates a new bitfield instance from the given words."]
+            #visibility_tokens #function_modifier_tokens fn from_words(words: #words_data_type_tokens) -> Self {
+                #default_words_into_bytes_tokens
+                Self::from_bytes(bytes)
+            }
+
+            #[doc = "Creates a new bitfield instance from the given words while \
+                     respecting defaults."]
+            #visibility_tokens #function_modifier_tokens fn from_words_with_defaults(words: #words_data_type_tokens) -> Self {
+                #default_words_into_bytes_tokens
+                Self::from_bytes_with_defaults(bytes)
+            }
+
+            #[doc = "Creates a new bitfield instance from the given little-endian words."]
+            #visibility_tokens #function_modifier_tokens fn from_le_words(words: #words_data_type_tokens) -> Self {
+                #le_words_into_bytes_tokens
+                Self::from_le_bytes(bytes)
+            }
+
+            #[doc = "Creates a new bitfield instance from the given little-endian \
+                     words while respecting defaults."]
+            #visibility_tokens #function_modifier_tokens fn from_le_words_with_defaults(words: #words_data_type_tokens) -> Self {
+                #le_words_into_bytes_tokens
+                Self::from_le_bytes_with_defaults(bytes)
+            }
+
+            #[doc = "Creates a new bitfield instance from the given big-endian words."]
+            #visibility_tokens #function_modifier_tokens fn from_be_words(words: #words_data_type_tokens) -> Self {
+                #be_words_into_bytes_tokens
+                Self::from_be_bytes(bytes)
+            }
+
+            #[doc = "Creates a new bitfield instance from the given big-endian words \
+                     while respecting defaults."]
+            #visibility_tokens #function_modifier_tokens fn from_be_words_with_defaults(words: #words_data_type_tokens) -> Self {
+                #be_words_into_bytes_tokens
+                Self::from_be_bytes_with_defaults(bytes)
+            }
+
+            #[doc = "Returns the words of the bitfield."]
+            #visibility_tokens #function_modifier_tokens fn into_words(&self) -> #words_data_type_tokens {
+                let bytes = self.into_bytes();
+                #default_bytes_into_words_tokens
+                words
+            }
+
+            #[doc = "Returns the words of the bitfield in little-endian order."]
+            #visibility_tokens #function_modifier_tokens fn into_le_words(&self) -> #words_data_type_tokens {
+                let bytes = self.into_le_bytes();
+                #le_bytes_into_words_tokens
+                words
+            }
+
+            #[doc = "Returns the words of the bitfield in big-endian order."]
+            #visibility_tokens #function_modifier_tokens fn into_be_words(&self) -> #words_data_type_tokens {
+                let bytes = self.into_be_bytes();
+                #be_bytes_into_words_tokens
+                words
+            }
         }
     }
 
@@ -203,12 +387,7 @@ impl FromIntoBitsFeature {
         let setting_fields_to_default_value_tokens_list =
             generate_setting_fields_to_default_value_tokens_list(bitfield);
         let source_param = generate_backing_data_param_ident(bitfield);
-        let array_len = bitfield
-            .spanned_data_type_token()
-            .array_length()
-            .expect("array-backed bitfield must have a known length");
-        let half_len = array_len / 2;
-        let last_idx = array_len - 1;
+        let (half_len, last_idx) = get_bytes_reversal_bounds_tokens(bitfield);
 
         let from_bits_convert_tokens = |endian| match endian {
             ConversionEndian::Little => quote! {
@@ -304,12 +483,8 @@ impl FromIntoBitsFeature {
             );
         let setting_fields_to_default_value_tokens_list =
             generate_setting_fields_to_default_value_tokens_list(bitfield);
-        let array_len = bitfield
-            .spanned_data_type_token()
-            .array_length()
-            .expect("array-backed bitfield must have a known length");
-        let half_len = array_len / 2;
-        let last_idx = array_len - 1;
+        let array_len = get_bytes_length_tokens(bitfield);
+        let (half_len, last_idx) = get_bytes_reversal_bounds_tokens(bitfield);
 
         let copy_from_slice_tokens = quote! {
             let mut bits: #bitfield_data_type_tokens = [0; #array_len];
@@ -510,12 +685,7 @@ impl FromIntoBitsFeature {
         let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
         let bitfield_internal_value_ident_tokens =
             bitfield.bitfield_internal_value_ident_tokens(/* builder_caller= */ false);
-        let array_len = bitfield
-            .spanned_data_type_token()
-            .array_length()
-            .expect("array-backed bitfield must have a known length");
-        let half_len = array_len / 2;
-        let last_idx = array_len - 1;
+        let (half_len, last_idx) = get_bytes_reversal_bounds_tokens(bitfield);
 
         let is_heap = bitfield.arguments().array_heap();
 
@@ -594,12 +764,8 @@ impl FromIntoBitsFeature {
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
         let bitfield_internal_value_ident_tokens =
             bitfield.bitfield_internal_value_ident_tokens(/* builder_caller= */ false);
-        let array_len = bitfield
-            .spanned_data_type_token()
-            .array_length()
-            .expect("array-backed bitfield must have a known length");
-        let half_len = array_len / 2;
-        let last_idx = array_len - 1;
+        let array_len = get_bytes_length_tokens(bitfield);
+        let (half_len, last_idx) = get_bytes_reversal_bounds_tokens(bitfield);
 
         let bits_access_tokens = if bitfield.arguments().array_heap() {
             quote! { *#bitfield_internal_value_ident_tokens }
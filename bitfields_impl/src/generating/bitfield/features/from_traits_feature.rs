@@ -4,8 +4,10 @@ use quote::quote;
 use crate::generating::bitfield::feature::{Feature, FeaturePosition};
 use crate::generating::bitfield::features::common::generator_helper::{
     ProtectionType, generate_backing_data_param_ident,
-    generate_bitfield_struct_initialization_tokens,
+    generate_bitfield_struct_initialization_tokens, generate_bytes_into_words_tokens,
     generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens,
+    generate_signed_bits_cast_tokens, generate_signed_bits_param_cast_tokens,
+    generate_words_into_bytes_tokens,
 };
 use crate::parsing::bitfields::bitfield::Bitfield;
 use crate::parsing::common::conversion_endian::ConversionEndian;
@@ -42,10 +44,13 @@ impl FromTraitsFeature {
     fn generate_from_traits_feature_tokens(bitfield: &Bitfield) -> TokenStream {
         let from_data_type_trait_tokens = Self::generate_from_data_type_trait_tokens(bitfield);
         let from_bitfield_trait_tokens = Self::generate_from_bitfield_trait_tokens(bitfield);
+        let words_traits_tokens =
+            bitfield.word_type().is_some().then(|| Self::generate_words_traits_tokens(bitfield));
 
         quote! {
             #from_data_type_trait_tokens
             #from_bitfield_trait_tokens
+            #words_traits_tokens
         }
     }
 
@@ -60,17 +65,21 @@ impl FromTraitsFeature {
                 ProtectionType::None,
             );
 
-        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
-        let bitfield_name_tokens = bitfield.name_tokens();
+        let bitfield_data_type_tokens = Self::get_bitfield_data_type_tokens(bitfield);
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
         let bits_return_endian_conversion_tokens =
             Self::generate_bits_return_endian_conversion_tokens(
                 bitfield, /* into_bits= */ false,
             );
+        let signed_bits_param_cast_tokens = generate_signed_bits_param_cast_tokens(bitfield);
         let source_param = generate_backing_data_param_ident(bitfield);
 
         quote! {
-            impl core::convert::From<#bitfield_data_type_tokens> for #bitfield_name_tokens {
+            impl #impl_generics_tokens core::convert::From<#bitfield_data_type_tokens> for #bitfield_type_tokens #where_clause_tokens {
                 fn from(#source_param: #bitfield_data_type_tokens) -> Self {
+                    #signed_bits_param_cast_tokens
                     let mut this = #initialize_struct_initialization_tokens;
                     let bits = #bits_return_endian_conversion_tokens;
                     #generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -81,16 +90,20 @@ impl FromTraitsFeature {
     }
 
     fn generate_from_bitfield_trait_tokens(bitfield: &Bitfield) -> TokenStream {
-        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
-        let bitfield_name_tokens = bitfield.name_tokens();
-        let bits_return_endian_conversion_tokens =
-            Self::generate_bits_return_endian_conversion_tokens(
+        let bitfield_data_type_tokens = Self::get_bitfield_data_type_tokens(bitfield);
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
+        let bits_return_endian_conversion_tokens = generate_signed_bits_cast_tokens(
+            bitfield,
+            &Self::generate_bits_return_endian_conversion_tokens(
                 bitfield, /* into_bits= */ true,
-            );
+            ),
+        );
 
         quote! {
-            impl core::convert::From<#bitfield_name_tokens> for #bitfield_data_type_tokens {
-                fn from(val: #bitfield_name_tokens) -> Self {
+            impl #impl_generics_tokens core::convert::From<#bitfield_type_tokens> for #bitfield_data_type_tokens #where_clause_tokens {
+                fn from(val: #bitfield_type_tokens) -> Self {
                     let mut this = val;
                     #bits_return_endian_conversion_tokens
                 }
@@ -98,6 +111,49 @@ impl FromTraitsFeature {
         }
     }
 
+    /// Generates the from traits between the bitfield and its words for word
+    /// array bitfields, which convert the words from and into the bytes in the
+    /// same endian.
+    fn generate_words_traits_tokens(bitfield: &Bitfield) -> TokenStream {
+        let words_data_type_tokens = bitfield.declared_data_type_token().to_tokens();
+        let bytes_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+        let bitfield_type_tokens = bitfield.type_tokens();
+        let impl_generics_tokens = bitfield.generic_parameters().impl_generics_tokens();
+        let where_clause_tokens = bitfield.generic_parameters().where_clause_tokens(&[]);
+        let words_into_bytes_tokens =
+            generate_words_into_bytes_tokens(bitfield, bitfield.arguments().from_endian());
+        let bytes_into_words_tokens =
+            generate_bytes_into_words_tokens(bitfield, bitfield.arguments().into_endian());
+
+        quote! {
+            impl #impl_generics_tokens core::convert::From<#words_data_type_tokens> for #bitfield_type_tokens #where_clause_tokens {
+                fn from(words: #words_data_type_tokens) -> Self {
+                    #words_into_bytes_tokens
+                    Self::from(bytes)
+                }
+            }
+
+            impl #impl_generics_tokens core::convert::From<#bitfield_type_tokens> for #words_data_type_tokens #where_clause_tokens {
+                fn from(val: #bitfield_type_tokens) -> Self {
+                    let bytes = <#bytes_data_type_tokens>::from(val);
+                    #bytes_into_words_tokens
+                    words
+                }
+            }
+        }
+    }
+
+    /// Returns the type the bitfield is converted from and into, which is the
+    /// declared type of integer-backed bitfields and the bytes of array-backed
+    /// bitfields.
+    fn get_bitfield_data_type_tokens(bitfield: &Bitfield) -> TokenStream {
+        if bitfield.is_integer_backed() {
+            bitfield.declared_data_type_token().to_tokens()
+        } else {
+            bitfield.spanned_data_type_token().to_tokens()
+        }
+    }
+
     /// Generates bits result conversion tokens depending on the configured
     /// endian.
     fn generate_bits_return_endian_conversion_tokens(
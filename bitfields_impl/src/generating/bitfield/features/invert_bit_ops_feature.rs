@@ -3,11 +3,14 @@ use quote::{ToTokens as QuoteToTokens, format_ident, quote};
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition, is_bit_ops_feature_enabled};
 use crate::generating::bitfield::features::common::generator_helper::{
-    BitsSource, ProtectionType,
+    BitsSource, ProtectionType, generate_ascii_str_from_bytes_tokens,
+    generate_custom_field_from_raw_tokens, generate_decoding_field_value_tokens,
     generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens,
     generate_extract_field_bits_from_source_into_variable_tokens,
-    generate_protected_bits_mask_tokens, get_bits_or_bytes_term, get_field_unit_terms,
-    get_function_modifier_tokens,
+    generate_protected_bits_mask_tokens, generate_setting_field_to_raw_value_tokens,
+    get_array_length_tokens, get_bits_or_bytes_term, get_bytes_length_tokens, get_field_unit_terms,
+    get_function_modifier_tokens, get_split_field_segments_documentation, has_converted_bits,
+    is_byte_ascii_str_field,
 };
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
 use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitOrder;
@@ -79,10 +82,7 @@ impl InvertBitOpsFeature {
                 }
             }
         } else {
-            let array_len = bitfield
-                .spanned_data_type_token()
-                .array_length()
-                .expect("array-backed bitfield must have a known length");
+            let array_len = get_bytes_length_tokens(bitfield);
 
             let bits_init_tokens = if bitfield.arguments().array_heap() {
                 quote! { let mut bits = *#bitfield_internal_value_ident_tokens; }
@@ -108,7 +108,6 @@ impl InvertBitOpsFeature {
 
     /// Returns tokens to invert a field in the bitfield.
     fn generate_invert_fields_tokens(bitfield: &Bitfield) -> TokenStream {
-        let visibility_tokens = bitfield.visibility().to_tokens();
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
 
         bitfield
@@ -116,6 +115,10 @@ impl InvertBitOpsFeature {
             .iter()
             .filter(|field| field.has_setter())
             .map(|field| {
+                let visibility_tokens = field
+                    .explicit_setter_visibility()
+                    .unwrap_or_else(|| bitfield.visibility())
+                    .to_tokens();
                 let field_setter_ident_tokens = field.setter_ident_tokens();
                 let field_invert_ident_tokens =
                     format_ident!("invert_{}", field.name(), span = field.name_ident().span()).to_token_stream();
@@ -130,13 +133,23 @@ impl InvertBitOpsFeature {
                         /* builder_caller= */ false,
                     );
 
-                let value_to_field_tokens = Self::generate_value_to_field_tokens(bitfield, field);
+                // The inverted bits of fields with converted bits and niche-typed
+                // fields are set as is, the setter would convert them.
+                let setting_field_tokens = if has_converted_bits(bitfield, field) || field.is_niche() {
+                    generate_setting_field_to_raw_value_tokens(bitfield, field)
+                } else {
+                    let value_to_field_tokens =
+                        Self::generate_value_to_field_tokens(bitfield, field);
+                    quote! {
+                        this.#field_setter_ident_tokens(#value_to_field_tokens);
+                    }
+                };
                 quote! {
                     #[doc = #documentation]
                     #visibility_tokens #function_modifier_tokens fn #field_invert_ident_tokens(&mut self) {
                         let this = self;
                         #extract_field_bits_from_source_into_variable_tokens
-                        this.#field_setter_ident_tokens(#value_to_field_tokens);
+                        #setting_field_tokens
                     }
                 }
             })
@@ -145,7 +158,6 @@ impl InvertBitOpsFeature {
 
     /// Returns tokens to return a field inverted.
     fn generate_inverted_fields_getter_tokens(bitfield: &Bitfield) -> TokenStream {
-        let visibility_tokens = bitfield.visibility().to_tokens();
         let function_modifier_tokens = get_function_modifier_tokens(bitfield);
 
         bitfield
@@ -153,10 +165,17 @@ impl InvertBitOpsFeature {
             .iter()
             .filter(|field| field.has_getter())
             .map(|field| {
+                let visibility_tokens = field
+                    .explicit_getter_visibility()
+                    .unwrap_or_else(|| bitfield.visibility())
+                    .to_tokens();
                 let field_inverted_ident_tokens =
                     format_ident!("{}_inverted", field.name(), span = field.name_ident().span()).to_token_stream();
                 let documentation = Self::get_invert_field_documentation(bitfield, field);
                 let value_to_field_tokens = Self::generate_value_to_field_tokens(bitfield, field);
+                let decoding_field_value_tokens =
+                    has_converted_bits(bitfield, field)
+                        .then(|| generate_decoding_field_value_tokens(bitfield, field));
                 let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
                 let extract_field_bits_from_source_into_variable_tokens =
                     generate_extract_field_bits_from_source_into_variable_tokens(
@@ -172,6 +191,7 @@ impl InvertBitOpsFeature {
                     #visibility_tokens #function_modifier_tokens fn #field_inverted_ident_tokens(&self) -> #field_data_type_tokens {
                         let this = self;
                         #extract_field_bits_from_source_into_variable_tokens
+                        #decoding_field_value_tokens
                         #value_to_field_tokens
                     }
                 }
@@ -180,21 +200,29 @@ impl InvertBitOpsFeature {
     }
 
     fn generate_value_to_field_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
-        let custom_field_data_type_tokens = field.spanned_data_type_token().to_tokens();
         match field.spanned_data_type_token().data_type() {
+            DataType::Custom if is_byte_ascii_str_field(bitfield, field) => {
+                generate_ascii_str_from_bytes_tokens(field, quote! { value })
+            },
             DataType::Custom => {
+                let custom_field_from_raw_tokens =
+                    generate_custom_field_from_raw_tokens(field, quote! { value });
                 quote! {
-                    #custom_field_data_type_tokens::from_bits(value as _)
+                    {
+                        #[allow(clippy::unnecessary_cast)]
+                        let value = value as u128;
+                        #custom_field_from_raw_tokens
+                    }
                 }
             },
             DataType::Integer(IntegerType::Bool) => {
                 quote! { value != 0 }
             },
             DataType::Array {
-                length,
+                ..
             } => {
-                let len = length as usize;
-                if bitfield.is_integer_backed() {
+                if bitfield.is_integer_backed() && !field.is_packed_array() {
+                    let len = get_array_length_tokens(field);
                     quote! {
                         {
                             let __val = value as u128;
@@ -219,14 +247,19 @@ impl InvertBitOpsFeature {
 
     /// Returns invert field documentation.
     fn get_invert_field_documentation(bitfield: &Bitfield, field: &Field) -> String {
-        let offset = field.offset();
+        if let Some(segments_documentation) = get_split_field_segments_documentation(field) {
+            return format!("Inverts {segments_documentation}.");
+        }
+        let (Some(offset), Some(bits)) = (field.offset().known(), field.bits().known()) else {
+            return format!("Inverts `{}`.", field.name());
+        };
         let (unit, units) = get_field_unit_terms(field);
 
-        if field.bits() == 1 {
+        if bits == 1 {
             return format!("Inverts {unit} `{offset}`.");
         }
 
-        let bits_end = offset + field.bits() - 1;
+        let bits_end = offset + bits - 1;
 
         let (documentation_bits_start, documentation_bits_end) =
             if bitfield.arguments().order() == BitOrder::Msb {
@@ -0,0 +1,304 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::generating::bitfield::feature::{Feature, FeaturePosition};
+use crate::generating::bitfield::features::common::generator_helper::get_array_element_data_type_tokens;
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::bitfields::bitfield_attribute::layout_assertion::{
+    LayoutAssertion, LayoutProperty,
+};
+use crate::parsing::common::bits_expr::BitsExpr;
+use crate::parsing::common::spanned_data_type::DataType;
+use crate::parsing::common::to_tokens::ToTokens;
+
+/// Generates compile time assertions for layout checks that can't be done
+/// while parsing, because the bits of a field are only known by the compiler,
+/// and for the layout assertions declared on the bitfield.
+///
+/// Example:
+///
+/// - `const _: () = assert!(8u32 + <Nested as BitfieldField>::BITS == 16u32,
+///   "...");`
+/// - `const _: () = assert!(Bitfield::MODE_OFFSET == 0, "...");`
+pub struct LayoutAssertionsFeature;
+
+impl Feature for LayoutAssertionsFeature {
+    fn generate_feature(&self, bitfield: &Bitfield) -> TokenStream {
+        Self::generate_layout_assertions_feature_tokens(bitfield)
+    }
+
+    fn enabled(&self, _: &Bitfield) -> bool {
+        true
+    }
+
+    fn feature_position(&self) -> FeaturePosition {
+        FeaturePosition::Below
+    }
+
+    fn order_priority(&self) -> u32 {
+        4
+    }
+}
+
+impl LayoutAssertionsFeature {
+    fn generate_layout_assertions_feature_tokens(bitfield: &Bitfield) -> TokenStream {
+        let total_bits_assertion_tokens = Self::generate_total_bits_assertion_tokens(bitfield);
+        let custom_field_bits_assertion_tokens_list: Vec<TokenStream> = bitfield
+            .fields()
+            .iter()
+            // Module level assertions can't refer to the type parameters.
+            .filter(|field| {
+                !bitfield
+                    .generic_parameters()
+                    .is_type_parameter_dependent(&field.spanned_data_type_token().to_tokens())
+            })
+            .filter_map(Self::generate_custom_field_bits_assertion_tokens)
+            .collect();
+        let integer_field_bits_assertion_tokens_list: Vec<TokenStream> = bitfield
+            .fields()
+            .iter()
+            .filter_map(Self::generate_integer_field_bits_assertion_tokens)
+            .collect();
+        let declared_layout_assertion_tokens_list: Vec<TokenStream> = bitfield
+            .layout_assertions()
+            .iter()
+            .map(|layout_assertion| {
+                Self::generate_declared_layout_assertion_tokens(bitfield, layout_assertion)
+            })
+            .collect();
+
+        quote! {
+            #total_bits_assertion_tokens
+            #( #custom_field_bits_assertion_tokens_list )*
+            #( #integer_field_bits_assertion_tokens_list )*
+            #( #declared_layout_assertion_tokens_list )*
+        }
+    }
+
+    /// Generates the assertion of a layout assertion declared on the bitfield
+    /// (e.g. `assert(a.offset = 0)`), comparing the expected value against the
+    /// field constants, or the offset and bits of fields without constants.
+    fn generate_declared_layout_assertion_tokens(
+        bitfield: &Bitfield,
+        layout_assertion: &LayoutAssertion,
+    ) -> TokenStream {
+        let field = bitfield
+            .fields()
+            .into_iter()
+            .find(|field| field.name() == layout_assertion.field_name().token())
+            .expect("The layout assertion field should be checked while parsing");
+        let bitfield_name_tokens = bitfield.name_tokens();
+        let actual_value_tokens = match (layout_assertion.property(), field.has_constants()) {
+            (LayoutProperty::Offset, true) => {
+                let offset_constant_ident_tokens = field.offset_constant_ident_tokens();
+                quote! { #bitfield_name_tokens::#offset_constant_ident_tokens }
+            },
+            (LayoutProperty::Bits, true) => {
+                let bits_constant_ident_tokens = field.bits_constant_ident_tokens();
+                quote! { #bitfield_name_tokens::#bits_constant_ident_tokens }
+            },
+            (LayoutProperty::Offset, false) => field.offset().to_tokens(),
+            (LayoutProperty::Bits, false) => field.bits().to_tokens(),
+        };
+
+        let expected_value_tokens = layout_assertion.expected_value_tokens();
+        let message = format!(
+            "The {} of the field '{}' doesn't match the asserted {} '{}'.",
+            layout_assertion.property().name(),
+            field.name(),
+            layout_assertion.property().name(),
+            expected_value_tokens.to_string().replace('{', "{{").replace('}', "}}")
+        );
+
+        quote_spanned! { layout_assertion.span() =>
+            const _: () = ::core::assert!(
+                #actual_value_tokens == (#expected_value_tokens),
+                #message
+            );
+        }
+    }
+
+    /// Generates the assertion that the fields add up to the bits of the
+    /// bitfield type. Only generated when the parser couldn't check it.
+    fn generate_total_bits_assertion_tokens(bitfield: &Bitfield) -> Option<TokenStream> {
+        let total_field_bits = bitfield
+            .fields()
+            .iter()
+            .filter(|field| !field.is_alias())
+            .map(Field::occupied_bits)
+            .sum::<BitsExpr>();
+        let bitfield_bits = bitfield.spanned_data_type_token().bits();
+        if total_field_bits.known().is_some() && bitfield_bits.known().is_some() {
+            return None;
+        }
+
+        let total_field_bits_tokens = total_field_bits.to_tokens();
+        let bitfield_bits_tokens = bitfield_bits.to_tokens();
+        let message = match bitfield_bits.known() {
+            Some(bitfield_bit_size) => format!(
+                "The total number of bits of the fields doesn't match the number of bits of the \
+                 bitfield '{} ({bitfield_bit_size} bits)'.",
+                bitfield.declared_data_type_token()
+            ),
+            None => format!(
+                "The total number of bits of the fields doesn't match the number of bits of the \
+                 bitfield '{}'.",
+                bitfield.declared_data_type_token()
+            ),
+        };
+
+        Some(quote_spanned! { bitfield.spanned_data_type_token().span() =>
+            const _: () = ::core::assert!(#total_field_bits_tokens == #bitfield_bits_tokens, #message);
+        })
+    }
+
+    /// Generates the assertions that the defined bits of a custom field can
+    /// hold the bits of its type, and match them for types with a signed raw
+    /// representation, as wider fields wouldn't carry the sign in their top
+    /// bit. `Option` fields only need to hold the bits of their wrapped type,
+    /// as the `none` value may lie outside of them.
+    fn generate_custom_field_bits_assertion_tokens(field: &Field) -> Option<TokenStream> {
+        if !field.has_custom_data_type() {
+            return None;
+        }
+
+        if field.is_packed_array() {
+            return Some(Self::generate_custom_element_bits_assertion_tokens(field));
+        }
+
+        // `Option` fields hold the bits of their wrapped type, and the bits of
+        // float, non-zero and wrapped integer types are checked while parsing.
+        let option_inner_data_type_token = field.option_inner_data_type_token();
+        let bits_data_type_token =
+            option_inner_data_type_token.clone().unwrap_or_else(|| field.spanned_data_type_token());
+        if !matches!(bits_data_type_token.data_type(), DataType::Custom)
+            || bits_data_type_token.float_bits().is_some()
+            || bits_data_type_token.non_zero_bits().is_some()
+        {
+            return None;
+        }
+
+        // Fields without defined bits take the bits of their type, which always
+        // holds for the deferred bits of the type.
+        let field_bits = field.bits();
+        let field_bits_tokens = field_bits.to_tokens();
+        let field_data_type_tokens = bits_data_type_token.to_tokens();
+        let less_message = format!(
+            "The field '{}' has '{} bits', which is less than the bits of its type '{}'.",
+            field.name(),
+            Self::get_bits_message(&field_bits),
+            bits_data_type_token
+        );
+        let signed_assertion_tokens = option_inner_data_type_token.is_none().then(|| {
+            let signed_message = format!(
+                "The field '{}' has '{} bits', which is more than the bits of its type '{}', \
+                 whose raw representation is signed.",
+                field.name(),
+                Self::get_bits_message(&field_bits),
+                bits_data_type_token
+            );
+            Self::generate_signed_bits_assertion_tokens(
+                field,
+                &field_bits_tokens,
+                &field_data_type_tokens,
+                &signed_message,
+            )
+        });
+
+        Some(quote_spanned! { field.spanned_data_type_token().span() =>
+            const _: () = ::core::assert!(
+                #field_bits_tokens >= <#field_data_type_tokens as ::bitfields::BitfieldField>::BITS,
+                #less_message
+            );
+            #signed_assertion_tokens
+        })
+    }
+
+    /// Generates the assertion that bits wider than the bits of a custom type
+    /// are only defined for types with an unsigned raw representation.
+    fn generate_signed_bits_assertion_tokens(
+        field: &Field,
+        bits_tokens: &TokenStream,
+        data_type_tokens: &TokenStream,
+        message: &str,
+    ) -> TokenStream {
+        quote_spanned! { field.spanned_data_type_token().span() =>
+            const _: () = ::core::assert!(
+                #bits_tokens == <#data_type_tokens as ::bitfields::BitfieldField>::BITS
+                    || !<<#data_type_tokens as ::bitfields::BitfieldField>::Raw as ::bitfields::BitfieldRaw>::SIGNED,
+                #message
+            );
+        }
+    }
+
+    /// Generates the assertion that the bits of an integer field defined by a
+    /// const expression fit in its type.
+    fn generate_integer_field_bits_assertion_tokens(field: &Field) -> Option<TokenStream> {
+        let BitsExpr::Deferred(_) = field.bits() else {
+            return None;
+        };
+        let DataType::Integer(integer_type) = field.spanned_data_type_token().data_type() else {
+            return None;
+        };
+
+        let field_bits_tokens = field.bits().to_tokens();
+        let type_bits = integer_type.bit_size();
+        let message = format!(
+            "The field '{}' has '{} bits', which must be greater than 0 and fit in the \
+             '{type_bits} bits' of its type '{}'.",
+            field.name(),
+            Self::get_bits_message(&field.bits()),
+            field.spanned_data_type_token()
+        );
+
+        Some(quote_spanned! { field.spanned_data_type_token().span() =>
+            const _: () = ::core::assert!(
+                #field_bits_tokens > 0 && #field_bits_tokens <= #type_bits,
+                #message
+            );
+        })
+    }
+
+    /// Returns the bits as written in assertion messages, escaping the braces
+    /// of const expressions as the messages are format strings.
+    fn get_bits_message(bits: &BitsExpr) -> String {
+        match bits {
+            BitsExpr::Known(bits) => bits.to_string(),
+            BitsExpr::Deferred(expr) => expr.to_string().replace('{', "{{").replace('}', "}}"),
+        }
+    }
+
+    /// Generates the assertions that the element bits of a packed array of a
+    /// custom type can hold the bits of the element type, and match them for
+    /// element types with a signed raw representation.
+    fn generate_custom_element_bits_assertion_tokens(field: &Field) -> TokenStream {
+        let element_bits = field.element_bits().expect("Expected element bits for packed array");
+        let element_data_type_tokens = get_array_element_data_type_tokens(field);
+        let less_message = format!(
+            "The elements of the field '{}' have '{element_bits} bits', which is less than the \
+             bits of their type '{}'.",
+            field.name(),
+            element_data_type_tokens
+        );
+        let signed_message = format!(
+            "The elements of the field '{}' have '{element_bits} bits', which is more than the \
+             bits of their type '{}', whose raw representation is signed.",
+            field.name(),
+            element_data_type_tokens
+        );
+        let signed_assertion_tokens = Self::generate_signed_bits_assertion_tokens(
+            field,
+            &quote! { #element_bits },
+            &element_data_type_tokens,
+            &signed_message,
+        );
+
+        quote_spanned! { field.spanned_data_type_token().span() =>
+            const _: () = ::core::assert!(
+                #element_bits >= <#element_data_type_tokens as ::bitfields::BitfieldField>::BITS,
+                #less_message
+            );
+            #signed_assertion_tokens
+        }
+    }
+}
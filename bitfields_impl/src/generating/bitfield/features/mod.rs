@@ -5,11 +5,15 @@ pub mod common;
 pub mod debug_trait_feature;
 pub mod default_trait_feature;
 pub mod field_constants_feature;
+pub mod field_enums_feature;
 pub mod field_getters_feature;
 pub mod field_setters_feature;
+pub mod field_trait_feature;
 pub mod from_into_bits_feature;
 pub mod from_traits_feature;
 pub mod invert_bit_ops_feature;
+pub mod layout_assertions_feature;
 pub mod new_functions_feature;
+pub mod scaled_field_accessors_feature;
 pub mod set_get_bit_ops_feature;
 pub mod write_bit_ops_feature;
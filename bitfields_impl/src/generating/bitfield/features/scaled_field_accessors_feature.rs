@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::generating::bitfield::feature::{Feature, FeaturePosition};
+use crate::generating::bitfield::features::common::generator_helper::{
+    generate_raw_to_scaled_value_tokens, generate_scaled_raw_value_overflow_condition_tokens,
+    generate_scaled_value_to_raw_tokens, get_field_function_modifier_tokens,
+    get_scaling_documentation,
+};
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::common::to_tokens::ToTokens;
+
+/// Generates the scaled getters and setters of fixed-point and scaled fields,
+/// which convert from and into the raw accessors.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// bitfield.set_temperature(25.0);
+/// assert_eq!(bitfield.temperature_raw(), 650);
+/// ```
+pub struct ScaledFieldAccessorsFeature;
+
+impl Feature for ScaledFieldAccessorsFeature {
+    fn generate_feature(&self, bitfield: &Bitfield) -> TokenStream {
+        Self::generate_scaled_field_accessors_feature_tokens(bitfield)
+    }
+
+    fn enabled(&self, bitfield: &Bitfield) -> bool {
+        bitfield.fields().iter().any(Field::is_scaled)
+    }
+
+    fn feature_position(&self) -> FeaturePosition {
+        FeaturePosition::Inside
+    }
+
+    fn order_priority(&self) -> u32 {
+        5
+    }
+}
+
+impl ScaledFieldAccessorsFeature {
+    fn generate_scaled_field_accessors_feature_tokens(bitfield: &Bitfield) -> TokenStream {
+        bitfield
+            .fields()
+            .iter()
+            .filter(|field| field.is_scaled())
+            .map(|field| {
+                let getter_tokens = field
+                    .has_getter()
+                    .then(|| Self::generate_scaled_getter_tokens(bitfield, field));
+                let setters_tokens = field
+                    .has_setter()
+                    .then(|| Self::generate_scaled_setters_tokens(bitfield, field));
+
+                quote! {
+                    #getter_tokens
+                    #setters_tokens
+                }
+            })
+            .collect()
+    }
+
+    fn generate_scaled_getter_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.getter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let scaled_getter_ident_tokens = field.scaled_getter_ident_tokens();
+        let getter_ident_tokens = field.getter_ident_tokens();
+        let scaled_value_tokens =
+            generate_raw_to_scaled_value_tokens(field, &quote! { self.#getter_ident_tokens() });
+        let documentation =
+            format!("Returns `{}` {}.", field.name(), get_scaling_documentation(field));
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #scaled_getter_ident_tokens(&self) -> f64 {
+                #scaled_value_tokens
+            }
+        }
+    }
+
+    fn generate_scaled_setters_tokens(bitfield: &Bitfield, field: &Field) -> TokenStream {
+        let visibility_tokens = field.setter_visibility().to_tokens();
+        let function_modifier_tokens = get_field_function_modifier_tokens(bitfield, field);
+        let field_data_type_tokens = field.spanned_data_type_token().to_tokens();
+        let scaled_setter_ident_tokens = field.scaled_setter_ident_tokens();
+        let checked_scaled_setter_ident_tokens = field.checked_scaled_setter_ident_tokens();
+        let setter_ident_tokens = field.setter_ident_tokens();
+        let checked_setter_ident_tokens = field.checked_setter_ident_tokens();
+        let raw_value_tokens = generate_scaled_value_to_raw_tokens(field, &quote! { value });
+        let overflow_condition_tokens = generate_scaled_raw_value_overflow_condition_tokens(
+            bitfield,
+            field,
+            &quote! { raw },
+            /* builder_caller= */ false,
+        );
+        let scaling_documentation = get_scaling_documentation(field);
+        let documentation = format!(
+            "Sets `{}` {scaling_documentation}, rounded to the nearest raw value.",
+            field.name()
+        );
+        let checked_documentation = format!(
+            "Sets `{}` {scaling_documentation}, rounded to the nearest raw value. Returns an \
+             error if the raw value is too big to fit within the field bits.",
+            field.name()
+        );
+
+        quote! {
+            #[doc = #documentation]
+            #visibility_tokens #function_modifier_tokens fn #scaled_setter_ident_tokens(&mut self, value: f64) {
+                let raw = #raw_value_tokens;
+                self.#setter_ident_tokens(raw as #field_data_type_tokens);
+            }
+
+            #[doc = #checked_documentation]
+            #visibility_tokens #function_modifier_tokens fn #checked_scaled_setter_ident_tokens(&mut self, value: f64) -> ::core::result::Result<(), &'static str> {
+                let raw = #raw_value_tokens;
+                if #overflow_condition_tokens {
+                    return Err("Value is too big to fit within the field bits.");
+                }
+                self.#checked_setter_ident_tokens(raw as #field_data_type_tokens)
+            }
+        }
+    }
+}
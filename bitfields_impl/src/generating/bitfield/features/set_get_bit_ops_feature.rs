@@ -3,9 +3,11 @@ use quote::quote;
 
 use crate::generating::bitfield::feature::{Feature, FeaturePosition, is_bit_ops_feature_enabled};
 use crate::generating::bitfield::features::common::generator_helper::{
-    ProtectionType, generate_protected_bits_mask_tokens, get_function_modifier_tokens,
+    ProtectionType, generate_bytes_into_words_tokens, generate_protected_bits_mask_tokens,
+    generate_words_into_bytes_tokens, get_bytes_length_tokens, get_function_modifier_tokens,
 };
-use crate::parsing::bitfields::bitfield::Bitfield;
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::common::conversion_endian::ConversionEndian;
 use crate::parsing::common::to_tokens::ToTokens;
 
 /// Generates set/get bit operations for bitfield.
@@ -48,10 +50,69 @@ impl SetGetBitOpsFeature {
     fn generate_set_get_bit_ops_feature_tokens(bitfield: &Bitfield) -> TokenStream {
         let set_bit_ops_tokens = Self::generate_set_bit_ops_tokens(bitfield);
         let get_bit_ops_tokens = Self::generate_get_bit_ops_tokens(bitfield);
+        let set_get_words_ops_tokens = bitfield
+            .word_type()
+            .is_some()
+            .then(|| Self::generate_set_get_words_ops_tokens(bitfield));
 
         quote! {
             #set_bit_ops_tokens
             #get_bit_ops_tokens
+            #set_get_words_ops_tokens
+        }
+    }
+
+    /// Generates the set/get words range functions of word array bitfields,
+    /// which convert the little-endian words from and into bytes for the
+    /// bytes range functions.
+    fn generate_set_get_words_ops_tokens(bitfield: &Bitfield) -> TokenStream {
+        let visibility_tokens = bitfield.visibility().to_tokens();
+        let function_modifier_tokens = get_function_modifier_tokens(bitfield);
+        let words_data_type_tokens = bitfield.declared_data_type_token().to_tokens();
+        let words_into_bytes_tokens =
+            generate_words_into_bytes_tokens(bitfield, ConversionEndian::Little);
+        let bytes_into_words_tokens =
+            generate_bytes_into_words_tokens(bitfield, ConversionEndian::Little);
+
+        quote! {
+            #[doc = "Sets words in the bitfield starting from the offset \
+                     to the length with the provided value."]
+            #visibility_tokens #function_modifier_tokens fn set_words_range(&mut self, offset: u32, length: u32, value: #words_data_type_tokens) {
+                let words = value;
+                #words_into_bytes_tokens
+                self.set_bytes_range(offset, length, bytes);
+            }
+
+            #[doc = "Sets words in the bitfield starting from the offset \
+                     to the length with the provided value. Returns an \
+                     error if the offset and length is outside the \
+                     bitfield range, or if any bit in the range is \
+                     non-writable."]
+            #visibility_tokens #function_modifier_tokens fn checked_set_words_range(&mut self, offset: u32, length: u32, value: #words_data_type_tokens) -> ::core::result::Result<(), &'static str> {
+                let words = value;
+                #words_into_bytes_tokens
+                self.checked_set_bytes_range(offset, length, bytes)
+            }
+
+            #[doc = "Returns words from the bitfield."]
+            #visibility_tokens #function_modifier_tokens fn get_words_range(&self, offset: u32, length: u32) -> #words_data_type_tokens {
+                let bytes = self.get_bytes_range(offset, length);
+                #bytes_into_words_tokens
+                words
+            }
+
+            #[doc = "Returns words from the bitfield starting from the \
+                     offset to the length. Returns an error if the offset \
+                     and length is outside the bitfield range or if any \
+                     bit in the range is non-readable."]
+            #visibility_tokens #function_modifier_tokens fn checked_get_words_range(&self, offset: u32, length: u32) -> ::core::result::Result<#words_data_type_tokens, &'static str> {
+                let bytes = match self.checked_get_bytes_range(offset, length) {
+                    Ok(bytes) => bytes,
+                    Err(err) => return Err(err),
+                };
+                #bytes_into_words_tokens
+                Ok(words)
+            }
         }
     }
 
@@ -300,7 +361,7 @@ impl SetGetBitOpsFeature {
         );
         let protected_mask =
             generate_protected_bits_mask_tokens(bitfield, ProtectionType::WriteOnly);
-        let array_len = bitfield.spanned_data_type_token().array_length().unwrap_or_default();
+        let array_len = bitfield.spanned_data_type_token().array_length_tokens();
 
         if bitfield.is_integer_backed() {
             quote! {
@@ -445,9 +506,7 @@ impl SetGetBitOpsFeature {
                 #bitfield_data_type_tokens::BITS
             }
         } else {
-            let total_bits =
-                bitfield.spanned_data_type_token().array_length().unwrap_or_default() as u32 * 8;
-            quote! { #total_bits }
+            bitfield.spanned_data_type_token().bits().to_tokens()
         };
         let guard_return_tokens = Self::get_guard_return_tokens(bitfield, guard_return_type);
 
@@ -468,9 +527,7 @@ impl SetGetBitOpsFeature {
                 #bitfield_data_type_tokens::BITS
             }
         } else {
-            let total_bits =
-                bitfield.spanned_data_type_token().array_length().unwrap_or_default() as u32 * 8;
-            quote! { #total_bits }
+            bitfield.spanned_data_type_token().bits().to_tokens()
         };
         let guard_return_tokens = Self::get_guard_return_tokens(bitfield, guard_return_type);
 
@@ -492,13 +549,14 @@ impl SetGetBitOpsFeature {
             .filter(
                 |field| if read_access { !field.has_read_access() } else { !field.has_setter() },
             )
-            .map(|field| {
-                let field_offset = field.offset();
-                let field_end_bits = field_offset + field.bits();
+            .flat_map(Field::bit_ranges)
+            .map(|(offset, bits)| {
+                let field_offset = offset.to_tokens();
+                let field_end_bits = (offset + bits).to_tokens();
                 let guard_return_tokens =
                     Self::get_guard_return_tokens(bitfield, guard_return_type);
 
-                if bitfield.has_ignored_fields() {
+                if get_function_modifier_tokens(bitfield).is_none() {
                     quote! {
                         if (#field_offset..#field_end_bits).contains(&offset) {
                             #guard_return_tokens
@@ -536,8 +594,7 @@ impl SetGetBitOpsFeature {
                         return 0;
                     }
                 } else {
-                    let array_len =
-                        bitfield.spanned_data_type_token().array_length().unwrap_or_default();
+                    let array_len = get_bytes_length_tokens(bitfield);
                     quote! {
                         return [0; #array_len];
                     }
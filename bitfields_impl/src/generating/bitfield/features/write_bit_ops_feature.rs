@@ -5,8 +5,8 @@ use crate::generating::bitfield::feature::{Feature, FeaturePosition, is_bit_ops_
 use crate::generating::bitfield::features::common::generator_helper::{
     ProtectionType, generate_backing_data_param_ident,
     generate_extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens,
-    generate_setting_fields_to_default_value_tokens_list, get_bits_or_bytes_term,
-    get_function_modifier_tokens,
+    generate_setting_fields_to_default_value_tokens_list, generate_signed_bits_param_cast_tokens,
+    get_bits_or_bytes_term, get_bytes_reversal_bounds_tokens, get_function_modifier_tokens,
 };
 use crate::parsing::bitfields::bitfield::Bitfield;
 use crate::parsing::common::conversion_endian::ConversionEndian;
@@ -48,7 +48,12 @@ impl WriteBitOpsFeature {
             );
         let setting_fields_to_default_value_tokens_list =
             generate_setting_fields_to_default_value_tokens_list(bitfield);
-        let bitfield_data_type_tokens = bitfield.spanned_data_type_token().to_tokens();
+        let bitfield_data_type_tokens = if bitfield.is_integer_backed() {
+            bitfield.declared_data_type_token().to_tokens()
+        } else {
+            bitfield.spanned_data_type_token().to_tokens()
+        };
+        let signed_bits_param_cast_tokens = generate_signed_bits_param_cast_tokens(bitfield);
         let bits_variable_endian_conversion_tokens =
             Self::generate_bits_variable_endian_conversion_tokens(
                 bitfield,
@@ -82,6 +87,7 @@ impl WriteBitOpsFeature {
             #[doc = #write_doc]
             #visibility_tokens #function_modifier_tokens fn #write_fn(&mut self, #source_param: #bitfield_data_type_tokens) {
                 let this = self;
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #bits_variable_endian_conversion_tokens
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -90,6 +96,7 @@ impl WriteBitOpsFeature {
             #[doc = #write_with_defaults_doc]
             #visibility_tokens #function_modifier_tokens fn #write_with_defaults_fn(&mut self, #source_param: #bitfield_data_type_tokens) {
                 let this = self;
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #bits_variable_endian_conversion_tokens
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -99,6 +106,7 @@ impl WriteBitOpsFeature {
             #[doc = #write_le_doc]
             #visibility_tokens #function_modifier_tokens fn #write_le_fn(&mut self, #source_param: #bitfield_data_type_tokens) {
                 let this = self;
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #le_endian_conversion_tokens
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -107,6 +115,7 @@ impl WriteBitOpsFeature {
             #[doc = #write_le_with_defaults_doc]
             #visibility_tokens #function_modifier_tokens fn #write_le_with_defaults_fn(&mut self, #source_param: #bitfield_data_type_tokens) {
                 let this = self;
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #le_endian_conversion_tokens
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -116,6 +125,7 @@ impl WriteBitOpsFeature {
             #[doc = #write_be_doc]
             #visibility_tokens #function_modifier_tokens fn #write_be_fn(&mut self, #source_param: #bitfield_data_type_tokens) {
                 let this = self;
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #be_endian_conversion_tokens
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -124,6 +134,7 @@ impl WriteBitOpsFeature {
             #[doc = #write_be_with_defaults_doc]
             #visibility_tokens #function_modifier_tokens fn #write_be_with_defaults_fn(&mut self, #source_param: #bitfield_data_type_tokens) {
                 let this = self;
+                #signed_bits_param_cast_tokens
                 let bits = #source_param;
                 #be_endian_conversion_tokens
                 #extract_all_field_bits_from_variable_into_variable_tokens_and_set_fields_tokens
@@ -151,12 +162,7 @@ impl WriteBitOpsFeature {
                         let bits = bits.swap_bytes();
                     }
                 } else {
-                    let array_len = bitfield
-                        .spanned_data_type_token()
-                        .array_length()
-                        .expect("array-backed bitfield must have a known length");
-                    let half_len = array_len / 2;
-                    let last_idx = array_len - 1;
+                    let (half_len, last_idx) = get_bytes_reversal_bounds_tokens(bitfield);
                     quote! {
                         let mut bits = bits;
                         let mut i = 0;
@@ -14,6 +14,7 @@ pub fn generate_bitflag(bitflag: &Bitflag) -> TokenStream {
     let into_bits_tokens = generate_into_bits_tokens(bitflag);
     let repr_tokens = generate_repr_tokens(bitflag);
     let copy_derive_tokens = generate_copy_derive_tokens(bitflag);
+    let field_trait_tokens = generate_field_trait_tokens(bitflag);
 
     quote! {
         #repr_tokens
@@ -27,6 +28,8 @@ pub fn generate_bitflag(bitflag: &Bitflag) -> TokenStream {
             #from_bits_tokens
             #into_bits_tokens
         }
+
+        #field_trait_tokens
     }
 }
 
@@ -126,3 +129,45 @@ fn generate_into_bits_tokens(bitflag: &Bitflag) -> TokenStream {
         }
     }
 }
+
+/// Generates the `BitfieldField` trait implementation, allowing the bitflag to
+/// be used as a bitfield field. The bits of the bitflag are the minimum number
+/// of bits needed to hold its largest variant.
+fn generate_field_trait_tokens(bitflag: &Bitflag) -> TokenStream {
+    let name_tokens = bitflag.name_tokens();
+    let bitflag_data_type_tokens = bitflag.spanned_data_type_token().to_tokens();
+    let variant_values_tokens_list: Vec<TokenStream> = bitflag
+        .variants()
+        .iter()
+        .map(|variant| {
+            let variant_name_tokens = variant.name_tokens();
+            quote! {
+                Self::#variant_name_tokens as #bitflag_data_type_tokens
+            }
+        })
+        .collect();
+
+    quote! {
+        impl ::bitfields::BitfieldField for #name_tokens {
+            const BITS: u32 = {
+                let mut max: #bitflag_data_type_tokens = 0;
+                #(
+                    if #variant_values_tokens_list > max {
+                        max = #variant_values_tokens_list;
+                    }
+                )*
+                let bits = #bitflag_data_type_tokens::BITS - max.leading_zeros();
+                if bits == 0 { 1 } else { bits }
+            };
+            type Raw = #bitflag_data_type_tokens;
+
+            fn from_raw(raw: #bitflag_data_type_tokens) -> Self {
+                Self::from_bits(raw)
+            }
+
+            fn into_raw(self) -> #bitflag_data_type_tokens {
+                self.into_bits()
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod bitfield;
 pub mod bitflag;
 pub mod common;
+pub mod tagged_union;
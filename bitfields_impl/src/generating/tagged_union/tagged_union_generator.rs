@@ -0,0 +1,266 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::generating::bitfield::bitfield_generator::generate_bitfield;
+use crate::generating::bitfield::features::common::generator_helper::{
+    generate_setting_field_to_default_tokens, get_function_modifier_tokens,
+};
+use crate::parsing::common::to_tokens::ToTokens;
+use crate::parsing::tagged_unions::tagged_union::{TaggedUnion, TaggedUnionVariant};
+
+const UNKNOWN_TAG_ERROR_MESSAGE: &str = "The tag doesn't select a variant.";
+
+/// Generates the bitfield structs of the variants and the enum selecting
+/// between them.
+pub fn generate_tagged_union(tagged_union: &TaggedUnion) -> TokenStream {
+    let variant_bitfields_tokens_list = tagged_union
+        .variants()
+        .iter()
+        .map(|variant| {
+            let variant_bitfield_tokens = generate_bitfield(variant.bitfield());
+            let variant_tag_writer_tokens =
+                generate_variant_tag_writer_tokens(tagged_union, variant);
+            quote! {
+                #variant_bitfield_tokens
+                #variant_tag_writer_tokens
+            }
+        })
+        .collect::<Vec<_>>();
+    let enum_tokens = generate_enum_tokens(tagged_union);
+    let from_bits_tokens = generate_from_bits_tokens(tagged_union);
+    let into_bits_tokens = generate_into_bits_tokens(tagged_union);
+    let tag_tokens = generate_tag_tokens(tagged_union);
+    let from_variant_traits_tokens = generate_from_variant_traits_tokens(tagged_union);
+    let from_traits_tokens = generate_from_traits_tokens(tagged_union);
+    let debug_trait_tokens = generate_debug_trait_tokens(tagged_union);
+    let name_tokens = tagged_union.name_tokens();
+
+    quote! {
+        #( #variant_bitfields_tokens_list )*
+
+        #enum_tokens
+
+        impl #name_tokens {
+            #from_bits_tokens
+            #into_bits_tokens
+            #tag_tokens
+        }
+
+        #from_variant_traits_tokens
+        #from_traits_tokens
+        #debug_trait_tokens
+    }
+}
+
+/// Returns the function modifier shared by the variants, the functions of the
+/// enum can only be `const` if the functions of every variant are.
+fn get_tagged_union_function_modifier_tokens(tagged_union: &TaggedUnion) -> Option<TokenStream> {
+    tagged_union
+        .variants()
+        .iter()
+        .all(|variant| get_function_modifier_tokens(variant.bitfield()).is_some())
+        .then(|| quote! { const })
+}
+
+fn generate_enum_tokens(tagged_union: &TaggedUnion) -> TokenStream {
+    let user_attributes_tokens = tagged_union.user_attributes_tokens();
+    let visibility_tokens = tagged_union.visibility().to_tokens();
+    let name_tokens = tagged_union.name_tokens();
+    let copy_derive_tokens = tagged_union.arguments().derive_copy().then(|| {
+        quote! {
+            #[derive(std::marker::Copy, core::clone::Clone)]
+        }
+    });
+    let variants_tokens_list = tagged_union.variants().iter().map(|variant| {
+        let documentation_attributes_tokens = variant.documentation_attributes_tokens();
+        let variant_name_tokens = variant.name_tokens();
+        let variant_type_tokens = variant.bitfield().type_tokens();
+        quote! {
+            #( #documentation_attributes_tokens )*
+            #variant_name_tokens(#variant_type_tokens)
+        }
+    });
+
+    quote! {
+        #copy_derive_tokens
+        #( #user_attributes_tokens )*
+        #visibility_tokens enum #name_tokens {
+            #( #variants_tokens_list, )*
+        }
+    }
+}
+
+fn generate_from_bits_tokens(tagged_union: &TaggedUnion) -> TokenStream {
+    let visibility_tokens = tagged_union.visibility().to_tokens();
+    let function_modifier_tokens = get_tagged_union_function_modifier_tokens(tagged_union);
+    let tagged_union_data_type_tokens = tagged_union.spanned_data_type_token().to_tokens();
+    // Every variant shares the tag, so any of them can decode it.
+    let tag_variant_type_tokens = tagged_union
+        .variants()
+        .first()
+        .expect("Expected a tagged-union variant")
+        .bitfield()
+        .type_tokens();
+    let cases_tokens_list = tagged_union.variants().iter().map(|variant| {
+        let tag_tokens = variant.tag_tokens();
+        let variant_name_tokens = variant.name_tokens();
+        let variant_type_tokens = variant.bitfield().type_tokens();
+        quote! {
+            #tag_tokens => ::core::result::Result::Ok(Self::#variant_name_tokens(#variant_type_tokens::from_bits(bits)))
+        }
+    });
+
+    quote! {
+        #[doc = "Creates a new tagged union from the given bits, with the variant selected by the tag."]
+        #[doc = ""]
+        #[doc = "Returns an error if the tag doesn't select a variant."]
+        #visibility_tokens #function_modifier_tokens fn from_bits(bits: #tagged_union_data_type_tokens) -> ::core::result::Result<Self, &'static str> {
+            match #tag_variant_type_tokens::from_bits(bits).tag() {
+                #( #cases_tokens_list, )*
+                _ => ::core::result::Result::Err(#UNKNOWN_TAG_ERROR_MESSAGE),
+            }
+        }
+    }
+}
+
+fn generate_into_bits_tokens(tagged_union: &TaggedUnion) -> TokenStream {
+    let visibility_tokens = tagged_union.visibility().to_tokens();
+    let function_modifier_tokens = get_tagged_union_function_modifier_tokens(tagged_union);
+    let tagged_union_data_type_tokens = tagged_union.spanned_data_type_token().to_tokens();
+    let cases_tokens_list =
+        tagged_union.variants().iter().map(generate_into_bits_case_tokens).collect::<Vec<_>>();
+
+    quote! {
+        #[doc = "Returns the bits of the tagged union, with the tag of its variant."]
+        #visibility_tokens #function_modifier_tokens fn into_bits(self) -> #tagged_union_data_type_tokens {
+            match self {
+                #( #cases_tokens_list, )*
+            }
+        }
+    }
+}
+
+/// Generates the `into_bits` case of a variant, which writes back the tag in
+/// case the bits of the variant were changed.
+fn generate_into_bits_case_tokens(variant: &TaggedUnionVariant) -> TokenStream {
+    let variant_name_tokens = variant.name_tokens();
+
+    quote! {
+        Self::#variant_name_tokens(variant) => variant.with_variant_tag().into_bits()
+    }
+}
+
+/// Generates the function writing the tag of the variant into the struct of
+/// the variant, which is private as the tag is read-only.
+fn generate_variant_tag_writer_tokens(
+    tagged_union: &TaggedUnion,
+    variant: &TaggedUnionVariant,
+) -> TokenStream {
+    let function_modifier_tokens = get_tagged_union_function_modifier_tokens(tagged_union);
+    let variant_type_tokens = variant.bitfield().type_tokens();
+    let setting_tag_tokens =
+        generate_setting_field_to_default_tokens(variant.bitfield(), &variant.tag_field());
+
+    quote! {
+        impl #variant_type_tokens {
+            #function_modifier_tokens fn with_variant_tag(self) -> Self {
+                let mut this = self;
+                #setting_tag_tokens;
+                this
+            }
+        }
+    }
+}
+
+fn generate_tag_tokens(tagged_union: &TaggedUnion) -> TokenStream {
+    let visibility_tokens = tagged_union.visibility().to_tokens();
+    let tag_data_type_tokens = tagged_union.tag_data_type_tokens();
+    let cases_tokens_list = tagged_union.variants().iter().map(|variant| {
+        let tag_tokens = variant.tag_tokens();
+        let variant_name_tokens = variant.name_tokens();
+        quote! {
+            Self::#variant_name_tokens(_) => #tag_tokens
+        }
+    });
+
+    quote! {
+        #[doc = "Returns the tag of the variant."]
+        #visibility_tokens const fn tag(&self) -> #tag_data_type_tokens {
+            match self {
+                #( #cases_tokens_list, )*
+            }
+        }
+    }
+}
+
+/// Generates the `From` implementations wrapping the struct of each variant.
+fn generate_from_variant_traits_tokens(tagged_union: &TaggedUnion) -> TokenStream {
+    let name_tokens = tagged_union.name_tokens();
+    let from_variant_traits_tokens_list = tagged_union.variants().iter().map(|variant| {
+        let variant_name_tokens = variant.name_tokens();
+        let variant_type_tokens = variant.bitfield().type_tokens();
+        quote! {
+            impl core::convert::From<#variant_type_tokens> for #name_tokens {
+                fn from(variant: #variant_type_tokens) -> Self {
+                    Self::#variant_name_tokens(variant)
+                }
+            }
+        }
+    });
+
+    quote! {
+        #( #from_variant_traits_tokens_list )*
+    }
+}
+
+/// Generates the `TryFrom` and `From` implementations converting from and
+/// into bits.
+fn generate_from_traits_tokens(tagged_union: &TaggedUnion) -> Option<TokenStream> {
+    if !tagged_union.arguments().generate_from_traits() {
+        return None;
+    }
+
+    let name_tokens = tagged_union.name_tokens();
+    let tagged_union_data_type_tokens = tagged_union.spanned_data_type_token().to_tokens();
+
+    Some(quote! {
+        impl core::convert::TryFrom<#tagged_union_data_type_tokens> for #name_tokens {
+            type Error = &'static str;
+
+            fn try_from(bits: #tagged_union_data_type_tokens) -> ::core::result::Result<Self, Self::Error> {
+                Self::from_bits(bits)
+            }
+        }
+
+        impl core::convert::From<#name_tokens> for #tagged_union_data_type_tokens {
+            fn from(val: #name_tokens) -> Self {
+                val.into_bits()
+            }
+        }
+    })
+}
+
+fn generate_debug_trait_tokens(tagged_union: &TaggedUnion) -> Option<TokenStream> {
+    if !tagged_union.arguments().generate_debug() {
+        return None;
+    }
+
+    let name_tokens = tagged_union.name_tokens();
+    let cases_tokens_list = tagged_union.variants().iter().map(|variant| {
+        let variant_name_tokens = variant.name_tokens();
+        let variant_name = variant_name_tokens.to_string();
+        quote! {
+            Self::#variant_name_tokens(variant) => f.debug_tuple(#variant_name).field(variant).finish()
+        }
+    });
+
+    Some(quote! {
+        impl core::fmt::Debug for #name_tokens {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    #( #cases_tokens_list, )*
+                }
+            }
+        }
+    })
+}
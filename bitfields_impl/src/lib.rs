@@ -3,7 +3,11 @@ use parsing::bitfields::bitfield::Bitfield;
 use parsing::bitfields::bitfield_parser::parse_bitfield_struct;
 
 use crate::generating::bitflag::bitflag_generator::generate_bitflag;
+use crate::generating::tagged_union::tagged_union_generator::generate_tagged_union;
 use crate::parsing::bitflags::bitflag_parser::parse_bitflag_enum;
+use crate::parsing::tagged_unions::tagged_union_parser::{
+    is_tagged_union_enum, parse_tagged_union_enum,
+};
 
 mod generating;
 mod parsing;
@@ -20,7 +24,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// up to the exact number of bits of the bitfield type.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u8)]
@@ -37,6 +40,33 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// ### Signed Bitfield Types
+///
+/// A primitive bitfield can also represent the signed types (`i8`, `i16`, `i32`,
+/// `i64`, `i128`), for formats documented with signed containers. The bits are
+/// held by the unsigned counterpart, but the `from_bits`, `into_bits` and
+/// `write_bits` functions and the `From` implementations use the signed type.
+/// The raw bit operations (`get_bits_range`, `set_bits_range`) remain unsigned.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(i32)]
+/// struct Sample {
+///     #[bits(31)]
+///     magnitude: u32,
+///     negative: bool,
+/// }
+///
+/// fn main() {
+///     let sample = Sample::from_bits(-1);
+///     assert_eq!(sample.magnitude(), 0x7FFF_FFFF);
+///     assert!(sample.negative());
+///     assert_eq!(sample.into_bits(), -1);
+///     assert_eq!(i32::from(Sample::from(i32::MIN)), i32::MIN);
+/// }
+/// ```
+///
 /// ### Array Backed Bitfield
 ///
 /// A bitfield can also be backed by an `[u8;N]` array type, which allows for
@@ -50,7 +80,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// array bitfields**.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 17])] /// 136 bits.
@@ -76,6 +105,40 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// ### Word Array Backed Bitfield
+///
+/// A bitfield can also be backed by a `[u16; N]`, `[u32; N]` or `[u64; N]` word
+/// array, which suits register files and descriptors documented as words. The
+/// bits are held by the equivalent byte array, so the bytes functions remain
+/// available, and word functions are added: `from_words`, `into_words`,
+/// `get_words_range`, `set_words_range` and their `le`/`be`, `with_defaults` and
+/// checked variants, along with the `From` implementations of the word array.
+///
+/// The words follow the same endian as the bytes, so the first word of the
+/// default big-endian order holds the most significant bits. The words of the
+/// range functions are little-endian, like their bytes.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield([u32; 2])]
+/// struct Descriptor {
+///     addr: u32,
+///     #[bits(24)]
+///     len: u32,
+///     flags: u8,
+/// }
+///
+/// fn main() {
+///     let descriptor = Descriptor::from_words([0x0100_0040, 0x8000_0000]);
+///     assert_eq!(descriptor.flags(), 0x01);
+///     assert_eq!(descriptor.len(), 0x40);
+///     assert_eq!(descriptor.addr(), 0x8000_0000);
+///     assert_eq!(descriptor.into_le_words(), [0x8000_0000, 0x0100_0040]);
+///     assert_eq!(descriptor.get_words_range(32, 24), [0x40, 0]);
+/// }
+/// ```
+///
 /// ### Constructing a Bitfield
 ///
 /// #### Bitfield Constructor
@@ -87,7 +150,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// which always keep their defaults.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -127,7 +189,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// but allow you to set fields using a fluent builder pattern.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -172,7 +233,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// up to you to make sure they fit.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// /// A constant variable can be used as a default value.
@@ -210,6 +270,57 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Const Expression Bits
+///
+/// The bits of a field can be a const path or simple const arithmetic, to share
+/// layouts through constants. Arithmetic of literals (e.g. `2 * 4`) is evaluated
+/// by the macro, while expressions with const paths are evaluated by the compiler,
+/// which checks the layout with generated `const` assertions. The constants must be
+/// `u32`s.
+///
+/// The bits of array, float, niche-typed, encoded and byte ordered fields, and bit
+/// ranges must be known by the macro. The lengths of `[u8; N]` array fields and of
+/// the `#[bitfield([u8; N])]` backing type can be arithmetic of literals (e.g.
+/// `[u8; 2 * 4]`) or const paths (e.g. `[u8; LEN]`), which the compiler checks
+/// the same way. The lengths of packed array fields, and the backing length of
+/// bitfields with explicit bit positions, split fields or the `fill` argument must
+/// be known by the macro.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// const ADDR_BITS: u32 = 20;
+///
+/// #[bitfield(u32)]
+/// struct Register {
+///     #[bits(ADDR_BITS)]
+///     addr: u32,
+///     #[bits(32 - ADDR_BITS - 4)]
+///     flags: u8,
+///     #[bits(2 * 2)]
+///     _reserved: u8,
+/// }
+///
+/// const DESCRIPTOR_LEN: usize = 4;
+///
+/// #[bitfield([u8; DESCRIPTOR_LEN])]
+/// struct Descriptor {
+///     id: [u8; DESCRIPTOR_LEN - 1],
+///     flags: u8,
+/// }
+///
+/// fn main() {
+///     let register = RegisterBuilder::new().with_addr(0x12345).with_flags(0xA5).build();
+///     assert_eq!(register.addr(), 0x12345);
+///     assert_eq!(register.flags(), 0xA5);
+///     assert_eq!(Register::FLAGS_OFFSET, 20);
+///
+///     let descriptor = DescriptorBuilder::new().with_id([0x01, 0x02, 0x03]).with_flags(0x04).build();
+///     assert_eq!(descriptor.id(), [0x01, 0x02, 0x03]);
+///     assert_eq!(descriptor.into_bytes(), [0x04, 0x03, 0x02, 0x01]);
+/// }
+/// ```
+///
 /// #### Signed Bitfield Fields
 ///
 /// A bitfield can have signed (`i8`, `i16`, `i32`, `i64`, `i128`) types. Signed
@@ -218,7 +329,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// range would be `-16` to `15`. The more bits you include, the larger the range!
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -243,13 +353,325 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Arbitrary-Width Integer Fields
+///
+/// Setters of integer fields truncate values that don't fit in the bits of the
+/// field. Fields can instead be arbitrary-width integers, `u1` to `u127` and `i1`
+/// to `i127` (or `UInt<T, N>` and `Int<T, N>` stored in the primitive `T`), which
+/// take the width of their type and whose getters and setters use the type. Values
+/// are checked to fit when they're created with `new`, which fails compilation in
+/// const contexts, or `try_new`, which returns an error. Default values are checked
+/// to fit at compile time.
+///
+/// Arbitrary-width integers support arithmetic, which panics if the result doesn't
+/// fit, while the `checked_*`, `wrapping_*` and `saturating_*` methods return
+/// `None`, wrap or saturate instead. They convert into their primitive types with
+/// `value` or `From`. Arbitrary-width integer fields convert through the const
+/// `new_truncated` and `value` functions, so the generated functions stay `const`,
+/// and `Debug` shows their value.
+///
+/// ```rust
+/// use bitfields::{bitfield, i4, u4, u12};
+///
+/// #[bitfield(u32)]
+/// struct Sprite {
+///     #[bits(default = 9)]
+///     palette: u4,
+///     tile: u12,
+///     #[bits(default = -3)]
+///     velocity: i4,
+///     #[bits(12)]
+///     _reserved: u16,
+/// }
+///
+/// fn main() {
+///     let mut sprite = Sprite::new();
+///     assert_eq!(sprite.palette(), u4::new(9));
+///     assert_eq!(sprite.velocity().value(), -3);
+///
+///     sprite.set_tile(u12::new(0x123));
+///     sprite.set_palette(sprite.palette() + u4::new(1));
+///     assert_eq!(sprite.into_bits(), 0x000D_123A);
+///
+///     // Values that don't fit are caught where they're created.
+///     assert!(u4::try_new(0x12).is_err());
+///     assert_eq!(u4::MAX.checked_add(u4::new(1)), None);
+///     assert_eq!(u16::from(sprite.tile()), 0x123);
+/// }
+/// ```
+///
+/// #### Float Fields
+///
+/// Fields can be `f32` or `f64` types, which are stored as their IEEE 754 bits. A
+/// float field occupies the bits of its type, or `16` bits when stored as a
+/// half-precision float with `half = true`. Half-precision values are rounded to the
+/// nearest half-precision float when set, values too large become infinite.
+///
+/// Float fields take float default values, which are checked to fit in the float
+/// at compile time. Float fields convert through the const `from_bits` and
+/// `to_bits` functions of their type, so the generated functions stay `const`, and
+/// `Debug` shows their value.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u64)]
+/// struct Bitfield {
+///     #[bits(default = 1.5)]
+///     a: f32,
+///
+///     /// Stored in 16 bits as a half-precision float.
+///     #[bits(16, half = true, default = -2.0)]
+///     b: f32,
+///
+///     c: u16,
+/// }
+///
+/// fn main() {
+///     let mut bitfield = Bitfield::new();
+///     assert_eq!(bitfield.a(), 1.5);
+///     assert_eq!(bitfield.b(), -2.0);
+///     assert_eq!(bitfield.into_bits(), 0x0000_C000_3FC0_0000);
+///
+///     bitfield.set_b(0.1);
+///     assert_eq!(bitfield.b(), 0.099975586);
+/// }
+/// ```
+///
+/// #### Fixed-Point and Scaled Fields
+///
+/// Integer fields can hold physical quantities as Q-format fixed-point numbers with
+/// `fixed = N` fraction bits, or as linearly scaled values `raw * scale + offset`
+/// with the `scale` and `offset` arguments. The getters and setters of these fields
+/// take `f64` values, which are rounded to the nearest raw value when set, while
+/// the raw accessors have a `_raw` suffix. A `unit` can be added, which is shown in
+/// the generated docs and `Debug` output.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u32)]
+/// struct TelemetryFrame {
+///     /// A Q8.4 fixed-point number.
+///     #[bits(12, fixed = 4)]
+///     ratio: u16,
+///
+///     /// Default values are raw values.
+///     #[bits(10, scale = 0.1, offset = -40, unit = "C", default = 650)]
+///     temperature: u16,
+///
+///     #[bits(10)]
+///     _reserved: u16,
+/// }
+///
+/// fn main() {
+///     let mut frame = TelemetryFrame::new();
+///     assert_eq!(frame.temperature(), 25.0);
+///     assert_eq!(frame.temperature_raw(), 650);
+///
+///     frame.set_ratio(2.53);
+///     assert_eq!(frame.ratio(), 2.5);
+///     assert_eq!(frame.ratio_raw(), 0x28);
+///
+///     // Checked setters return an error if the raw value doesn't fit.
+///     assert!(frame.checked_set_temperature(100.0).is_err());
+///     assert_eq!(format!("{frame:?}"), "TelemetryFrame { ratio: 2.5, temperature: 25.0 C, _reserved: 0 }");
+/// }
+/// ```
+///
+/// #### Field Encodings
+///
+/// Integer and bool fields are stored as is by default, with signed fields in two's
+/// complement. The `encoding` argument stores the value of a field in another
+/// encoding, which the getters, setters, default values and `Debug` output
+/// translate from and into:
+///
+/// - `sign_magnitude` - A sign bit followed by the magnitude, for signed fields.
+/// - `offset_binary` - The value offset by half the range of the field, for signed
+///   fields.
+/// - `bcd` - A decimal digit in each 4 bits, for unsigned fields.
+/// - `gray` - The reflected binary Gray code of the value, for unsigned fields.
+/// - `active_low` - The inverted bits of the value, for bool and unsigned fields.
+///
+/// Checked setters return an error if the value can't be encoded in the field
+/// bits. BCD fields also have a checked getter, which returns an error if a digit
+/// of the bits isn't decimal, where the getter decodes them as is. Fields without
+/// a default value start as zero (or `false`) when defaults are applied, so
+/// `offset_binary` and `active_low` fields start with the encoded zero. The
+/// `from_bits` function and the `clear` bit operations use zeroed bits as is, so
+/// an `active_low` bool field read from zeroed bits is `true`.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u32)]
+/// struct Rtc {
+///     #[bits(8, encoding = bcd, default = 59)]
+///     minutes: u8,
+///     #[bits(12, encoding = offset_binary)]
+///     sample: i16,
+///     #[bits(4, encoding = gray)]
+///     position: u8,
+///     #[bits(1, encoding = active_low, default = false)]
+///     enable: bool,
+///     #[bits(7)]
+///     _reserved: u8,
+/// }
+///
+/// fn main() {
+///     let mut rtc = Rtc::new();
+///     assert_eq!(rtc.minutes(), 59);
+///     assert_eq!(rtc.sample(), 0);
+///     assert!(!rtc.enable());
+///     assert_eq!(rtc.into_bits(), 0x0108_0059);
+///
+///     rtc.set_sample(-1);
+///     rtc.set_position(2);
+///     assert_eq!(rtc.into_bits(), 0x0137_FF59);
+///     assert!(rtc.checked_set_minutes(100).is_err());
+///
+///     let rtc = Rtc::from_bits(0x0000_0842);
+///     assert_eq!(rtc.minutes(), 42);
+///     assert_eq!(rtc.sample(), 8 - 2048);
+///     assert!(Rtc::from_bits(0x0000_00FF).checked_minutes().is_err());
+/// }
+/// ```
+///
+/// #### Niche-Typed Fields
+///
+/// Fields can be non-zero unsigned integers (`NonZeroU8` to `NonZeroU128`), which
+/// are stored as their integer bits and can occupy fewer bits than their type. Zero
+/// bits have no non-zero value, so the getter panics on them, while the checked
+/// getter (`checked_<field>`) returns an error.
+///
+/// Fields can also be `Option` types wrapping an unsigned integer, a non-zero
+/// integer or a custom type, with the `none` argument defining the bits that
+/// represent `None` (e.g. `0xFF` for an unset slot). The getter returns `None` for
+/// those bits and setting `None` writes them back. The checked setter returns an error
+/// when a `Some` value has the bits of `none`. Without a default value, `Option`
+/// fields default to `None`. `Debug` shows `Option` fields of integers, non-zero
+/// integers and arbitrary-width integers as their value, while `Option` fields of
+/// custom types show their bits.
+///
+/// ```rust
+/// use core::num::NonZeroU8;
+///
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u16)]
+/// struct Bitfield {
+///     /// No IRQ is assigned when the bits are zero.
+///     #[bits(4)]
+///     irq: NonZeroU8,
+///
+///     #[bits(4)]
+///     _reserved: u8,
+///
+///     /// No slot is set when the bits are `0xFF`.
+///     #[bits(8, none = 0xFF)]
+///     slot: Option<u8>,
+/// }
+///
+/// fn main() {
+///     let mut bitfield = Bitfield::new();
+///     assert_eq!(bitfield.checked_irq(), Err("The field bits are zero."));
+///     assert_eq!(bitfield.slot(), None);
+///     assert_eq!(bitfield.into_bits(), 0xFF00);
+///
+///     bitfield.set_irq(NonZeroU8::new(9).unwrap());
+///     bitfield.set_slot(Some(2));
+///     assert_eq!(bitfield.irq().get(), 9);
+///     assert_eq!(bitfield.slot(), Some(2));
+///     assert_eq!(bitfield.into_bits(), 0x0209);
+/// }
+/// ```
+///
+/// #### Enumerated Values
+///
+/// Fields can list their values inline with the `values(..)` argument instead of
+/// declaring a separate `#[bitflag]` enum. The macro generates an enum named by the
+/// field type next to the bitfield, with a variant for each value, and checks at
+/// compile time that every value fits in the bits of the field. Bits without a
+/// variant are read as the first variant, while the raw getter (`<field>_raw`)
+/// returns the bits as is.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u8)]
+/// struct DisplayControl {
+///     #[bits(2, values(Picture = 0, Video = 1, Sleep = 2, Game = 3))]
+///     bg_mode: BgMode,
+///     #[bits(3, default = Palette::Warm, values(Mono = 1, Warm = 2, Cold = 4))]
+///     palette: Palette,
+///     #[bits(3)]
+///     _reserved: u8,
+/// }
+///
+/// fn main() {
+///     let mut display_control = DisplayControl::new();
+///     assert_eq!(display_control.palette(), Palette::Warm);
+///
+///     display_control.set_bg_mode(BgMode::Game);
+///     assert_eq!(display_control.bg_mode(), BgMode::Game);
+///     assert_eq!(display_control.into_bits(), 0b0000_1011);
+///
+///     // The palette bits `0b111` have no variant.
+///     let display_control = DisplayControl::from_bits(0b0001_1100);
+///     assert_eq!(display_control.palette(), Palette::Mono);
+///     assert_eq!(display_control.palette_raw(), 0b111);
+/// }
+/// ```
+///
+/// #### ASCII String Fields
+///
+/// Fixed-width text embedded in binary headers (e.g. the title of a ROM header or
+/// the name of a FAT directory entry) can be an `AsciiStr<N>` field, which stores
+/// `N` ASCII bytes padded with trailing NUL bytes in `N * 8` bits, with the first
+/// byte in the lowest bits. Getters return the fixed buffer with the bytes as read,
+/// which can be viewed as a `&str` without the padding through `as_str`, returning
+/// an error if the bytes aren't ASCII. Strings are validated and padded when
+/// creating an `AsciiStr` with `AsciiStr::new` or `try_into`, which return an error
+/// if the string isn't ASCII or is too long. String default values are checked to
+/// fit the field at compile time, and `Debug` shows the string of the field.
+///
+/// ASCII string fields in integer-backed bitfields can have at most 16 bytes, while
+/// array-backed bitfields (e.g. `#[bitfield([u8; 32])]`) hold strings of any
+/// length.
+///
+/// ```rust
+/// use bitfields::{bitfield, AsciiStr};
+///
+/// #[bitfield(u128)]
+/// struct RomHeader {
+///     #[bits(96, default = "NONAME")]
+///     title: AsciiStr<12>,
+///     maker_code: AsciiStr<2>,
+///     version: u8,
+///     checksum: u8,
+/// }
+///
+/// fn main() {
+///     let mut rom_header = RomHeader::new();
+///     assert_eq!(rom_header.title().as_str(), Ok("NONAME"));
+///
+///     rom_header.set_title(AsciiStr::new("ZELDA").unwrap());
+///     rom_header.set_maker_code("01".try_into().unwrap());
+///     assert_eq!(rom_header.title(), "ZELDA");
+///     assert_eq!(rom_header.maker_code().as_bytes(), b"01");
+///
+///     // Strings that aren't ASCII or don't fit are rejected.
+///     assert!(AsciiStr::<12>::new("THE LEGEND OF ZELDA").is_err());
+///     assert!(AsciiStr::<2>::try_from("ÉA").is_err());
+/// }
+/// ```
+///
 /// #### Array Bitfield Fields
 ///
 /// Bitfield fields can also be `[u8;N]` array types, which are useful
 /// for representing fields that are larger than 128 bits.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -270,6 +692,110 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// Arrays of other integer types and `bool` are also supported, with each element
+/// taking the bits of its type. Like [packed array fields](#packed-array-fields),
+/// they have indexed getters and setters. Multi-byte elements follow the
+/// `from_endian` and `into_endian` of the bitfield, and signed elements are sign
+/// extended.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u64)]
+/// struct Registers {
+///     /// One bit per interrupt line.
+///     interrupts: [bool; 16],
+///     words: [u16; 2],
+///     offsets: [i8; 2],
+/// }
+///
+/// fn main() {
+///     let mut registers = Registers::new();
+///     registers.set_interrupts_at(3, true);
+///     registers.set_words([0x1234, 0xABCD]);
+///     registers.set_offsets([-1, 1]);
+///
+///     assert!(registers.interrupts_at(3));
+///     assert_eq!(registers.words_at(1), 0xABCD);
+///     assert_eq!(registers.offsets(), [-1, 1]);
+///     assert_eq!(registers.into_bits(), 0x01FF_ABCD_1234_0008);
+/// }
+/// ```
+///
+/// #### Packed Array Fields
+///
+/// Array fields can pack elements narrower than a byte with the `element`
+/// argument, which defines the bits of each element. The field occupies the
+/// element bits times the array length, with the first element in the lowest bits.
+/// Besides the whole-array getter and setter, packed array fields have indexed
+/// `<field>_at` getters and `set_<field>_at` setters, which panic on an index
+/// outside the array, with checked variants returning an error instead, and
+/// `<FIELD>_ELEMENT_BITS` and `<FIELD>_LEN` constants.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u16)]
+/// struct Sprite {
+///     /// 4 colors of 2 bits each.
+///     #[bits(element = 2)]
+///     palette: [u8; 4],
+///     #[bits(element = 4)]
+///     tiles: [u8; 2],
+/// }
+///
+/// fn main() {
+///     let mut sprite = Sprite::new();
+///     sprite.set_palette([1, 2, 3, 0]);
+///     sprite.set_palette_at(3, 2);
+///     assert_eq!(sprite.palette_at(3), 2);
+///     assert_eq!(sprite.palette(), [1, 2, 3, 2]);
+///
+///     // Elements that don't fit in the element bits are rejected.
+///     assert!(sprite.checked_set_palette_at(0, 4).is_err());
+///     // So are indices outside the array.
+///     assert!(sprite.checked_set_palette_at(4, 1).is_err());
+///
+///     assert_eq!(Sprite::PALETTE_ELEMENT_BITS, 2);
+///     assert_eq!(Sprite::PALETTE_LEN, 4);
+///     assert_eq!(sprite.into_bits(), 0x00B9);
+/// }
+/// ```
+///
+/// The elements of a packed array can also be bitflags or custom types, which
+/// convert through the `BitfieldField` trait like nested fields. The element bits
+/// must be able to hold the bits of the element type, and the element type must be
+/// `Copy`.
+///
+/// ```rust
+/// use bitfields::{bitfield, bitflag};
+///
+/// #[bitflag(u8)]
+/// #[derive(Debug, PartialEq)]
+/// enum PinMode {
+///     #[base]
+///     Input = 0,
+///     Output = 1,
+///     Alternate = 2,
+///     Analog = 3,
+/// }
+///
+/// #[bitfield(u16)]
+/// struct GpioModes {
+///     #[bits(element = 2)]
+///     modes: [PinMode; 8],
+/// }
+///
+/// fn main() {
+///     let mut gpio = GpioModes::new();
+///     gpio.set_modes_at(0, PinMode::Output);
+///     gpio.set_modes_at(7, PinMode::Analog);
+///     assert_eq!(gpio.modes_at(7), PinMode::Analog);
+///     assert_eq!(gpio.modes()[0], PinMode::Output);
+///     assert_eq!(gpio.into_bits(), 0xC001);
+/// }
+/// ```
+///
 /// #### Checked Setters
 ///
 /// Normally, when fields are set, the provided value is truncated to the number of
@@ -277,7 +803,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// the value overflows the bits of the field.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -301,6 +826,85 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Overflow Policy
+///
+/// The `overflow` argument changes what setters do with values that overflow the
+/// bits of a field. It can be set on the `#[bitfield]` attribute for every field,
+/// and overridden per field in the `#[bits]` attribute.
+///
+/// - `truncate` (default): Keeps the lower bits of the value.
+/// - `saturate`: Clamps the value to the bounds of the field. Only integer fields
+///   and arrays of integers can saturate values.
+/// - `panic`: Panics where the checked setter would return an error, which
+///   includes values that don't satisfy a [value constraint](#value-constraints).
+/// - `debug_assert`: Panics in debug builds where the checked setter would return
+///   an error, and truncates in release builds.
+///
+/// The policy applies to setters, element setters and builder methods. Checked
+/// setters always return an error instead.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u16, overflow = saturate)]
+/// struct Bitfield {
+///     #[bits(4)]
+///     a: u8,
+///     #[bits(6)]
+///     b: i8,
+///     #[bits(6, overflow = panic)]
+///     c: u8,
+/// }
+///
+/// fn main() {
+///     let mut bitfield = Bitfield::new();
+///     bitfield.set_a(0x12); // Clamped to 4 bits.
+///     bitfield.set_b(-100); // Clamped to 6 bits.
+///     assert_eq!(bitfield.a(), 0xF);
+///     assert_eq!(bitfield.b(), -32);
+///
+///     bitfield.set_c(0x3F);
+///     // bitfield.set_c(0x40); // Panics, value overflows bits.
+///     assert_eq!(bitfield.c(), 0x3F);
+/// }
+/// ```
+///
+/// #### Value Constraints
+///
+/// Fields that only accept some of the values their bits can hold can be
+/// constrained to an inclusive or exclusive range with `range = 1..=12`, or to a
+/// list of values with `values = [4, 8, 16]`. Checked setters and checked builder
+/// setters return an error if the value doesn't satisfy the constraint, and the
+/// `checked_from_bits` (or `checked_from_bytes`) function returns an error if a
+/// decoded field doesn't. Setters with the `panic` or `debug_assert`
+/// [overflow policy](#overflow-policy) panic on such values, while other setters
+/// set them as is. Default values are checked to satisfy the constraint at compile
+/// time.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u16)]
+/// struct Bitfield {
+///     #[bits(4, range = 1..=12, default = 1)]
+///     divider: u8,
+///     #[bits(5, values = [4, 8, 16], default = 4)]
+///     burst_length: u8,
+///     #[bits(7)]
+///     _reserved: u8,
+/// }
+///
+/// fn main() {
+///     let mut bitfield = Bitfield::new();
+///     assert!(bitfield.checked_set_divider(12).is_ok());
+///     assert!(bitfield.checked_set_divider(13).is_err()); // Error, outside the range.
+///     assert!(bitfield.checked_set_burst_length(6).is_err()); // Error, not a listed value.
+///
+///     assert!(Bitfield::checked_from_bits(0x0081).is_ok());
+///     assert!(Bitfield::checked_from_bits(0x0080).is_err()); // Error, the divider is 0.
+/// }
+/// ```
+///
 /// #### Field Access
 ///
 /// Field access can be controlled by specifying the `#[bits(access = N)]` arg on a
@@ -327,7 +931,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///   the access level without having to change the field name.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -382,17 +985,70 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Accessor Visibility
+///
+/// Accessors have the visibility of their field by default. The
+/// `#[bits(get = N)]` and `#[bits(set = N)]` args give the getters and the setters
+/// of a field their own visibility, so a field can be readable by everyone but only
+/// writable inside the crate. Valid visibilities are `pub`, `pub(crate)`,
+/// `pub(super)` and `pub(self)`.
+///
+/// - `get` applies to the getters, checked getters and `<field>_inverted()`.
+///
+/// - `set` applies to the setters, checked setters, `clear_<field>()`,
+///   `invert_<field>()` and the builder methods. Read-only fields keep their
+///   builder methods, so they accept `set` too.
+///
+/// ```rust
+/// mod driver {
+///     use bitfields::bitfield;
+///
+///     #[bitfield(u16)]
+///     pub struct StatusRegister {
+///         #[bits(get = pub, set = pub(crate))]
+///         status: u8,
+///         #[bits(get = pub, set = pub(self))]
+///         irq_count: u8,
+///     }
+///
+///     impl StatusRegister {
+///         pub fn record_irq(&mut self) {
+///             self.set_irq_count(self.irq_count().wrapping_add(1));
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let mut register = driver::StatusRegisterBuilder::new().with_status(0x80).build();
+///     register.set_status(0x81);
+///     register.record_irq();
+///     // register.set_irq_count(0); // Compile error, the setter is private to the driver.
+///
+///     assert_eq!(register.status(), 0x81);
+///     assert_eq!(register.irq_count(), 1);
+/// }
+/// ```
+///
 /// #### Custom Types
 ///
-/// A bitfield field can be a user-defined custom types, but the bits of the field
-/// must be specified since the macro has no way of knowing how many bits the custom
-/// type occupies. To interface with a bitfield, the custom types must implement the
-/// const functions `from_bits` and `into_bits`.
+/// A bitfield field can be a user-defined custom types. To interface with a
+/// bitfield, the custom types must implement the `BitfieldField` trait, which
+/// converts the type to and from its raw bits. If the `#[bits]` attribute is
+/// omitted, the field occupies the `BitfieldField::BITS` of the custom type. If the
+/// bits are specified, they must be at least `BitfieldField::BITS`, otherwise
+/// compilation fails. Types with a signed `BitfieldField::Raw` must match it, as a
+/// wider field wouldn't carry the sign in its top bit.
+///
+/// Custom type fields, including nested bitfields and bitflags, always convert
+/// through the `BitfieldField` trait. Trait methods can't be called from const
+/// functions, so the accessors of custom type fields, and the functions converting
+/// all fields (e.g. `new` and `from_bits`), aren't `const`. The accessors of the
+/// other fields stay `const`.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitflag;
 /// use bitfields::bitfield;
+/// use bitfields::BitfieldField;
 ///
 /// const DEFAULT_PLAYER_STATE: PlayerState = PlayerState {
 ///     health: 15,
@@ -411,8 +1067,8 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///     game_state: GameState
 /// }
 ///
-/// /// A struct can be a custom type, but must implement the `from_bits` 
-/// /// and `into_bits` const functions to convert to and from bits.
+/// /// A struct can be a custom type, but must implement the `BitfieldField` 
+/// /// trait to convert to and from bits.
 /// #[derive(Debug, PartialEq)]
 /// struct PlayerState {
 ///     health: u8,
@@ -420,10 +1076,16 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///     stamina: u8,
 /// }
 ///
-/// impl PlayerState {
-///     /// The from_bits takes in the bits of the field and converts 
+/// impl BitfieldField for PlayerState {
+///     /// The number of bits the custom type occupies.
+///     const BITS: u32 = 7;
+///
+///     /// The primitive type the bits of the field are passed as.
+///     type Raw = u8;
+///
+///     /// The from_raw takes in the bits of the field and converts 
 ///     /// it into the custom type.
-///     const fn from_bits(bits: u8) -> Self {
+///     fn from_raw(bits: u8) -> Self {
 ///         Self {
 ///             health: bits & 0b0000_1111, // First 4 bits for health
 ///             mana: (bits >> 4) & 0b0000_0011, // Next 2 bits for mana
@@ -431,10 +1093,9 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///         }
 ///     }
 ///
-///     /// The into_bits converts the custom type into a packed bits
-///     /// representation. The only requirement is that the return type
-///     /// is a primitive type.
-///     const fn into_bits(self) -> u8 {
+///     /// The into_raw converts the custom type into a packed bits
+///     /// representation.
+///     fn into_raw(self) -> u8 {
 ///         (self.health & 0b0000_1111) |
 ///             ((self.mana & 0b0000_0011) << 4) |
 ///             ((self.stamina & 0b0000_0001) << 6)
@@ -442,11 +1103,10 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 ///
 /// /// Enums can also be custom types as long as they implement the 
-/// /// `from_bits` and `into_bits` const functions.
+/// /// `BitfieldField` trait.
 /// ///
 /// /// Instead of writing custom enum types by hand, you can also
-/// /// use the `#[bitflag]` attribute to generate these functions
-/// /// for you.
+/// /// use the `#[bitflag]` attribute to implement the trait for you.
 /// #[derive(Debug, PartialEq)]
 /// enum GameState {
 ///     Playing = 0,
@@ -454,10 +1114,11 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///     GameOver = 2,
 /// }
 ///
-/// impl GameState {
-///     /// The from_bits takes in the bits of the field and converts
-///     /// it into the custom type.
-///     const fn from_bits(bits: u8) -> Self {
+/// impl BitfieldField for GameState {
+///     const BITS: u32 = 2;
+///     type Raw = u8;
+///
+///     fn from_raw(bits: u8) -> Self {
 ///         match bits {
 ///             0 => Self::Playing,
 ///             1 => Self::Paused,
@@ -466,18 +1127,15 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///         }
 ///     }
 ///
-///     /// The into_bits converts the custom type into a packed bits'
-///     /// representation. The only requirement is that the return
-///     /// type is a primitive type.
-///     const fn into_bits(self) -> u8 {
+///     fn into_raw(self) -> u8 {
 ///         self as u8
 ///     }
 /// }
 ///
-/// /// Bitflags are enums but the `from_bits` and `into_bits` functions
-/// /// are generated for you. The only requirement is that one of the
-/// /// variants must be annotated with `#[base]` which represents the 
-/// /// base value of the bitflag.
+/// /// Bitflags are enums but the `BitfieldField` trait is implemented
+/// /// for you. The only requirement is that one of the variants must
+/// /// be annotated with `#[base]` which represents the base value of
+/// /// the bitflag.
 /// #[bitflag(u8)]
 /// #[derive(Debug, PartialEq)]
 /// enum GameStateBitflag {
@@ -489,19 +1147,17 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///
 /// /// The code that's generated for the `GameStateBitflag` by the
 /// /// `#[bitflag]` attribute saving you the trouble of writing 
-/// /// the `from_bits` and `into_bits` functions yourself.
-/// // impl GameState {
-/// //     const fn from_bits(bits: u8) -> Self {
-/// //         match bits {
-/// //             0 => Self::Playing,
-/// //             1 => Self::Paused,
-/// //             2 => Self::GameOver,
-/// //             _ => unreachable!(),
-/// //         }
+/// /// the trait implementation yourself.
+/// // impl BitfieldField for GameStateBitflag {
+/// //     const BITS: u32 = 2;
+/// //     type Raw = u8;
+/// //
+/// //     fn from_raw(bits: u8) -> Self {
+/// //         Self::from_bits(bits)
 /// //     }
 /// //
-/// //     const fn into_bits(self) -> u8 {
-/// //         self as u8
+/// //     fn into_raw(self) -> u8 {
+/// //         self.into_bits()
 /// //     }
 /// // }
 ///
@@ -517,18 +1173,18 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///
 /// #### Nested Bitfields
 ///
-/// Bitfields can be nested within other bitfields, but the bits of the nested
-/// bitfield must be specified since the macro has no way of knowing how many bits
-/// the nested bitfield occupies.
+/// Bitfields can be nested within other bitfields. The bits of the nested bitfield
+/// can be omitted, the field then occupies the number of bits of the nested
+/// bitfield type. Since the bits are only known by the compiler, the total number of
+/// bits of the fields is checked at compile time instead of by the macro.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
 /// struct GameWorld {
 ///     /// ❌ No compile time default value size checks for const functions.
-///     #[bits(32, default = GameMap::new())]
+///     #[bits(default = GameMap::new())]
 ///     game_map: GameMap,
 /// }
 ///
@@ -565,7 +1221,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// `__` as the name for all reserved fields.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -607,6 +1262,125 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Automatic Padding
+///
+/// By default, the field bits must add up to the bits of the bitfield type. With
+/// the `fill` argument, the bits that aren't occupied by any field are filled with
+/// implicit reserved fields instead: the missing high bits, or the missing low
+/// bits with the `msb` order, and the gaps between fields with explicit bit
+/// positions. The filled bits take part in the defaults, masks and `into_bits`
+/// like any reserved field.
+///
+/// - `fill = reserved`: The filled bits are 0 by default.
+/// - `fill = reserved_ones`: The filled bits are 1 by default.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u32, fill = reserved)]
+/// struct Control {
+///     enable: bool,
+///     #[bits(3)]
+///     mode: u8,
+/// }
+///
+/// #[bitfield(u16, fill = reserved_ones)]
+/// struct Status {
+///     #[bits(0..=3)]
+///     code: u8,
+///     #[bits(8..=11)]
+///     level: u8,
+/// }
+///
+/// fn main() {
+///     let control = ControlBuilder::new().with_enable(true).with_mode(0x5).build();
+///     assert_eq!(control.into_bits(), 0xB);
+///
+///     let status = StatusBuilder::new().with_code(0x1).build();
+///     assert_eq!(status.into_bits(), 0xF0F1);
+/// }
+/// ```
+///
+/// #### Alias Fields
+///
+/// Alias fields are additional views of the bits of another field, declared with
+/// the `alias_of` argument. An alias starts at the offset of the aliased field,
+/// unless a bit position is specified, and its bits must be within the bits of the
+/// aliased field. Alias fields have getters, setters, constants, and builder
+/// functions, but don't count toward the bits of the bitfield and aren't part of
+/// the `Debug` output.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u32)]
+/// struct Registers {
+///     ax: u16,
+///     /// The low byte of `ax`, bits 0..=7.
+///     #[bits(8, alias_of = ax)]
+///     al: u8,
+///     /// The high byte of `ax`, bits 8..=15.
+///     #[bits(alias_of = ax, 8..=15)]
+///     ah: u8,
+///     bx: u16,
+/// }
+///
+/// fn main() {
+///     let mut registers = Registers::new();
+///     registers.set_ax(0x1234);
+///     assert_eq!(registers.al(), 0x34);
+///     assert_eq!(registers.ah(), 0x12);
+///
+///     registers.set_ah(0xAB);
+///     assert_eq!(registers.ax(), 0xAB34);
+///     assert_eq!(Registers::AH_OFFSET, 8);
+/// }
+/// ```
+///
+/// #### Split Fields
+///
+/// Split fields are values whose bits are scattered across the bitfield, declared
+/// with the `segments` argument. Segments are bit positions or inclusive bit
+/// ranges, concatenated from the most significant segment, and the `shift`
+/// argument adds implicit zero bits below them. Setters discard the shifted bits,
+/// while checked setters return an error if any of them is set. Split fields must
+/// be integers and don't have field constants.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// /// A RISC-V branch instruction.
+/// #[bitfield(u32)]
+/// struct BranchInstruction {
+///     #[bits(7)]
+///     opcode: u8,
+///     #[bits(3, at = 12)]
+///     funct3: u8,
+///     #[bits(5, at = 15)]
+///     rs1: u8,
+///     #[bits(5, at = 20)]
+///     rs2: u8,
+///     /// The 13-bit branch offset, made of bits 31, 7, 30..=25, and 11..=8.
+///     #[bits(segments = [31, 7, 30..=25, 11..=8], shift = 1)]
+///     imm: i16,
+/// }
+///
+/// fn main() {
+///     // beq x1, x2, -4
+///     let instruction = BranchInstruction::from_bits(0xFE20_8EE3);
+///     assert_eq!(instruction.rs1(), 1);
+///     assert_eq!(instruction.rs2(), 2);
+///     assert_eq!(instruction.imm(), -4);
+///
+///     let mut instruction = BranchInstruction::new();
+///     instruction.set_opcode(0x63);
+///     instruction.set_rs1(1);
+///     instruction.set_rs2(2);
+///     instruction.set_imm(-4);
+///     assert_eq!(instruction.into_bits(), 0xFE20_8EE3);
+/// }
+/// ```
+///
 /// <!-- rust-bitflags-docs-start -->
 ///
 /// ### Bitflags
@@ -614,14 +1388,14 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// There are times when you just want to define a bitflag, which are just enums
 /// that map to bits. Instead of defining a custom type, you can take advantage of
 /// the `#[bitflag]` attribute which generates `from_bits` and `into_bits` for enums
-/// automatically.
+/// automatically and implements the `BitfieldField` trait so the enum can be used as
+/// a bitfield field.
 ///
 /// Bitflags only supports unsigned types (`u8`, `u16`, `u32`, `u64`, `u128`) and
 /// the one of the variants must be annotated with `#[base]` or `#[default]` which represents the
 /// base value of the bitflag. If `#[base]` and `#[default]` are both present, `#[base]` takes precedence.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 /// use bitfields::bitflag;
 ///
@@ -658,6 +1432,19 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// //         self as u8
 /// //     }
 /// // }
+/// //
+/// // impl BitfieldField for RenderMode {
+/// //     const BITS: u32 = 2;
+/// //     type Raw = u8;
+/// //
+/// //     fn from_raw(bits: u8) -> Self {
+/// //         Self::from_bits(bits)
+/// //     }
+/// //
+/// //     fn into_raw(self) -> u8 {
+/// //         self.into_bits()
+/// //     }
+/// // }
 ///
 /// /// Annotate an enum with the `#[bitflag]` attribute to automatically generate
 /// /// the `from_bits` and `into_bits` functions for you! One variant must be annotated
@@ -674,14 +1461,17 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///
 /// #[bitfield(u8)]
 /// struct DisplayControl {
-///     /// Must have the `#[bits]` attribute since the macro has 
-///     /// no way of knowing how many bits the custom type occupies.
+///     /// The bits can be specified, as long as they fit the bitflag.
 ///     #[bits(4, default = RenderMode::Normal)]
 ///     render_mode: RenderMode,
-///     /// Must have the `#[bits]` attribute since the macro has 
-///     /// no way of knowing how many bits the custom type occupies.
-///     #[bits(4, default = AudioMode::Stereo)]
+///     /// Without the bits, the field occupies the bits needed for the
+///     /// largest variant of the bitflag, which is 2 bits here. Adding a
+///     /// larger variant widens the field, so specify the bits when the
+///     /// layout must stay fixed.
+///     #[bits(default = AudioMode::Stereo)]
 ///     audio_mode: AudioMode,
+///     #[bits(2)]
+///     _reserved: u8,
 /// }
 ///
 /// fn main() {
@@ -708,13 +1498,83 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 ///
 /// <!-- rust-bitflags-docs-end -->
 ///
+/// ### Tagged Unions
+///
+/// Some formats use a tag field to select the layout of the remaining bits, such as
+/// the opcode of an instruction selecting its format. Annotating an enum with
+/// `#[bitfield]` and the `tag_bits` argument creates a tagged union, where each
+/// variant declares its fields and its tag with `#[tag = ..]`.
+///
+/// Each variant is generated as a bitfield struct named after the enum and the
+/// variant (e.g. `InstructionR`), with a read-only `tag` field as its first field,
+/// defaulting to the tag of the variant, so each variant has the full set of
+/// accessors. The `from_bits` function of the enum decodes the variant selected by
+/// the tag, returning an error for an unknown tag, and `into_bits` writes the tag
+/// of the variant back. Tagged unions only support integer types.
+///
+/// A variant can place the tag elsewhere by declaring a `tag` field with the type
+/// of the tag (e.g. `tag: u8` as its last field), which can only have
+/// documentation attributes. The tag must be at the same offset in every variant.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// #[bitfield(u32, tag_bits = 7)]
+/// #[derive(PartialEq)]
+/// enum Instruction {
+///     #[tag = 0b0110011]
+///     R {
+///         #[bits(5)]
+///         rd: u8,
+///         #[bits(3)]
+///         funct3: u8,
+///         #[bits(5)]
+///         rs1: u8,
+///         #[bits(5)]
+///         rs2: u8,
+///         #[bits(7)]
+///         funct7: u8,
+///     },
+///     #[tag = 0b0010011]
+///     I {
+///         #[bits(5)]
+///         rd: u8,
+///         #[bits(3)]
+///         funct3: u8,
+///         #[bits(5)]
+///         rs1: u8,
+///         #[bits(12)]
+///         imm: i16,
+///     },
+/// }
+///
+/// fn main() {
+///     // add x3, x1, x2
+///     let instruction = Instruction::from_bits(0x002081B3).unwrap();
+///     assert_eq!(instruction.tag(), 0b0110011);
+///     if let Instruction::R(r) = instruction {
+///         assert_eq!(r.rd(), 3);
+///         assert_eq!(r.rs1(), 1);
+///         assert_eq!(r.rs2(), 2);
+///     }
+///
+///     // addi x1, x0, -1
+///     let mut addi = InstructionI::new();
+///     addi.set_rd(1);
+///     addi.set_imm(-1);
+///     assert_eq!(Instruction::from(addi).into_bits(), 0xFFF00093);
+///
+///     // Unknown tags are errors.
+///     assert!(Instruction::from_bits(0x0000007F).is_err());
+/// }
+/// ```
+///
 /// ### Field Constants
 ///
 /// Fields with read or write access have constants generated for their number of
 /// bits and offset in the bitfield.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -741,6 +1601,35 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Layout Assertions
+///
+/// The `assert` argument checks the offset and number of bits of fields against
+/// expected values at compile time, which catches layouts transcribed wrongly from
+/// a datasheet or fields moved when the declaration order is edited. Each
+/// assertion compares the field constants with the expected value, which can be a
+/// const expression, and a mismatch fails the compilation with an error pointing
+/// at the field of the assertion.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// const CRC_BITS: u32 = 8;
+///
+/// #[bitfield(u32, assert(mode.offset = 0, enable.offset = 7, crc.bits = CRC_BITS))]
+/// struct Register {
+///     #[bits(7)]
+///     mode: u8,
+///     enable: bool,
+///     crc: u8,
+///     #[bits(16)]
+///     _reserved: u16,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Register::ENABLE_OFFSET, 7);
+/// }
+/// ```
+///
 /// ### Field Order
 ///
 /// The order of the bitfield determines whether from top to bottom struct fields
@@ -752,7 +1641,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// bitfield struct, with the options `lsb` or `msb`.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// /// Field layout (LSB → MSB):
@@ -834,6 +1722,55 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Explicit Bit Positions
+///
+/// Fields can be placed at explicit bit positions, which makes it easy to
+/// transcribe register tables from datasheets. A bit position is either an
+/// inclusive bit range `#[bits(4..=7)]` or a start offset `#[bits(at = 12)]`,
+/// which can be combined with the bits of the field `#[bits(4, at = 12)]`.
+/// Bit positions are counted from the least significant bit, regardless of the
+/// field order.
+///
+/// Fields without a bit position follow the previous field in the field order,
+/// so fields can be declared in any order. The bits that aren't occupied by any
+/// field are implicit reserved bits, so no `_reserved` fields are needed for the
+/// gaps. Fields with overlapping bits fail to compile.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// /// Field layout (LSB → MSB):
+/// ///
+/// /// | 31  28 | 27    16 | 15     8 | 7  4 | 3      0 |
+/// /// +--------+----------+----------+------+----------+
+/// /// |  mode  | reserved | prescale | irq  | reserved |
+/// /// +--------+----------+----------+------+----------+
+/// #[bitfield(u32)]
+/// struct TimerControl {
+///     #[bits(28..=31, default = 0x3)]
+///     mode: u8,
+///     #[bits(4..=7)]
+///     irq: u8,
+///     /// Follows the `irq` field, occupying bits 8..=15.
+///     #[bits(default = 0x80)]
+///     prescale: u8,
+/// }
+///
+/// fn main() {
+///     let timer_control = TimerControl::new();
+///     assert_eq!(timer_control.mode(), 0x3);
+///     assert_eq!(timer_control.prescale(), 0x80);
+///     assert_eq!(timer_control.into_bits(), 0x3000_8000);
+///     assert_eq!(TimerControl::MODE_OFFSET, 28);
+///     assert_eq!(TimerControl::PRESCALE_OFFSET, 8);
+///
+///     // The implicit reserved bits are kept as is.
+///     let timer_control = TimerControl::from_bits(0xFFFF_FFFF);
+///     assert_eq!(timer_control.irq(), 0xF);
+///     assert_eq!(timer_control.into_bits(), 0xFFFF_FFFF);
+/// }
+/// ```
+///
 /// ### Bitfield Conversions
 ///
 /// A bitfield can be converted into and from bits using multiple functions.
@@ -854,7 +1791,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `from_be_bits_with_defaults(bits: N)` | Big-endian                                   | Creates a new bitfield instance from the given big-endian bits, while respecting/applying field defaults.    |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -938,7 +1874,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `checked_from_be_slice_with_defaults(slice: &[u8]) -> Result<Self, &str>` | Big-endian                                   | Creates a new bitfield instance from a big-endian byte slice, while respecting/applying field defaults. Returns an error if the slice is too small.    |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 4])]
@@ -1001,7 +1936,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `into_be_bits() -> N` | Big-endian                                   | Returns the bits of the bitfield in big-endian order.                  |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -1045,7 +1979,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `checked_into_be_slice(slice: &mut [u8]) -> Result<(), &str>` | Big-endian                                   | Writes the bitfield bytes in big-endian order into the provided slice. Returns an error if the slice is too small.    |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 4])]
@@ -1104,7 +2037,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// conversions.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32, from_endian = little, into_endian = big)]
@@ -1142,7 +2074,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// ```
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 4], from_endian = little, into_endian = big)]
@@ -1179,6 +2110,37 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// #### Field Endian
+///
+/// Mixed-endian formats can set the byte order of a single multi-byte integer field
+/// with the `#[bits(endian = N)]` arg, where `N` is `little` or `big`. The field is
+/// read and written in that byte order within the bits or bytes the bitfield is
+/// converted from, no matter the `from_endian` of the bitfield, by the getter,
+/// setter, builder, default value and `Debug` output of the field. The field must
+/// have a multiple of 8 bits.
+///
+/// ```rust
+/// use bitfields::bitfield;
+///
+/// /// A big-endian header with a little-endian vendor field.
+/// #[bitfield([u8; 6])]
+/// struct Header {
+///     #[bits(16)]
+///     length: u16,
+///     #[bits(32, endian = little)]
+///     vendor: u32,
+/// }
+///
+/// fn main() {
+///     let header = Header::from_bytes([0x11, 0x22, 0x33, 0x44, 0xAB, 0xCD]);
+///     assert_eq!(header.length(), 0xABCD);
+///     assert_eq!(header.vendor(), 0x4433_2211);
+///
+///     let header = HeaderBuilder::new().with_length(0x0102).with_vendor(0x0A0B_0C0D).build();
+///     assert_eq!(header.into_bytes(), [0x0D, 0x0C, 0x0B, 0x0A, 0x01, 0x02]);
+/// }
+/// ```
+///
 /// ### Bit Operations
 ///
 /// The bitfield generates bitwise operations that make it easy to manipulate,
@@ -1203,7 +2165,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `write_defaults()`                     | N/A                                           | Reapplies field defaults without replacing the whole bitfield. |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -1260,7 +2221,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `write_defaults()`                             | N/A                                           | Reapplies field defaults without replacing the whole bitfield.  |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -1302,7 +2262,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// `write_bytes`. The explicit helpers always use the endian in their name.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32, write_endian = little)]
@@ -1351,7 +2310,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// when invalid offsets or inaccessible fields should be treated as errors.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u8, bit_ops = true)]
@@ -1407,7 +2365,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `checked_set_bits_range(offset: u32, len: u32, value: N) -> Result<(), &str>` | Checked             | Sets a range of bits to the given shifted value. Returns an error if any bit is out of bounds or protected. |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16, bit_ops = true)]
@@ -1459,7 +2416,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `checked_set_bytes_range(offset: u32, len: u32, value: [u8; N]) -> Result<(), &str>` | Checked             | Sets a range of bits using the input array. Returns an error if any bit is out of bounds or protected.        |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16, bit_ops = true)]
@@ -1498,7 +2454,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// provided or returned array.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 2], bit_ops = true)]
@@ -1533,7 +2488,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `clear_<field>_to_default()` | Restores the specific writable field's default value. (Generated per field with a default) |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -1593,7 +2547,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `clear_<field>_to_default()`  | Restores the specific writable field's default value. (Generated per field with a default) |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 4])]
@@ -1649,7 +2602,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// | `<field>_inverted() -> T` | Returns the field value with its bits inverted, without mutating the bitfield. (Generated per readable field) |
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16, bit_ops = true)]
@@ -1696,7 +2648,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// field value `0b01100` returns `0b10011`, not an 8-bit `0b11110011`.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 2], bit_ops = true)]
@@ -1744,7 +2695,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// Attributes below the `#[bitfield]` attribute are passed to the generated struct.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -1767,7 +2717,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// Take note that using ignored fields removes some constant guarantees.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -1802,7 +2751,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// generated struct and field accessors.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -1812,6 +2760,55 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
+/// ### Generic Bitfields
+///
+/// Bitfields can have type and const parameters, along with a where clause, which
+/// are carried onto the generated struct, builder and trait implementations. Type
+/// parameters are held by a `PhantomData`, so they can be used as markers, such as
+/// typed registers. A field whose type is a type parameter must define its bits.
+///
+/// ```rust
+/// use bitfields::{bitfield, bitflag, BitfieldField};
+///
+/// struct Rx;
+/// struct Tx;
+///
+/// #[bitfield(u8)]
+/// struct Status<Direction> {
+///     ready: bool,
+///     #[bits(7)]
+///     count: u8,
+/// }
+///
+/// #[bitflag(u8)]
+/// #[derive(Debug, PartialEq)]
+/// enum DescriptorKind {
+///     #[base]
+///     Data = 0,
+///     Code = 1,
+/// }
+///
+/// #[bitfield(u8)]
+/// struct Descriptor<T: BitfieldField<Raw = u8>, const VERSION: u8> {
+///     #[bits(4)]
+///     kind: T,
+///     #[bits(4, default = VERSION)]
+///     version: u8,
+/// }
+///
+/// fn main() {
+///     let rx_status = Status::<Rx>::new();
+///     let tx_status: Status<Tx> = StatusBuilder::new().with_ready(true).build();
+///     assert!(!rx_status.ready());
+///     assert!(tx_status.ready());
+///
+///     let mut descriptor = Descriptor::<DescriptorKind, 2>::new();
+///     descriptor.set_kind(DescriptorKind::Code);
+///     assert_eq!(descriptor.kind(), DescriptorKind::Code);
+///     assert_eq!(descriptor.into_bits(), 0x21);
+/// }
+/// ```
+///
 /// ### Default Implementations
 ///
 /// #### Debug Trait
@@ -1820,7 +2817,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// and their values.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u32)]
@@ -1849,7 +2845,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// `#[bitfield(default = false)]` on the bitfield.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -1874,7 +2869,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// `#[bitfield(from_traits = false)]` on the bitfield.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)] /// From<u16> and Into<u16> are generated for this bitfield.
@@ -1901,7 +2895,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// ```
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield([u8; 2]
@@ -1937,7 +2930,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// bitfield.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(u16)]
@@ -1974,7 +2966,6 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// override Cargo feature defaults.
 ///
 /// ```rust,ignore
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 ///
 /// #[bitfield(
@@ -2003,30 +2994,32 @@ const INTERNAL_ERROR_MESSAGE: &str = "A major unexpected error has occurred. If
 /// }
 /// ```
 ///
-/// | Argument          | Values                                          | Default  | Description                                                                                                                                                                                                                                                                                                      |
-/// |-------------------|-------------------------------------------------|----------|------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
-/// | `<backing type>`  | `u8`, `u16`, `u32`, `u64`, `u128`, or `[u8; N]` | Required | The storage used by the generated bitfield. Primitive backing types support bitfields up to 128 bits. `[u8; N]` creates an array-backed bitfield for larger layouts. Field bit widths, excluding ignored fields, must add up exactly to the backing storage size.                                                |
-/// | `order`           | `lsb`, `msb`                                    | `lsb`    | Controls how struct fields are assigned to bit offsets. `lsb` assigns the first non-ignored field to the least-significant bits. `msb` assigns the first non-ignored field to the most-significant bits.                                                                                                         |
-/// | `from_endian`     | `big`, `little`                                 | `big`    | Default endian used by `from_bits`, `from_bytes`, `from_slice`, and `From<Backing> for Bitfield`. Explicit helpers such as `from_le_bits` and `from_be_bytes` ignore this setting.                                                                                                                               |
-/// | `into_endian`     | `big`, `little`                                 | `big`    | Default endian used by `into_bits`, `into_bytes`, `into_slice`, and `From<Bitfield> for Backing`. Explicit helpers such as `into_le_bits` and `into_be_bytes` ignore this setting.                                                                                                                               |
-/// | `write_endian`    | `big`, `little`                                 | `big`    | Default endian used by whole-bitfield write helpers such as `write_bits` and `write_bytes`. Explicit helpers such as `write_le_bits` and `write_be_bytes` ignore this setting.                                                                                                                                   |
-/// | `new`             | `true`, `false`                                 | `true`   | Generates `new()` and `new_without_defaults()` constructors. Other generated features that need construction logic, such as `Default` and the builder, still inline equivalent initialization logic when this is disabled.                                                                                       |
-/// | `from_into_bits`  | `true`, `false`                                 | `true`   | Generates backing-data conversion functions. Primitive bitfields get `from_bits`, `from_bits_with_defaults`, endian-specific `from_*_bits` helpers, `into_bits`, and endian-specific `into_*_bits` helpers. Array-backed bitfields get the corresponding `bytes` and `slice` APIs.                               |
-/// | `from_traits`     | `true`, `false`                                 | `true`   | Generates `From<Backing> for Bitfield` and `From<Bitfield> for Backing`. These conversions use `from_endian` and `into_endian`.                                                                                                                                                                                  |
-/// | `default`         | `true`, `false`                                 | `true`   | Generates `Default` for the bitfield. The default value is equivalent to `new()`: zero-initialized storage with field defaults applied.                                                                                                                                                                          |
-/// | `debug`           | `true`, `false`                                 | `true`   | Generates `core::fmt::Debug` for the bitfield. The implementation prints readable fields and their values.                                                                                                                                                                                                       |
-/// | `copy`            | `true`, `false`                                 | `true`   | Derives `Copy` and `Clone` for primitive and stack array-backed bitfields. Heap array-backed bitfields derive `Clone` only because `Box<[u8; N]>` is not `Copy`.                                                                                                                                                 |
-/// | `builder`         | `true`, `false`                                 | `true`   | Generates the `<Bitfield>Builder` type, `new`, `new_without_defaults`, `with_<field>`, `checked_with_<field>`, and `build`. Reserved fields do not get builder setters.                                                                                                                                          |
-/// | `bit_ops`         | `true`, `false`                                 | `true`   | Master switch for bit operation groups. When `false`, all bit operation groups are disabled unless a specific bit operation group is explicitly set to `true`.                                                                                                                                                   |
-/// | `write_bit_ops`   | `true`, `false`                                 | `true`   | Generates whole-bitfield write helpers such as `write_bits`, `write_bits_with_defaults`, `write_le_bits`, `write_be_bits`, and `write_defaults` for primitive bitfields, or the corresponding `bytes` helpers for array-backed bitfields.                                                                        |
-/// | `clear_bit_ops`   | `true`, `false`                                 | `true`   | Generates whole-bitfield clear helpers such as `clear_bits` / `clear_bytes`, `clear_bits_with_defaults` / `clear_bytes_with_defaults`, plus per-field helpers like `clear_<field>()` and `clear_<field>_to_default()`.                                                                                           |
-/// | `set_get_bit_ops` | `true`, `false`                                 | `true`   | Generates individual bit helpers (`get_bit`, `checked_get_bit`, `set_bit`, `checked_set_bit`) and range helpers (`get_bits_range` / `set_bits_range` for primitive bitfields, `get_bytes_range` / `set_bytes_range` for array-backed bitfields, plus checked variants).                                          |
-/// | `invert_bit_ops`  | `true`, `false`                                 | `true`   | Generates inversion helpers such as `invert_bits` / `invert_bytes`, per-field `invert_<field>()`, and readable-field `<field>_inverted()` getters.                                                                                                                                                               |
-/// | `toggle_bit_ops`  | `true`, `false`                                 | `true`   | Accepted as a bit-operation group flag for configuration compatibility. In this version, there are no separate `toggle_*` APIs; use the generated invert helpers to toggle bits.                                                                                                                                 |
-/// | `array_heap`      | `true`, `false`                                 | `false`  | For array-backed bitfields only, stores the backing `[u8; N]` in a `Box` instead of inline in the struct. This helps avoid large stack values but requires heap allocation and therefore gives up the zero-allocation and `no_std` guarantees for that bitfield. It has no effect on primitive-backed bitfields. |
-///
-/// ```rust
-/// # use bitfields_impl as bitfields;
+/// | Argument          | Values                                                                       | Default  | Description                                                                                                                                                                                                                                                                                                                                           |
+/// |-------------------|------------------------------------------------------------------------------|----------|-------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+/// | `<backing type>`  | `u8`..`u128`, `i8`..`i128`, or `[u8; N]`, `[u16; N]`, `[u32; N]`, `[u64; N]` | Required | The storage used by the generated bitfield. Primitive backing types support bitfields up to 128 bits. `[u8; N]` creates an array-backed bitfield for larger layouts, and word arrays add word APIs. Signed types are held by their unsigned counterpart. Field bit widths, excluding ignored fields, must add up exactly to the backing storage size. |
+/// | `order`           | `lsb`, `msb`                                                                 | `lsb`    | Controls how struct fields are assigned to bit offsets. `lsb` assigns the first non-ignored field to the least-significant bits. `msb` assigns the first non-ignored field to the most-significant bits.                                                                                                                                              |
+/// | `from_endian`     | `big`, `little`                                                              | `big`    | Default endian used by `from_bits`, `from_bytes`, `from_slice`, and `From<Backing> for Bitfield`. Explicit helpers such as `from_le_bits` and `from_be_bytes` ignore this setting.                                                                                                                                                                    |
+/// | `into_endian`     | `big`, `little`                                                              | `big`    | Default endian used by `into_bits`, `into_bytes`, `into_slice`, and `From<Bitfield> for Backing`. Explicit helpers such as `into_le_bits` and `into_be_bytes` ignore this setting.                                                                                                                                                                    |
+/// | `write_endian`    | `big`, `little`                                                              | `big`    | Default endian used by whole-bitfield write helpers such as `write_bits` and `write_bytes`. Explicit helpers such as `write_le_bits` and `write_be_bytes` ignore this setting.                                                                                                                                                                        |
+/// | `new`             | `true`, `false`                                                              | `true`   | Generates `new()` and `new_without_defaults()` constructors. Other generated features that need construction logic, such as `Default` and the builder, still inline equivalent initialization logic when this is disabled.                                                                                                                            |
+/// | `from_into_bits`  | `true`, `false`                                                              | `true`   | Generates backing-data conversion functions. Primitive bitfields get `from_bits`, `from_bits_with_defaults`, endian-specific `from_*_bits` helpers, `into_bits`, and endian-specific `into_*_bits` helpers. Array-backed bitfields get the corresponding `bytes` and `slice` APIs.                                                                    |
+/// | `from_traits`     | `true`, `false`                                                              | `true`   | Generates `From<Backing> for Bitfield` and `From<Bitfield> for Backing`. These conversions use `from_endian` and `into_endian`.                                                                                                                                                                                                                       |
+/// | `default`         | `true`, `false`                                                              | `true`   | Generates `Default` for the bitfield. The default value is equivalent to `new()`: zero-initialized storage with field defaults applied.                                                                                                                                                                                                               |
+/// | `debug`           | `true`, `false`                                                              | `true`   | Generates `core::fmt::Debug` for the bitfield. The implementation prints readable fields and their values.                                                                                                                                                                                                                                            |
+/// | `copy`            | `true`, `false`                                                              | `true`   | Derives `Copy` and `Clone` for primitive and stack array-backed bitfields. Heap array-backed bitfields derive `Clone` only because `Box<[u8; N]>` is not `Copy`.                                                                                                                                                                                      |
+/// | `builder`         | `true`, `false`                                                              | `true`   | Generates the `<Bitfield>Builder` type, `new`, `new_without_defaults`, `with_<field>`, `checked_with_<field>`, and `build`. Reserved fields do not get builder setters.                                                                                                                                                                               |
+/// | `bit_ops`         | `true`, `false`                                                              | `true`   | Master switch for bit operation groups. When `false`, all bit operation groups are disabled unless a specific bit operation group is explicitly set to `true`.                                                                                                                                                                                        |
+/// | `write_bit_ops`   | `true`, `false`                                                              | `true`   | Generates whole-bitfield write helpers such as `write_bits`, `write_bits_with_defaults`, `write_le_bits`, `write_be_bits`, and `write_defaults` for primitive bitfields, or the corresponding `bytes` helpers for array-backed bitfields.                                                                                                             |
+/// | `clear_bit_ops`   | `true`, `false`                                                              | `true`   | Generates whole-bitfield clear helpers such as `clear_bits` / `clear_bytes`, `clear_bits_with_defaults` / `clear_bytes_with_defaults`, plus per-field helpers like `clear_<field>()` and `clear_<field>_to_default()`.                                                                                                                                |
+/// | `set_get_bit_ops` | `true`, `false`                                                              | `true`   | Generates individual bit helpers (`get_bit`, `checked_get_bit`, `set_bit`, `checked_set_bit`) and range helpers (`get_bits_range` / `set_bits_range` for primitive bitfields, `get_bytes_range` / `set_bytes_range` for array-backed bitfields, plus checked variants).                                                                               |
+/// | `invert_bit_ops`  | `true`, `false`                                                              | `true`   | Generates inversion helpers such as `invert_bits` / `invert_bytes`, per-field `invert_<field>()`, and readable-field `<field>_inverted()` getters.                                                                                                                                                                                                    |
+/// | `toggle_bit_ops`  | `true`, `false`                                                              | `true`   | Accepted as a bit-operation group flag for configuration compatibility. In this version, there are no separate `toggle_*` APIs; use the generated invert helpers to toggle bits.                                                                                                                                                                      |
+/// | `array_heap`      | `true`, `false`                                                              | `false`  | For array-backed bitfields only, stores the backing `[u8; N]` in a `Box` instead of inline in the struct. This helps avoid large stack values but requires heap allocation and therefore gives up the zero-allocation and `no_std` guarantees for that bitfield. It has no effect on primitive-backed bitfields.                                      |
+/// | `fill`            | `reserved`, `reserved_ones`                                                  | unset    | Fills the bits that aren't occupied by any field with implicit reserved fields, instead of requiring the field bits to add up to the backing storage size. `reserved` fills them with zeros and `reserved_ones` with ones by default.                                                                                                                 |
+/// | `overflow`        | `truncate`, `saturate`, `panic`, `debug_assert`                              | `truncate`| Controls how setters, element setters and builder methods handle values that overflow the field bits. Can be overridden per field with `#[bits(overflow = ..)]`. See [Overflow Policy](#overflow-policy).                                                                                                                                             |
+/// | `assert`          | `(a.offset = 0, b.bits = 8)`                                                 | unset    | Asserts the offset and number of bits of fields at compile time against expected values, which can be const expressions. A mismatch fails the compilation with an error pointing at the field.                                                                                                                                                        |
+///
+/// ```rust
 /// use bitfields::bitfield;
 ///
 /// // Demonstrates a selection of configuration arguments.
@@ -2149,6 +3142,10 @@ fn start_bitfield_generation(
     args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    if is_tagged_union_tokens(&input) {
+        return start_tagged_union_generation(args, input);
+    }
+
     let parsed_bitfield = match parse_bitfield_struct(args.into(), input.into()) {
         Ok(bitfield) => bitfield,
         Err(err) => return err.into_compile_error().into(),
@@ -2157,6 +3154,24 @@ fn start_bitfield_generation(
     generate_bitfield(&parsed_bitfield).into()
 }
 
+/// Entry for starting the tagged-union bitfield generation.
+fn start_tagged_union_generation(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let parsed_tagged_union = match parse_tagged_union_enum(args.into(), input.into()) {
+        Ok(tagged_union) => tagged_union,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    generate_tagged_union(&parsed_tagged_union).into()
+}
+
+/// Returns if the annotated item is a tagged-union bitfield enum.
+fn is_tagged_union_tokens(input: &proc_macro::TokenStream) -> bool {
+    syn::parse::<syn::ItemEnum>(input.clone())
+        .is_ok_and(|enum_tokens| is_tagged_union_enum(&enum_tokens))
+}
+
 /// Forces a panic if argument is enabled.
 fn check_force_panic(bitfield: &Bitfield) {
     assert!(!bitfield.arguments().force_panic(), "Forced panic for testing purposes");
@@ -2169,14 +3184,14 @@ fn check_force_panic(bitfield: &Bitfield) {
 /// There are times when you just want to define a bitflag, which are just enums
 /// that map to bits. Instead of defining a custom type, you can take advantage of
 /// the `#[bitflag]` attribute which generates `from_bits` and `into_bits` for enums
-/// automatically.
+/// automatically and implements the `BitfieldField` trait so the enum can be used as
+/// a bitfield field.
 ///
 /// Bitflags only supports unsigned types (`u8`, `u16`, `u32`, `u64`, `u128`) and
 /// the one of the variants must be annotated with `#[base]` or `#[default]` which represents the
 /// base value of the bitflag. If `#[base]` and `#[default]` are both present, `#[base]` takes precedence.
 ///
 /// ```rust
-/// # use bitfields_impl as bitfields;
 /// use bitfields::bitfield;
 /// use bitfields::bitflag;
 ///
@@ -2213,6 +3228,19 @@ fn check_force_panic(bitfield: &Bitfield) {
 /// //         self as u8
 /// //     }
 /// // }
+/// //
+/// // impl BitfieldField for RenderMode {
+/// //     const BITS: u32 = 2;
+/// //     type Raw = u8;
+/// //
+/// //     fn from_raw(bits: u8) -> Self {
+/// //         Self::from_bits(bits)
+/// //     }
+/// //
+/// //     fn into_raw(self) -> u8 {
+/// //         self.into_bits()
+/// //     }
+/// // }
 ///
 /// /// Annotate an enum with the `#[bitflag]` attribute to automatically generate
 /// /// the `from_bits` and `into_bits` functions for you! One variant must be annotated
@@ -2229,14 +3257,17 @@ fn check_force_panic(bitfield: &Bitfield) {
 ///
 /// #[bitfield(u8)]
 /// struct DisplayControl {
-///     /// Must have the `#[bits]` attribute since the macro has 
-///     /// no way of knowing how many bits the custom type occupies.
+///     /// The bits can be specified, as long as they fit the bitflag.
 ///     #[bits(4, default = RenderMode::Normal)]
 ///     render_mode: RenderMode,
-///     /// Must have the `#[bits]` attribute since the macro has 
-///     /// no way of knowing how many bits the custom type occupies.
-///     #[bits(4, default = AudioMode::Stereo)]
+///     /// Without the bits, the field occupies the bits needed for the
+///     /// largest variant of the bitflag, which is 2 bits here. Adding a
+///     /// larger variant widens the field, so specify the bits when the
+///     /// layout must stay fixed.
+///     #[bits(default = AudioMode::Stereo)]
 ///     audio_mode: AudioMode,
+///     #[bits(2)]
+///     _reserved: u8,
 /// }
 ///
 /// fn main() {
@@ -1,10 +1,21 @@
 use getset::{CloneGetters, Getters};
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{ToTokens, quote};
 
 use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitfieldArguments;
-use crate::parsing::bitfields::bits_attribute::bits_arguments::{BitsArguments, FieldAccess};
-use crate::parsing::common::spanned_data_type::{DataType, SpannedDataTypeToken};
+use crate::parsing::bitfields::bitfield_attribute::layout_assertion::LayoutAssertion;
+use crate::parsing::bitfields::bits_attribute::bits_arguments::{
+    BitsArguments, FieldAccess, FieldConstraint, FieldEncoding, FieldEnumeratedValue, FieldScaling,
+    FieldSegment,
+};
+use crate::parsing::common::bits_expr::BitsExpr;
+use crate::parsing::common::conversion_endian::ConversionEndian;
+use crate::parsing::common::generic_parameters::GenericParameters;
+use crate::parsing::common::overflow_policy::OverflowPolicy;
+use crate::parsing::common::spanned_data_type::{
+    ArrayElementType, DataType, IntegerType, SpannedDataTypeToken,
+};
+use crate::parsing::common::to_tokens::ToTokens as DataTypeToTokens;
 use crate::parsing::common::visibility::Visibility;
 
 /// Represents the annotated struct that is the source of the bitfield
@@ -24,9 +35,17 @@ pub struct Bitfield {
     /// The ident of the bitfield
     name_ident: Ident,
 
-    /// The type of the bitfield.
+    /// The generic parameters of the bitfield.
+    #[getset(skip)]
+    generic_parameters: GenericParameters,
+
+    /// The type holding the bits of the bitfield.
     spanned_data_type_token: SpannedDataTypeToken,
 
+    /// The type of the bitfield declared in the attribute, which differs from
+    /// the type holding the bits for signed integer and word array types.
+    declared_data_type_token: SpannedDataTypeToken,
+
     /// The fields of the bitfield.
     fields: Vec<Field>,
 
@@ -35,18 +54,25 @@ pub struct Bitfield {
 
     /// The arguments of the bitfield.
     arguments: BitfieldArguments,
+
+    /// The layout assertions declared on the bitfield.
+    layout_assertions: Vec<LayoutAssertion>,
 }
 
 impl Bitfield {
     /// Creates a new [`Bitfield`] instance.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_attributes_tokens: Vec<TokenStream>,
         visibility: Visibility,
         name_ident: Ident,
+        generic_parameters: GenericParameters,
         spanned_data_type_token: SpannedDataTypeToken,
+        declared_data_type_token: SpannedDataTypeToken,
         fields: Vec<Field>,
         ignored_fields: Vec<Field>,
         arguments: BitfieldArguments,
+        layout_assertions: Vec<LayoutAssertion>,
     ) -> Self {
         let name = name_ident.to_string();
         Self {
@@ -54,10 +80,13 @@ impl Bitfield {
             visibility,
             name,
             name_ident,
+            generic_parameters,
             spanned_data_type_token,
+            declared_data_type_token,
             fields,
             ignored_fields,
             arguments,
+            layout_assertions,
         }
     }
 
@@ -66,14 +95,69 @@ impl Bitfield {
         !self.ignored_fields.is_empty()
     }
 
+    /// Returns if the bitfield has fields that can't be converted from const
+    /// functions, see [`Self::is_non_const_field`].
+    pub fn has_non_const_fields(&self) -> bool {
+        self.fields.iter().any(|field| self.is_non_const_field(field))
+    }
+
+    /// Returns if the field can't be converted from const functions: fields
+    /// converted through the `BitfieldField` trait, whose methods aren't const,
+    /// and packed arrays of custom elements, which are converted through
+    /// iterators.
+    ///
+    /// Fields of a type parameter dependent custom type are converted through
+    /// the trait bounds of the parameter.
+    pub fn is_non_const_field(&self, field: &Field) -> bool {
+        let field_type_tokens = DataTypeToTokens::to_tokens(&field.spanned_data_type_token());
+        field.converts_through_trait()
+            || (field.is_packed_array() && field.has_custom_data_type())
+            || (field.has_custom_data_type()
+                && self.generic_parameters.is_type_parameter_dependent(&field_type_tokens))
+    }
+
     /// Returns the name as tokens.
     pub fn name_tokens(&self) -> TokenStream {
         self.name_ident.to_token_stream()
     }
 
+    /// Returns the generic parameters.
+    pub const fn generic_parameters(&self) -> &GenericParameters {
+        &self.generic_parameters
+    }
+
+    /// Returns the type as tokens, which is the name with its generic
+    /// parameters (e.g. `Bitfield<T, N>`).
+    pub fn type_tokens(&self) -> TokenStream {
+        let name_tokens = self.name_tokens();
+        let type_generics_tokens = self.generic_parameters.type_generics_tokens();
+        quote! { #name_tokens #type_generics_tokens }
+    }
+
     pub const fn is_integer_backed(&self) -> bool {
         matches!(self.spanned_data_type_token.data_type(), DataType::Integer(_))
     }
+
+    /// Returns if the declared type is a signed integer, whose bits are held
+    /// by its unsigned counterpart.
+    pub const fn is_signed_integer_backed(&self) -> bool {
+        matches!(
+            self.declared_data_type_token.data_type(),
+            DataType::Integer(integer_type) if !integer_type.is_unsigned()
+        )
+    }
+
+    /// Returns the element type of a word array declared type (e.g. `u32` for
+    /// `[u32; 4]`), whose bits are held by a byte array, or `None` for other
+    /// types.
+    pub fn word_type(&self) -> Option<IntegerType> {
+        match self.declared_data_type_token.data_type() {
+            DataType::Array {
+                element_type: ArrayElementType::Integer(integer_type), ..
+            } if integer_type != IntegerType::U8 => Some(integer_type),
+            _ => None,
+        }
+    }
 }
 
 /// Represents a bitfield field.
@@ -95,10 +179,10 @@ pub struct Field {
     spanned_data_type_token: SpannedDataTypeToken,
 
     /// The bits of the field.
-    bits: u32,
+    bits: BitsExpr,
 
     /// The offset of the field.
-    offset: u32,
+    offset: BitsExpr,
 
     /// Whether the field is reserved.
     reserved: bool,
@@ -120,8 +204,8 @@ impl Field {
         visibility: Visibility,
         name_ident: Ident,
         spanned_data_type_token: SpannedDataTypeToken,
-        bits: u32,
-        offset: u32,
+        bits: BitsExpr,
+        offset: BitsExpr,
         reserved: bool,
         access: FieldAccess,
         arguments: Option<BitsArguments>,
@@ -153,6 +237,28 @@ impl Field {
         self.visibility.clone()
     }
 
+    /// Returns the visibility of the getters of the field, which is the field
+    /// visibility unless overridden by the `get` argument.
+    pub fn getter_visibility(&self) -> Visibility {
+        self.explicit_getter_visibility().unwrap_or_else(|| self.visibility())
+    }
+
+    /// Returns the visibility of the setters and builder methods of the field,
+    /// which is the field visibility unless overridden by the `set` argument.
+    pub fn setter_visibility(&self) -> Visibility {
+        self.explicit_setter_visibility().unwrap_or_else(|| self.visibility())
+    }
+
+    /// Returns the visibility set by the `get` argument, if any.
+    pub fn explicit_getter_visibility(&self) -> Option<Visibility> {
+        self.arguments.as_ref().and_then(BitsArguments::getter_visibility)
+    }
+
+    /// Returns the visibility set by the `set` argument, if any.
+    pub fn explicit_setter_visibility(&self) -> Option<Visibility> {
+        self.arguments.as_ref().and_then(BitsArguments::setter_visibility)
+    }
+
     /// Returns the name as tokens.
     pub fn name_tokens(&self) -> TokenStream {
         self.name_ident.to_token_stream()
@@ -163,9 +269,35 @@ impl Field {
         &self.name_ident
     }
 
-    /// Returns if the field has a default value.
+    /// Returns if the field has a default value, either declared, the
+    /// implicit logical zero of an encoded field or the implicit `None` of an
+    /// `Option` field.
     pub fn has_default_value(&self) -> bool {
         self.arguments.as_ref().is_some_and(|arguments| arguments.default_value_expr().is_some())
+            || self.has_implicit_encoded_default_value()
+            || self.has_implicit_none_default_value()
+    }
+
+    /// Returns if the `Option` field defaults to `None` without a declared
+    /// default, as its `none` value isn't necessarily zeroed bits.
+    pub fn has_implicit_none_default_value(&self) -> bool {
+        !self.reserved
+            && self
+                .arguments
+                .as_ref()
+                .is_some_and(|arguments| arguments.default_value_expr().is_none())
+            && self.none_value().is_some()
+    }
+
+    /// Returns if the field defaults to the logical zero (or `false`) without
+    /// a declared default, as its encoding doesn't store it as zeroed bits.
+    pub fn has_implicit_encoded_default_value(&self) -> bool {
+        !self.reserved
+            && self
+                .arguments
+                .as_ref()
+                .is_some_and(|arguments| arguments.default_value_expr().is_none())
+            && self.encoding().is_some_and(|encoding| !encoding.stores_zero_as_zeroed_bits())
     }
 
     /// Returns if the field has a setter generated.
@@ -194,12 +326,230 @@ impl Field {
     }
 
     /// Returns if the field has constants generated.
-    pub const fn has_constants(&self) -> bool {
-        self.has_getter() || self.has_setter()
+    ///
+    /// Split fields have no single offset, so they have no constants.
+    pub fn has_constants(&self) -> bool {
+        (self.has_getter() || self.has_setter()) && !self.is_split()
     }
 
     /// Returns if the field is a reserved field.
     pub const fn is_reserved(&self) -> bool {
         self.reserved
     }
+
+    /// Returns if the field is an alias of the bits of another field.
+    pub fn is_alias(&self) -> bool {
+        self.arguments.as_ref().is_some_and(|arguments| arguments.alias_of().is_some())
+    }
+
+    /// Returns the segments of a split field, from the most significant
+    /// segment.
+    pub fn segments(&self) -> Option<Vec<FieldSegment>> {
+        self.arguments.as_ref().and_then(BitsArguments::segments)
+    }
+
+    /// Returns the number of implicit zero bits below the segments of a split
+    /// field.
+    pub fn shift(&self) -> u32 {
+        self.arguments.as_ref().and_then(BitsArguments::shift).unwrap_or_default()
+    }
+
+    /// Returns if the field is split into multiple segments.
+    pub fn is_split(&self) -> bool {
+        self.segments().is_some()
+    }
+
+    /// Returns if the field is a custom type or an array of custom types, which
+    /// are converted through the `BitfieldField` trait.
+    pub const fn has_custom_data_type(&self) -> bool {
+        matches!(
+            self.spanned_data_type_token.data_type(),
+            DataType::Custom
+                | DataType::Array {
+                    element_type: ArrayElementType::Custom,
+                    ..
+                }
+        )
+    }
+
+    /// Returns if the field converts through the `BitfieldField` trait, which
+    /// custom field types do, other than half-precision floats, the enums
+    /// generated for fields with enumerated values and the types converting
+    /// through const functions (see
+    /// [`SpannedDataTypeToken::converts_through_trait`]).
+    pub fn converts_through_trait(&self) -> bool {
+        !self.is_half_precision()
+            && !self.has_enumerated_values()
+            && self.spanned_data_type_token.converts_through_trait()
+    }
+
+    /// Returns the `range` or `values` constraint of the field, if any.
+    pub fn constraint(&self) -> Option<FieldConstraint> {
+        self.arguments.as_ref().and_then(|arguments| arguments.constraint().clone())
+    }
+
+    /// Returns the scaling of a fixed-point or linearly scaled field.
+    pub fn scaling(&self) -> Option<FieldScaling> {
+        self.arguments.as_ref().and_then(BitsArguments::scaling)
+    }
+
+    /// Returns if the field is a fixed-point or linearly scaled field, whose
+    /// raw accessors have a `_raw` suffix.
+    pub fn is_scaled(&self) -> bool {
+        self.scaling().is_some()
+    }
+
+    /// Returns the name of the accessors of the raw value of the field.
+    pub fn raw_accessor_name(&self) -> String {
+        if self.is_scaled() {
+            return format!("{}_raw", self.name);
+        }
+
+        self.name.clone()
+    }
+
+    /// Returns if the field is a float stored as a half-precision float.
+    pub fn is_half_precision(&self) -> bool {
+        self.arguments.as_ref().is_some_and(BitsArguments::half)
+    }
+
+    /// Returns the encoding of the field value in its bits, if it isn't stored
+    /// as is.
+    pub fn encoding(&self) -> Option<FieldEncoding> {
+        self.arguments.as_ref().and_then(BitsArguments::encoding)
+    }
+
+    /// Returns the byte order of the field, if it isn't the byte order of the
+    /// bitfield.
+    pub fn endian(&self) -> Option<ConversionEndian> {
+        self.arguments.as_ref().and_then(BitsArguments::endian)
+    }
+
+    /// Returns what the setters of the field do with values that don't fit in
+    /// the field bits, if the field overrides the bitfield.
+    pub fn overflow(&self) -> Option<OverflowPolicy> {
+        self.arguments.as_ref().and_then(BitsArguments::overflow)
+    }
+
+    /// Returns if the field is a non-zero unsigned integer (e.g. `NonZeroU8`).
+    pub const fn is_non_zero(&self) -> bool {
+        self.spanned_data_type_token.non_zero_bits().is_some()
+    }
+
+    /// Returns if the field is an arbitrary-width integer (e.g. `u4`), which
+    /// holds values of exactly the bits of the field.
+    pub const fn is_arbitrary_int(&self) -> bool {
+        self.spanned_data_type_token.arbitrary_int().is_some()
+    }
+
+    /// Returns the named values of a field with enumerated values, which are
+    /// the variants of the enum generated for the field.
+    pub fn enumerated_values(&self) -> Option<Vec<FieldEnumeratedValue>> {
+        self.arguments.as_ref().and_then(BitsArguments::enumerated_values)
+    }
+
+    /// Returns if the field has enumerated values.
+    pub fn has_enumerated_values(&self) -> bool {
+        self.arguments
+            .as_ref()
+            .is_some_and(|arguments| arguments.enumerated_values_span().is_some())
+    }
+
+    /// Returns the bits representing `None` of an `Option` field.
+    pub fn none_value(&self) -> Option<u128> {
+        self.arguments.as_ref().and_then(BitsArguments::none)
+    }
+
+    /// Returns the wrapped type of an `Option` field.
+    pub fn option_inner_data_type_token(&self) -> Option<SpannedDataTypeToken> {
+        self.spanned_data_type_token.option_inner_type().and_then(Result::ok)
+    }
+
+    /// Returns if the field is a niche-typed field, a non-zero integer or an
+    /// `Option` with a `none` value.
+    ///
+    /// Not all bits of a niche-typed field convert to its type and back (e.g.
+    /// zero bits of a non-zero integer or bits without a variant of an
+    /// enumerated field), so the bits are set as is when they aren't set from a
+    /// value of the type.
+    pub fn is_niche(&self) -> bool {
+        self.is_non_zero() || self.none_value().is_some() || self.has_enumerated_values()
+    }
+
+    /// Returns the bits of each element of a packed array field.
+    ///
+    /// Arrays of integer types other than `u8` are always packed, taking the
+    /// bits of their element type unless the element bits are defined.
+    pub fn element_bits(&self) -> Option<u32> {
+        self.arguments
+            .as_ref()
+            .and_then(BitsArguments::element)
+            .or_else(|| self.spanned_data_type_token.data_type().implicit_element_bits())
+    }
+
+    /// Returns if the field is an array of packed elements.
+    pub fn is_packed_array(&self) -> bool {
+        self.element_bits().is_some()
+    }
+
+    /// Returns a reserved field for an element of a packed array field, used to
+    /// access the raw bits of the element at the index held by `index_tokens`.
+    pub fn element_field(&self, offset_tokens: &TokenStream, index_tokens: &TokenStream) -> Self {
+        let element_bits = self.element_bits().expect("Expected element bits for packed array");
+        let element_data_type_token = SpannedDataTypeToken::new(&syn::parse_quote!(u128))
+            .expect("Expected integer type for element field");
+        Self::new(
+            Visibility::Private,
+            self.name_ident.clone(),
+            element_data_type_token,
+            BitsExpr::Known(element_bits),
+            BitsExpr::Deferred(quote! {
+                #offset_tokens + (#index_tokens as u32) * #element_bits
+            }),
+            /* reserved= */ true,
+            FieldAccess::NoAccess,
+            /* arguments= */ None,
+            /* ignored= */ false,
+        )
+    }
+
+    /// Returns the offset and bits of each bit range the field occupies in the
+    /// bitfield.
+    pub fn bit_ranges(&self) -> Vec<(BitsExpr, BitsExpr)> {
+        match self.segments() {
+            Some(segments) => segments
+                .iter()
+                .map(|segment| (BitsExpr::Known(segment.offset()), BitsExpr::Known(segment.bits())))
+                .collect(),
+            None => vec![(self.offset.clone(), self.bits.clone())],
+        }
+    }
+
+    /// Returns the number of bits the field occupies in the bitfield.
+    pub fn occupied_bits(&self) -> BitsExpr {
+        self.bit_ranges().into_iter().map(|(_, bits)| bits).sum()
+    }
+
+    /// Returns a reserved field for each segment of a split field, used to
+    /// access the raw bits of the segments.
+    pub fn segment_fields(&self) -> Vec<Self> {
+        let segment_data_type_token = SpannedDataTypeToken::new(&syn::parse_quote!(u128))
+            .expect("Expected integer type for segment field");
+        self.bit_ranges()
+            .into_iter()
+            .map(|(offset, bits)| {
+                Self::new(
+                    Visibility::Private,
+                    self.name_ident.clone(),
+                    segment_data_type_token.clone(),
+                    bits,
+                    offset,
+                    /* reserved= */ true,
+                    FieldAccess::NoAccess,
+                    /* arguments= */ None,
+                    /* ignored= */ false,
+                )
+            })
+            .collect()
+    }
 }
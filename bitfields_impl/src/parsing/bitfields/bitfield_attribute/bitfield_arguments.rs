@@ -13,6 +13,7 @@ use crate::parsing::common::attribute_argument_parser::{
 };
 use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
 use crate::parsing::common::conversion_endian::{ConversionEndian, resolve_endian_feature};
+use crate::parsing::common::overflow_policy::OverflowPolicy;
 
 const ORDER_LSB_FEATURE_ENABLED: bool = cfg!(feature = "order_lsb");
 const ORDER_MSB_FEATURE_ENABLED: bool = cfg!(feature = "order_msb");
@@ -95,6 +96,33 @@ impl FromStr for BitOrder {
     }
 }
 
+/// The fill of the bits that aren't occupied by any field.
+///
+/// The bits are filled with implicit reserved fields, which are zeroed with
+/// `Reserved` and set with `ReservedOnes`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BitfieldFill {
+    /// The bits are filled with zeroed reserved fields.
+    Reserved,
+
+    /// The bits are filled with reserved fields set to ones.
+    ReservedOnes,
+}
+
+impl FromStr for BitfieldFill {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "reserved" => Ok(Self::Reserved),
+            "reserved_ones" => Ok(Self::ReservedOnes),
+            _ => Err(format!(
+                "Invalid fill argument '{s}'. Valid values are 'reserved' or 'reserved_ones'."
+            )),
+        }
+    }
+}
+
 /// Parsed arguments for the bitfield attribute.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, CopyGetters)]
 #[getset(get_copy = "pub")]
@@ -171,6 +199,19 @@ pub struct BitfieldArguments {
     /// effect on integer-backed bitfields.
     array_heap: bool,
 
+    /// The bits of the tag selecting the variant of a tagged-union bitfield.
+    ///
+    /// Only supported on enums.
+    tag_bits: Option<u32>,
+
+    /// The fill of the bits that aren't occupied by any field, which are
+    /// otherwise an error unless the fields have explicit bit positions.
+    fill: Option<BitfieldFill>,
+
+    /// What the setters do with values that don't fit in the field bits,
+    /// unless a field overrides it.
+    overflow: OverflowPolicy,
+
     /// Whether to force a panic during macro generation.
     force_panic: bool,
 }
@@ -252,6 +293,9 @@ impl Default for BitfieldArguments {
                 DISABLE_BUILDER_FEATURE_ENABLED,
             ),
             array_heap: ENABLE_ARRAY_HEAP_FEATURE_ENABLED && !DISABLE_ARRAY_HEAP_FEATURE_ENABLED,
+            tag_bits: None,
+            fill: None,
+            overflow: OverflowPolicy::Truncate,
             user_set_generate_write_bit_ops: false,
             force_panic: false,
         }
@@ -336,6 +380,18 @@ enum BitfieldArgumentKey {
     #[strum(serialize = "array_heap")]
     ArrayHeap,
 
+    #[strum(serialize = "tag_bits")]
+    TagBits,
+
+    #[strum(serialize = "fill")]
+    Fill,
+
+    #[strum(serialize = "assert")]
+    Assert,
+
+    #[strum(serialize = "overflow")]
+    Overflow,
+
     #[strum(serialize = "force_panic")]
     ForcePanic,
 }
@@ -433,6 +489,46 @@ impl Parse for BitfieldArguments {
                 BitfieldArgumentKey::ArrayHeap => {
                     bitfield_arguments.array_heap = parse_boolean_attribute_argument(argument)?;
                 },
+                BitfieldArgumentKey::TagBits => {
+                    let tag_bits = argument
+                        .value()
+                        .token()
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|tag_bits| *tag_bits > 0)
+                        .ok_or_else(|| {
+                            create_user_parsing_compiler_error(
+                                argument.value().span(),
+                                format!(
+                                    "Invalid tag bits '{}', expected an unsigned integer greater \
+                                     than 0 without a suffix.",
+                                    argument.value().token()
+                                ),
+                            )
+                        })?;
+                    bitfield_arguments.tag_bits = Some(tag_bits);
+                },
+                BitfieldArgumentKey::Fill => {
+                    bitfield_arguments.fill =
+                        Some(BitfieldFill::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?);
+                },
+                BitfieldArgumentKey::Overflow => {
+                    bitfield_arguments.overflow =
+                        OverflowPolicy::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?;
+                },
+                BitfieldArgumentKey::Assert => {
+                    // The `assert(..)` arguments are split off before the other arguments are
+                    // parsed, so only other forms of the argument end up here.
+                    return Err(create_user_parsing_compiler_error(
+                        argument.key().span(),
+                        "The `assert` argument expects a list of layout assertions (e.g. \
+                         `assert(a.offset = 0, b.bits = 8)`).",
+                    ));
+                },
                 BitfieldArgumentKey::Copy => {
                     bitfield_arguments.derive_copy = parse_boolean_attribute_argument(argument)?;
                 },
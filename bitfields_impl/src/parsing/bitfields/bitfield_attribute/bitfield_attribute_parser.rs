@@ -1,12 +1,18 @@
 //! Module for parsing the entire `#[bitfield]` attribute.
 
 use getset::{CloneGetters, Getters};
-use proc_macro2::Span;
-use syn::parse::{Parse, ParseStream};
+use proc_macro2::{Span, TokenStream};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::parse_quote_spanned;
 
 use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitfieldArguments;
+use crate::parsing::bitfields::bitfield_attribute::layout_assertion::{
+    LayoutAssertion, split_layout_assertions,
+};
 use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
-use crate::parsing::common::spanned_data_type::{DataType, SpannedDataTypeToken};
+use crate::parsing::common::spanned_data_type::{
+    ArrayElementType, DataType, IntegerType, SpannedDataTypeToken,
+};
 use crate::parsing::common::type_parse_error::TypeParsingError;
 
 /// Represents the `#[bitfield]` attribute.
@@ -18,6 +24,9 @@ pub struct BitfieldAttribute {
 
     /// The arguments of the bitfield.
     arguments: BitfieldArguments,
+
+    /// The layout assertions of the `assert(..)` argument.
+    layout_assertions: Vec<LayoutAssertion>,
 }
 
 impl Parse for BitfieldAttribute {
@@ -25,19 +34,25 @@ impl Parse for BitfieldAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let spanned_data_type_token = Self::parse_bitfield_type(input)?;
         Self::check_supported_bitfield_type(&spanned_data_type_token)?;
-        let arguments = BitfieldArguments::parse(input)?;
+        let (layout_assertions, arguments_tokens) =
+            split_layout_assertions(input.parse::<TokenStream>()?)?;
+        let arguments = BitfieldArguments::parse.parse2(arguments_tokens)?;
         Ok(Self {
             spanned_data_type_token,
             arguments,
+            layout_assertions,
         })
     }
 }
 
 const BITFIELD_ATTRIBUTE_NON_UNSIGNED_INTEGER_FIRST_ARGUMENT_ERROR_MESSAGE: &str =
-    "The bitfield must have an unsigned integer literal as its first argument";
+    "The bitfield must have an integer or integer array type as its first argument";
 const BITFIELD_ATTRIBUTE_FLOAT_FIRST_ARGUMENT_ERROR_MESSAGE: &str =
-    "The bitfield must have an unsigned integer literal as its first argument, floats are \
+    "The bitfield must have an integer or integer array type as its first argument, floats are \
      unsupported.";
+const BITFIELD_ATTRIBUTE_NON_U8_ARRAY_ERROR_MESSAGE: &str = "The bitfield array must have an \
+                                                             unsigned `u8`, `u16`, `u32` or `u64` \
+                                                             integer type as its first argument.";
 
 impl BitfieldAttribute {
     /// Parse the bitfield type (first argument) from the attribute input.
@@ -62,16 +77,12 @@ impl BitfieldAttribute {
                 )),
                 TypeParsingError::NonIntegerArrayType => Err(create_user_parsing_compiler_error(
                     input.span(),
-                    "The bitfield array must have an unsigned `u8` integer type as its first argument.",
+                    BITFIELD_ATTRIBUTE_NON_U8_ARRAY_ERROR_MESSAGE,
                 )),
                 TypeParsingError::ZeroArrayLength => Err(create_user_parsing_compiler_error(
                     input.span(),
                     "The bitfield array length must be greater than 0.",
                 )),
-                TypeParsingError::InvalidArrayLength => Err(create_user_parsing_compiler_error(
-                    input.span(),
-                    "The bitfield array length must be a valid unsigned integer literal.",
-                )),
                 TypeParsingError::ArrayLengthTooLarge => Err(create_user_parsing_compiler_error(
                     input.span(),
                     "The bitfield array length is currently capped at 4,294,967,295, please reduce the array length.",
@@ -87,7 +98,13 @@ impl BitfieldAttribute {
         }
 
         match input.parse::<syn::Type>() {
-            Ok(ty) => SpannedDataTypeToken::new(&ty),
+            Ok(ty) => {
+                let spanned_data_type_token = SpannedDataTypeToken::new(&ty)?;
+                if spanned_data_type_token.float_bits().is_some() {
+                    return Err(TypeParsingError::UnexpectedFloat);
+                }
+                Ok(spanned_data_type_token)
+            },
             Err(err) => Err(TypeParsingError::NonType(err.to_string())),
         }
     }
@@ -97,16 +114,60 @@ impl BitfieldAttribute {
         spanned_data_type_token: &SpannedDataTypeToken,
     ) -> syn::Result<()> {
         let data_type = spanned_data_type_token.data_type();
-        if matches!(data_type, DataType::Custom) || !data_type.unsigned() {
+        if matches!(data_type, DataType::Custom) {
             return Err(Self::create_unsupported_type_compiler_error(
                 &spanned_data_type_token.to_string(),
                 spanned_data_type_token.span(),
             ));
         }
 
+        if matches!(
+            data_type,
+            DataType::Array { element_type, .. }
+                if !matches!(
+                    element_type,
+                    ArrayElementType::Integer(
+                        IntegerType::U8 | IntegerType::U16 | IntegerType::U32 | IntegerType::U64
+                    )
+                )
+        ) {
+            return Err(create_user_parsing_compiler_error(
+                spanned_data_type_token.span(),
+                BITFIELD_ATTRIBUTE_NON_U8_ARRAY_ERROR_MESSAGE,
+            ));
+        }
+
         Ok(())
     }
 
+    /// Returns the type holding the bits of the bitfield, which is the unsigned
+    /// counterpart of a signed integer type and the byte array of a word array
+    /// type (e.g. `u32` for `i32` and `[u8; 8]` for `[u32; 2]`), as the bits
+    /// are always manipulated unsigned and byte by byte.
+    pub fn storage_data_type_token(&self) -> SpannedDataTypeToken {
+        let span = self.spanned_data_type_token.span();
+        let storage_type: syn::Type = match self.spanned_data_type_token.data_type() {
+            DataType::Integer(IntegerType::I8) => parse_quote_spanned! { span => u8 },
+            DataType::Integer(IntegerType::I16) => parse_quote_spanned! { span => u16 },
+            DataType::Integer(IntegerType::I32) => parse_quote_spanned! { span => u32 },
+            DataType::Integer(IntegerType::I64) => parse_quote_spanned! { span => u64 },
+            DataType::Integer(IntegerType::I128) => parse_quote_spanned! { span => u128 },
+            DataType::Array {
+                element_type: ArrayElementType::Integer(integer_type), ..
+            } if integer_type != IntegerType::U8 => {
+                let word_bytes = (integer_type.bit_size() / 8) as usize;
+                let length_tokens = self
+                    .spanned_data_type_token
+                    .array_length_tokens()
+                    .expect("Expected length for array type");
+                parse_quote_spanned! { span => [u8; #length_tokens * #word_bytes] }
+            },
+            _ => return self.spanned_data_type_token.clone(),
+        };
+
+        SpannedDataTypeToken::new(&storage_type).expect("Expected a supported storage type")
+    }
+
     /// Create a user-facing parse compiler error for unsupported bitfield
     /// types.
     fn create_unsupported_type_compiler_error(type_string_repr: &str, span: Span) -> syn::Error {
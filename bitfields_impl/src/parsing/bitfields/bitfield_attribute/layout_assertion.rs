@@ -0,0 +1,167 @@
+//! Module for parsing the layout assertions of the `assert(..)` argument of the
+//! `#[bitfield]` attribute.
+
+use getset::CloneGetters;
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, Member, Token};
+
+use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
+use crate::parsing::common::spanned_token::SpannedToken;
+
+/// The name of the argument holding the layout assertions.
+const LAYOUT_ASSERTIONS_ARGUMENT_NAME: &str = "assert";
+
+const INVALID_LAYOUT_ASSERTION_ERROR_MESSAGE: &str = "Invalid layout assertion, expected a field \
+                                                      offset or bits and its expected value (e.g. \
+                                                      `a.offset = 0` or `a.bits = 8`).";
+
+/// The layout property of a field checked by a layout assertion.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LayoutProperty {
+    /// The offset of the field in the bitfield.
+    Offset,
+
+    /// The number of bits of the field.
+    Bits,
+}
+
+impl LayoutProperty {
+    /// Returns the name of the property as written in the assertion.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Offset => "offset",
+            Self::Bits => "bits",
+        }
+    }
+}
+
+/// Represents a layout assertion of a field (e.g. `a.offset = 0`).
+#[derive(Clone, Debug, CloneGetters)]
+#[getset(get_clone = "pub")]
+pub struct LayoutAssertion {
+    /// The name of the field the assertion checks.
+    field_name: SpannedToken,
+
+    /// The checked layout property of the field.
+    property: LayoutProperty,
+
+    /// The expected value of the property.
+    expected_value_tokens: TokenStream,
+}
+
+impl LayoutAssertion {
+    /// Returns the span the assertion errors point at, which is the field.
+    pub fn span(&self) -> Span {
+        self.field_name.span()
+    }
+
+    /// Parses a single layout assertion from an assignment expression.
+    fn from_expr(expr: &Expr) -> syn::Result<Self> {
+        let invalid_layout_assertion_error = || {
+            create_user_parsing_compiler_error(expr.span(), INVALID_LAYOUT_ASSERTION_ERROR_MESSAGE)
+        };
+
+        let Expr::Assign(assign_expr) = expr else {
+            return Err(invalid_layout_assertion_error());
+        };
+        let Expr::Field(field_expr) = assign_expr.left.as_ref() else {
+            return Err(invalid_layout_assertion_error());
+        };
+        let Expr::Path(field_path) = field_expr.base.as_ref() else {
+            return Err(invalid_layout_assertion_error());
+        };
+        let Some(field_ident) = field_path.path.get_ident() else {
+            return Err(invalid_layout_assertion_error());
+        };
+        let Member::Named(property_ident) = &field_expr.member else {
+            return Err(invalid_layout_assertion_error());
+        };
+
+        let property = match property_ident.to_string().as_str() {
+            "offset" => LayoutProperty::Offset,
+            "bits" => LayoutProperty::Bits,
+            property => {
+                return Err(create_user_parsing_compiler_error(
+                    property_ident.span(),
+                    format!(
+                        "Unknown layout property '{property}'. Valid properties are 'offset' or \
+                         'bits'."
+                    ),
+                ));
+            },
+        };
+
+        let expected_value_expr = &assign_expr.right;
+        Ok(Self {
+            field_name: SpannedToken::new(field_ident.to_string(), field_ident.span()),
+            property,
+            expected_value_tokens: quote! { #expected_value_expr },
+        })
+    }
+}
+
+/// Splits the `assert(..)` arguments from the other arguments of the
+/// attribute, as they aren't `key = value` arguments.
+///
+/// Returns the layout assertions and the tokens of the remaining arguments.
+pub fn split_layout_assertions(
+    input: TokenStream,
+) -> syn::Result<(Vec<LayoutAssertion>, TokenStream)> {
+    let mut layout_assertions = Vec::new();
+    let mut remaining_arguments_tokens = TokenStream::new();
+    let mut separator: Option<TokenTree> = None;
+    let mut segment: Vec<TokenTree> = Vec::new();
+    let mut tokens = input.into_iter();
+
+    loop {
+        let token = tokens.next();
+        let is_separator =
+            matches!(&token, Some(TokenTree::Punct(punct)) if punct.as_char() == ',');
+        if token.is_some() && !is_separator {
+            segment.extend(token);
+            continue;
+        }
+
+        // Drops the `assert(..)` segments together with their separator.
+        match segment.as_slice() {
+            [TokenTree::Ident(ident), TokenTree::Group(group)]
+                if ident == LAYOUT_ASSERTIONS_ARGUMENT_NAME
+                    && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                layout_assertions.extend(parse_layout_assertions(group)?);
+            },
+            _ => {
+                remaining_arguments_tokens.extend(separator.take());
+                remaining_arguments_tokens.extend(segment.drain(..));
+            },
+        }
+        segment.clear();
+        separator = token;
+
+        if separator.is_none() {
+            break;
+        }
+    }
+
+    Ok((layout_assertions, remaining_arguments_tokens))
+}
+
+/// Parses the comma separated layout assertions inside `assert(..)`.
+fn parse_layout_assertions(group: &Group) -> syn::Result<Vec<LayoutAssertion>> {
+    let exprs =
+        Punctuated::<Expr, Token![,]>::parse_terminated.parse2(group.stream()).map_err(|err| {
+            create_user_parsing_compiler_error(err.span(), INVALID_LAYOUT_ASSERTION_ERROR_MESSAGE)
+        })?;
+    if exprs.is_empty() {
+        return Err(create_user_parsing_compiler_error(
+            group.span(),
+            "Expected at least one layout assertion (e.g. `assert(a.offset = 0)`).",
+        ));
+    }
+
+    exprs.iter().map(LayoutAssertion::from_expr).collect()
+}
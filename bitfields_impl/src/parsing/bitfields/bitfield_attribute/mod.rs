@@ -1,2 +1,3 @@
 pub mod bitfield_arguments;
 pub mod bitfield_attribute_parser;
+pub mod layout_assertion;
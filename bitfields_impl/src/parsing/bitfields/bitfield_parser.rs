@@ -1,41 +1,70 @@
 use std::cmp::Ordering;
 use std::collections::HashSet;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use syn::spanned::Spanned;
 use syn::{Fields, ItemStruct, Meta};
 
 use crate::parsing::bitfields::bitfield::{Bitfield, Field};
-use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitOrder;
+use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::{BitOrder, BitfieldFill};
 use crate::parsing::bitfields::bitfield_attribute::bitfield_attribute_parser::BitfieldAttribute;
-use crate::parsing::bitfields::bits_attribute::bits_arguments::FieldAccess;
+use crate::parsing::bitfields::bits_attribute::bits_arguments::{
+    BitsArguments, FieldAccess, FieldEncoding, FieldSegment,
+};
 use crate::parsing::bitfields::bits_attribute::bits_attribute_parser::BitsAttribute;
+use crate::parsing::common::bits_expr::BitsExpr;
 use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
-use crate::parsing::common::const_expr::ConstExpr;
-use crate::parsing::common::spanned_data_type::{DataType, SpannedDataTypeToken};
+use crate::parsing::common::const_expr::{ConstExpr, evaluate_literal_arithmetic};
+use crate::parsing::common::generic_parameters::GenericParameters;
+use crate::parsing::common::overflow_policy::OverflowPolicy;
+use crate::parsing::common::spanned_data_type::{
+    ArrayElementType, DataType, IntegerType, SpannedDataTypeToken,
+};
+use crate::parsing::common::spanned_token::SpannedToken;
+use crate::parsing::common::to_tokens::ToTokens;
 use crate::parsing::common::type_parse_error::TypeParsingError;
 use crate::parsing::common::visibility::Visibility;
 
 /// Parses a bitfield struct annotated with `#[bitfield(..)]`.
 pub fn parse_bitfield_struct(args: TokenStream, input: TokenStream) -> syn::Result<Bitfield> {
     let struct_tokens = parse_struct_tokens(&input)?;
+    let bitfield_attribute = parse_bitfield_attribute(args)?;
+
+    check_tag_bits_argument(&bitfield_attribute, &struct_tokens)?;
+
+    parse_bitfield_item_struct(&bitfield_attribute, &struct_tokens)
+}
+
+/// Parses a bitfield struct with an already parsed `#[bitfield(..)]`
+/// attribute, such as the structs of the variants of a tagged-union bitfield.
+pub fn parse_bitfield_item_struct(
+    bitfield_attribute: &BitfieldAttribute,
+    struct_tokens: &ItemStruct,
+) -> syn::Result<Bitfield> {
     let user_attributes_tokens =
         struct_tokens.attrs.iter().map(quote::ToTokens::into_token_stream).collect();
     let visibility = Visibility::new(&struct_tokens.vis);
-    let bitfield_attribute = parse_bitfield_attribute(args)?;
-    let parsed_fields = parse_fields(&bitfield_attribute, &visibility, &struct_tokens)?;
+    let generic_parameters = GenericParameters::new(&struct_tokens.generics);
+    check_bitfield_array_length(bitfield_attribute, &generic_parameters)?;
+    let parsed_fields =
+        parse_fields(bitfield_attribute, &visibility, &generic_parameters, struct_tokens)?;
     let name_ident = struct_tokens.ident.clone();
 
-    check_fields_fit_in_bitfield_type(&bitfield_attribute, &parsed_fields.non_ignored)?;
+    check_generic_parameters(&struct_tokens.generics)?;
+    check_fields_fit_in_bitfield_type(bitfield_attribute, &parsed_fields.non_ignored)?;
+    check_layout_assertions(bitfield_attribute, struct_tokens, &parsed_fields.non_ignored)?;
 
     Ok(Bitfield::new(
         user_attributes_tokens,
         visibility,
         name_ident,
+        generic_parameters,
+        bitfield_attribute.storage_data_type_token(),
         bitfield_attribute.spanned_data_type_token(),
         parsed_fields.non_ignored,
         parsed_fields.ignored,
         bitfield_attribute.arguments(),
+        bitfield_attribute.layout_assertions(),
     ))
 }
 
@@ -43,10 +72,133 @@ fn parse_struct_tokens(input: &TokenStream) -> syn::Result<ItemStruct> {
     syn::parse2::<ItemStruct>(input.clone())
 }
 
-fn parse_bitfield_attribute(args: TokenStream) -> syn::Result<BitfieldAttribute> {
+/// Checks the generic parameters of the bitfield, lifetimes are not supported
+/// as the bitfield doesn't hold references.
+fn check_generic_parameters(generics: &syn::Generics) -> syn::Result<()> {
+    let Some(lifetime_param) = generics.lifetimes().next() else {
+        return Ok(());
+    };
+
+    Err(create_user_parsing_compiler_error(
+        lifetime_param.span(),
+        "Bitfields can't have lifetime parameters, only type and const parameters are supported.",
+    ))
+}
+
+/// Checks that a const array length of the bitfield type (e.g. `[u8;
+/// LENGTH]`) doesn't depend on the generic parameters of the bitfield.
+fn check_bitfield_array_length(
+    bitfield_attribute: &BitfieldAttribute,
+    generic_parameters: &GenericParameters,
+) -> syn::Result<()> {
+    let spanned_data_type_token = bitfield_attribute.spanned_data_type_token();
+    if !spanned_data_type_token.has_const_array_length() {
+        return Ok(());
+    }
+
+    // The layout is asserted outside of the bitfield, where the generic
+    // parameters can't be referred to.
+    let length_tokens =
+        spanned_data_type_token.array_length_tokens().expect("Expected length for array type");
+    if generic_parameters.is_parameter_dependent(&length_tokens) {
+        return Err(create_user_parsing_compiler_error(
+            spanned_data_type_token.span(),
+            "The array length of the bitfield can't depend on the generic parameters of the \
+             bitfield.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that fields typed by a type parameter define their bits, as the bits
+/// of the type are only known once the parameter is.
+fn check_type_parameter_dependent_field_bits(
+    generic_parameters: &GenericParameters,
+    field_tokens: &syn::Field,
+    bits: &BitsExpr,
+) -> syn::Result<()> {
+    let field_type = &field_tokens.ty;
+    if bits.known().is_some()
+        || !generic_parameters.is_type_parameter_dependent(&quote::quote! { #field_type })
+    {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        field_tokens.ty.span(),
+        "Fields with a type depending on a type parameter must define their bits.",
+    ))
+}
+
+/// Checks that the layout assertions refer to fields with a single offset and
+/// number of bits, which are asserted at compile time.
+fn check_layout_assertions(
+    bitfield_attribute: &BitfieldAttribute,
+    struct_tokens: &ItemStruct,
+    fields: &[Field],
+) -> syn::Result<()> {
+    let layout_assertions = bitfield_attribute.layout_assertions();
+    let Some(first_layout_assertion) = layout_assertions.first() else {
+        return Ok(());
+    };
+
+    // Module level assertions can't name the constants of a generic type.
+    if !struct_tokens.generics.params.is_empty() {
+        return Err(create_user_parsing_compiler_error(
+            first_layout_assertion.span(),
+            "Layout assertions are not supported on bitfields with generic parameters.",
+        ));
+    }
+
+    for layout_assertion in &layout_assertions {
+        let field_name = layout_assertion.field_name();
+        let Some(field) = fields.iter().find(|field| field.name() == field_name.token()) else {
+            return Err(create_user_parsing_compiler_error(
+                field_name.span(),
+                format!(
+                    "Unknown field '{}' in layout assertion, expected a non-ignored field of the \
+                     bitfield.",
+                    field_name.token()
+                ),
+            ));
+        };
+
+        if field.is_split() {
+            return Err(create_user_parsing_compiler_error(
+                field_name.span(),
+                format!(
+                    "The field '{}' is split into segments, which don't have a single offset to \
+                     assert.",
+                    field_name.token()
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn parse_bitfield_attribute(args: TokenStream) -> syn::Result<BitfieldAttribute> {
     syn::parse2(args)
 }
 
+/// Checks that the struct doesn't define the bits of a tag, which only
+/// tagged-union enums have.
+fn check_tag_bits_argument(
+    bitfield_attribute: &BitfieldAttribute,
+    struct_tokens: &ItemStruct,
+) -> syn::Result<()> {
+    if bitfield_attribute.arguments().tag_bits().is_none() {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        struct_tokens.ident.span(),
+        "The `tag_bits` argument is only supported on tagged-union enums.",
+    ))
+}
+
 /// Represents parsed fields.
 struct ParsedFields {
     non_ignored: Vec<Field>,
@@ -56,6 +208,7 @@ struct ParsedFields {
 fn parse_fields(
     bitfield_attribute: &BitfieldAttribute,
     bitfield_visibility: &Visibility,
+    generic_parameters: &GenericParameters,
     struct_tokens: &ItemStruct,
 ) -> syn::Result<ParsedFields> {
     let Fields::Named(field_tokens) = &struct_tokens.fields else {
@@ -66,8 +219,11 @@ fn parse_fields(
     };
 
     let mut non_ignored_parsed_fields: Vec<Field> = Vec::new();
+    let mut non_ignored_field_position_spans: Vec<Span> = Vec::new();
     let mut ignored_fields: Vec<Field> = Vec::new();
     let mut seen_field_names: HashSet<String> = HashSet::new();
+    let mut has_explicit_positions = false;
+    let mut alias_field_tokens_list: Vec<&syn::Field> = Vec::new();
 
     for field in &field_tokens.named {
         let field_name = field.ident.as_ref().expect("Expected field to have a name").to_string();
@@ -81,9 +237,19 @@ fn parse_fields(
             ));
         }
 
-        let parsed_field = parse_field_helper(
+        // Alias fields are parsed once the layout of all other fields is known.
+        if is_alias_field(field)? {
+            if !is_reserved_field(field) {
+                seen_field_names.insert(field_name);
+            }
+            alias_field_tokens_list.push(field);
+            continue;
+        }
+
+        let (parsed_field, explicit_position_span) = parse_field_helper(
             bitfield_attribute,
             bitfield_visibility,
+            generic_parameters,
             field,
             &non_ignored_parsed_fields,
         )?;
@@ -94,48 +260,183 @@ fn parse_fields(
         if parsed_field.ignored() {
             ignored_fields.push(parsed_field);
         } else {
+            has_explicit_positions |= explicit_position_span.is_some();
+            non_ignored_field_position_spans
+                .push(explicit_position_span.unwrap_or_else(|| field.span()));
             non_ignored_parsed_fields.push(parsed_field);
         }
     }
 
+    if has_explicit_positions {
+        check_field_positions(
+            bitfield_attribute,
+            &non_ignored_parsed_fields,
+            &non_ignored_field_position_spans,
+        )?;
+    } else if bitfield_attribute.arguments().fill().is_some() {
+        check_fill_field_bits(bitfield_attribute, &non_ignored_parsed_fields)?;
+    }
+    if has_explicit_positions || bitfield_attribute.arguments().fill().is_some() {
+        let implicit_reserved_fields =
+            generate_implicit_reserved_fields(bitfield_attribute, &non_ignored_parsed_fields);
+        non_ignored_parsed_fields.extend(implicit_reserved_fields);
+    }
+
+    let mut alias_fields: Vec<Field> = Vec::with_capacity(alias_field_tokens_list.len());
+    for alias_field_tokens in alias_field_tokens_list {
+        let (alias_field, _) = parse_field_helper(
+            bitfield_attribute,
+            bitfield_visibility,
+            generic_parameters,
+            alias_field_tokens,
+            &non_ignored_parsed_fields,
+        )?;
+        alias_fields.push(alias_field);
+    }
+    non_ignored_parsed_fields.extend(alias_fields);
+
     Ok(ParsedFields {
         non_ignored: non_ignored_parsed_fields,
         ignored: ignored_fields,
     })
 }
 
+/// Parses a field, returning the span of its explicit bit position if it has
+/// one.
 fn parse_field_helper(
     bitfield_attribute: &BitfieldAttribute,
     bitfield_visibility: &Visibility,
+    generic_parameters: &GenericParameters,
     field_tokens: &syn::Field,
     prev_fields: &[Field],
-) -> syn::Result<Field> {
+) -> syn::Result<(Field, Option<Span>)> {
     let bits_attribute = get_bits_attribute(field_tokens)?;
     if is_ignored_field(bits_attribute.as_ref()) {
-        return Ok(parse_ignored_field(field_tokens));
+        return Ok((parse_ignored_field(field_tokens), None));
     }
 
     let visibility = get_field_visibility(bitfield_visibility, field_tokens);
     let reserved = is_reserved_field(field_tokens);
     let spanned_data_type_token = get_field_data_type_spanned_token(field_tokens)?;
-    let bits = get_field_bits(bits_attribute.as_ref(), &spanned_data_type_token)?;
+    check_const_expression_bits(
+        generic_parameters,
+        bits_attribute.as_ref(),
+        &spanned_data_type_token,
+    )?;
+    let segments = bits_attribute.as_ref().and_then(|attr| attr.arguments().segments());
+    let bits = match &segments {
+        Some(segments) => check_split_field(
+            bitfield_attribute,
+            field_tokens,
+            bits_attribute.as_ref().expect("Expected bits attribute for split field"),
+            segments,
+            &spanned_data_type_token,
+        )?,
+        None => {
+            check_shift_without_segments(bits_attribute.as_ref())?;
+            let element_bits = bits_attribute
+                .as_ref()
+                .and_then(|attr| attr.arguments().element())
+                .or_else(|| spanned_data_type_token.data_type().implicit_element_bits());
+            match element_bits {
+                Some(element_bits) => check_packed_array_field(
+                    field_tokens,
+                    bits_attribute.as_ref(),
+                    element_bits,
+                    &spanned_data_type_token,
+                )?,
+                None => {
+                    check_custom_array_field_without_element_bits(
+                        field_tokens,
+                        &spanned_data_type_token,
+                    )?;
+                    check_const_length_array_field(
+                        generic_parameters,
+                        field_tokens,
+                        bits_attribute.as_ref(),
+                        &spanned_data_type_token,
+                    )?;
+                    get_field_bits(bits_attribute.as_ref(), &spanned_data_type_token)
+                },
+            }
+        },
+    };
 
-    check_bits(bits_attribute.as_ref(), bits)?;
+    check_type_parameter_dependent_field_bits(generic_parameters, field_tokens, &bits)?;
+    check_float_field(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_ascii_str_field(bits_attribute.as_ref(), &spanned_data_type_token)?;
+    check_arbitrary_int_field(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_scaled_field(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_field_overflow(bits_attribute.as_ref(), &spanned_data_type_token)?;
+    check_field_encoding(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_field_endian(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_niche_field(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_field_constraint(bits_attribute.as_ref(), &bits, &spanned_data_type_token)?;
+    check_field_enumerated_values(
+        field_tokens,
+        bits_attribute.as_ref(),
+        &bits,
+        &spanned_data_type_token,
+    )?;
 
-    if matches!(spanned_data_type_token.data_type(), DataType::Integer(..)) {
-        check_default_value_fit_in_field(bits_attribute.as_ref(), bits, &spanned_data_type_token)?;
-        check_field_data_type_can_hold_bits(
-            bits_attribute.as_ref(),
-            bits,
-            &spanned_data_type_token,
-        )?;
+    if let Some(bits) = bits.known() {
+        check_bits(bits_attribute.as_ref(), bits)?;
+
+        if matches!(spanned_data_type_token.data_type(), DataType::Integer(..))
+            && segments.is_none()
+        {
+            check_default_value_fit_in_field(
+                bits_attribute.as_ref(),
+                bits,
+                &spanned_data_type_token,
+            )?;
+            check_field_data_type_can_hold_bits(
+                bits_attribute.as_ref(),
+                bits,
+                &spanned_data_type_token,
+            )?;
+        }
     }
 
-    let offset = calculate_field_offset(bitfield_attribute, field_tokens, bits, prev_fields)?;
+    let explicit_position_span = bits_attribute
+        .as_ref()
+        .and_then(|attr| attr.arguments().segments_span().or_else(|| attr.offset_span()));
+    let explicit_offset = bits_attribute.as_ref().and_then(BitsAttribute::offset);
+    let alias_of = bits_attribute.as_ref().and_then(|attr| attr.arguments().alias_of());
+    let offset = match (alias_of, explicit_offset) {
+        // The segments of split fields have explicit bit positions.
+        _ if segments.is_some() => BitsExpr::Known(
+            segments
+                .iter()
+                .flatten()
+                .map(FieldSegment::offset)
+                .min()
+                .expect("Expected split field segments"),
+        ),
+        (Some(alias_of), _) => calculate_alias_field_offset(
+            field_tokens,
+            &alias_of,
+            &bits,
+            explicit_offset,
+            explicit_position_span,
+            prev_fields,
+        )?,
+        (None, Some(explicit_offset)) => check_explicit_field_position(
+            bitfield_attribute,
+            field_tokens,
+            explicit_offset,
+            &bits,
+            explicit_position_span.expect("Expected span for explicit bit position"),
+        )?,
+        (None, None) => {
+            calculate_field_offset(bitfield_attribute, field_tokens, &bits, prev_fields)?
+        },
+    };
     let access = get_field_access(bits_attribute.as_ref(), reserved)?;
+    check_field_accessor_visibility(bits_attribute.as_ref(), reserved, access)?;
     let name_ident = field_tokens.ident.as_ref().expect("Expected field identifier").clone();
     let arguments = bits_attribute.map(|attr| attr.arguments());
-    Ok(Field::new(
+    let field = Field::new(
         visibility,
         name_ident,
         spanned_data_type_token,
@@ -145,7 +446,9 @@ fn parse_field_helper(
         access,
         arguments,
         /* ignored= */ false,
-    ))
+    );
+
+    Ok((field, explicit_position_span))
 }
 
 fn parse_ignored_field(field_tokens: &syn::Field) -> Field {
@@ -156,8 +459,8 @@ fn parse_ignored_field(field_tokens: &syn::Field) -> Field {
         Visibility::new(&field_tokens.vis),
         name_ident,
         spanned_data_type_token,
-        0,
-        0,
+        BitsExpr::Known(0),
+        BitsExpr::Known(0),
         false,
         FieldAccess::NoAccess,
         /* arguments= */ None,
@@ -184,6 +487,12 @@ fn get_bits_attribute(field_tokens: &syn::Field) -> syn::Result<Option<BitsAttri
     Ok(Some(syn::parse2::<BitsAttribute>(bits_attribute_tokens.tokens.clone())?))
 }
 
+/// Returns true when the field is attributed as an alias of another field.
+fn is_alias_field(field_tokens: &syn::Field) -> syn::Result<bool> {
+    Ok(get_bits_attribute(field_tokens)?
+        .is_some_and(|bits_attribute| bits_attribute.arguments().alias_of().is_some()))
+}
+
 /// Returns true when the parsed `#[bits]` attribute marks the field as ignored.
 fn is_ignored_field(bits_attribute: Option<&BitsAttribute>) -> bool {
     bits_attribute.is_some_and(|attr| attr.arguments().ignored())
@@ -208,7 +517,7 @@ fn get_field_data_type_spanned_token(
                 )),
                 TypeParsingError::UnexpectedFloat => Err(create_user_parsing_compiler_error(
                     field_tokens.ty.span(),
-                    "Floats are not supported as a field type.".to_string(),
+                    "Floats are not supported as an array element type.".to_string(),
                 )),
                 TypeParsingError::ZeroArrayLength => Err(create_user_parsing_compiler_error(
                     field_tokens.ty.span(),
@@ -216,11 +525,9 @@ fn get_field_data_type_spanned_token(
                 )),
                 TypeParsingError::NonIntegerArrayType => Err(create_user_parsing_compiler_error(
                     field_tokens.ty.span(),
-                    "Array fields can only have `u8` as their element type.".to_string(),
-                )),
-                TypeParsingError::InvalidArrayLength => Err(create_user_parsing_compiler_error(
-                    field_tokens.ty.span(),
-                    "Field array length must be a valid unsigned integer literal.",
+                    "Array fields can only have integer, `bool` or custom types as their element \
+                     type."
+                        .to_string(),
                 )),
                 TypeParsingError::ArrayLengthTooLarge => Err(create_user_parsing_compiler_error(
                     field_tokens.ty.span(),
@@ -240,27 +547,319 @@ fn get_field_data_type_spanned_token(
 }
 
 /// Returns the field bits, inferring from the data type if not explicitly set.
+///
+/// The bits of custom and nested field types come from their `BitfieldField`
+/// implementation, which only the compiler can evaluate.
 fn get_field_bits(
     bits_attribute: Option<&BitsAttribute>,
     spanned_data_type_token: &SpannedDataTypeToken,
-) -> syn::Result<u32> {
+) -> BitsExpr {
     if let Some(bits_attr) = bits_attribute {
         if let Some(bits) = bits_attr.bits() {
-            return Ok(bits);
+            return bits;
         }
     }
 
+    if let Some(float_bits) = spanned_data_type_token.float_bits() {
+        let half = bits_attribute.is_some_and(|attr| attr.arguments().half());
+        return BitsExpr::Known(if half { HALF_PRECISION_FLOAT_BITS } else { float_bits });
+    }
+
+    if let Some(non_zero_bits) = spanned_data_type_token.non_zero_bits() {
+        return BitsExpr::Known(non_zero_bits);
+    }
+
+    if let Some(arbitrary_int) = spanned_data_type_token.arbitrary_int() {
+        return BitsExpr::Known(arbitrary_int.bits());
+    }
+
+    // `Option` fields take the bits of their wrapped type.
+    if let Some(Ok(inner_spanned_data_type_token)) = spanned_data_type_token.option_inner_type() {
+        return get_field_bits(None, &inner_spanned_data_type_token);
+    }
+
     if matches!(spanned_data_type_token.data_type(), DataType::Custom) {
+        let field_data_type_tokens = spanned_data_type_token.to_tokens();
+        return BitsExpr::Deferred(quote::quote! {
+            <#field_data_type_tokens as ::bitfields::BitfieldField>::BITS
+        });
+    }
+
+    // Get field bits from data type if bits attribute doesn't provide any.
+    spanned_data_type_token.bits()
+}
+
+/// Checks that bits defined by a const expression, which only the compiler can
+/// evaluate, belong to an integer or custom field whose arguments don't depend
+/// on the bits.
+fn check_const_expression_bits(
+    generic_parameters: &GenericParameters,
+    bits_attribute: Option<&BitsAttribute>,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(bits_attribute) = bits_attribute else {
+        return Ok(());
+    };
+    let Some(BitsExpr::Deferred(bits_expr)) = bits_attribute.bits() else {
+        return Ok(());
+    };
+
+    // The layout is asserted outside of the bitfield, where the generic
+    // parameters can't be referred to.
+    if generic_parameters.is_parameter_dependent(&bits_expr) {
         return Err(create_user_parsing_compiler_error(
-            spanned_data_type_token.span(),
-            "Custom and nested field types require a defined bit size, otherwise we can't \
-             determine the size of the field."
-                .to_string(),
+            bits_attribute.span().expect("Expected span for bits"),
+            "The bits of a field can't depend on the generic parameters of the bitfield.",
         ));
     }
 
-    // Get field bits from data type if bits attribute doesn't provide any.
-    Ok(spanned_data_type_token.data_type().bit_size())
+    if matches!(spanned_data_type_token.data_type(), DataType::Array { .. })
+        || spanned_data_type_token.float_bits().is_some()
+        || spanned_data_type_token.non_zero_bits().is_some()
+        || spanned_data_type_token.option_inner_type().is_some()
+    {
+        return Err(create_user_parsing_compiler_error(
+            bits_attribute.span().expect("Expected span for bits"),
+            "The bits of array, float and niche-typed fields can't be a const expression, define \
+             them with an integer literal.",
+        ));
+    }
+
+    let arguments = bits_attribute.arguments();
+    let bits_dependent_argument =
+        [(arguments.encoding_span(), "encoding"), (arguments.endian_span(), "endian")]
+            .into_iter()
+            .find_map(|(span, argument)| span.map(|span| (span, argument)));
+    if let Some((span, argument)) = bits_dependent_argument {
+        return Err(create_user_parsing_compiler_error(
+            span,
+            format!(
+                "The `{argument}` argument can't be used by fields with bits defined by a const \
+                 expression, define the bits with an integer literal."
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks the segments of a split field, returning the bits of the field,
+/// which are the bits of all segments and the shift.
+fn check_split_field(
+    bitfield_attribute: &BitfieldAttribute,
+    field_tokens: &syn::Field,
+    bits_attribute: &BitsAttribute,
+    segments: &[FieldSegment],
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<BitsExpr> {
+    let segments_span =
+        bits_attribute.arguments().segments_span().expect("Expected span for segments");
+    if bits_attribute.bits().is_some()
+        || bits_attribute.offset().is_some()
+        || bits_attribute.arguments().alias_of().is_some()
+    {
+        return Err(create_user_parsing_compiler_error(
+            segments_span,
+            "The bits of a split field are defined by its segments, remove the bits, the bit \
+             position, and the 'alias_of' argument.",
+        ));
+    }
+
+    if !matches!(
+        spanned_data_type_token.data_type(),
+        DataType::Integer(integer_type) if integer_type != IntegerType::Bool
+    ) {
+        return Err(create_user_parsing_compiler_error(
+            field_tokens.ty.span(),
+            "Split fields must have an integer type.",
+        ));
+    }
+
+    let bitfield_bit_size =
+        get_known_bitfield_bit_size(bitfield_attribute, segments_span, "Split fields")?;
+    for segment in segments {
+        let segment_bits_end = u64::from(segment.offset()) + u64::from(segment.bits()) - 1;
+        if segment_bits_end >= u64::from(bitfield_bit_size) {
+            return Err(create_user_parsing_compiler_error(
+                segments_span,
+                format!(
+                    "The segment bits '{}..={segment_bits_end}' exceed the bit size of the \
+                     bitfield type ({bitfield_bit_size} bits).",
+                    segment.offset()
+                ),
+            ));
+        }
+    }
+
+    let shift = bits_attribute.arguments().shift().unwrap_or_default();
+    let bits =
+        segments.iter().map(|segment| u64::from(segment.bits())).sum::<u64>() + u64::from(shift);
+    let field_data_type_bit_size = spanned_data_type_token.data_type().bit_size();
+    if bits > u64::from(field_data_type_bit_size) {
+        return Err(create_user_parsing_compiler_error(
+            segments_span,
+            format!(
+                "The field type '{}' is too small to hold the '{bits} bits' of the segments and \
+                 the shift.",
+                spanned_data_type_token.get_data_type_tokens()
+            ),
+        ));
+    }
+    if matches!(bitfield_attribute.spanned_data_type_token().data_type(), DataType::Integer(_))
+        && bits > u64::from(bitfield_bit_size)
+    {
+        return Err(create_user_parsing_compiler_error(
+            segments_span,
+            format!(
+                "The '{bits} bits' of the segments and the shift exceed the bit size of the \
+                 bitfield type ({bitfield_bit_size} bits)."
+            ),
+        ));
+    }
+
+    Ok(BitsExpr::Known(bits as u32))
+}
+
+/// Checks a packed array field, returning its bits, which are the bits of all
+/// its elements.
+fn check_packed_array_field(
+    field_tokens: &syn::Field,
+    bits_attribute: Option<&BitsAttribute>,
+    element_bits: u32,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<BitsExpr> {
+    // Arrays packed by their element type have no element argument.
+    let element_span = bits_attribute
+        .and_then(|attr| attr.arguments().element_span())
+        .unwrap_or_else(|| field_tokens.ty.span());
+    let DataType::Array {
+        length,
+        element_type,
+    } = spanned_data_type_token.data_type()
+    else {
+        return Err(create_user_parsing_compiler_error(
+            element_span,
+            "The 'element' argument can only be used by array fields.",
+        ));
+    };
+    let Some(length) = length else {
+        return Err(create_user_parsing_compiler_error(
+            field_tokens.ty.span(),
+            "Packed array fields must have a length known by the macro, only `[u8; N]` arrays \
+             without the 'element' argument can have a const length.",
+        ));
+    };
+
+    // The bits of custom element types are only known by the compiler, they are
+    // checked by the generated layout assertion.
+    if let ArrayElementType::Integer(integer_type) = element_type {
+        if element_bits > integer_type.bit_size() {
+            let element_data_type_tokens = spanned_data_type_token
+                .array_element_type_tokens()
+                .expect("Expected element type for array field");
+            return Err(create_user_parsing_compiler_error(
+                element_span,
+                format!(
+                    "The array element type '{element_data_type_tokens}' is too small to hold the \
+                     specified '{element_bits} bits' of each element."
+                ),
+            ));
+        }
+    }
+
+    let bits = u64::from(element_bits) * u64::from(length);
+    if let Some(defined_bits) =
+        bits_attribute.and_then(BitsAttribute::bits).as_ref().and_then(BitsExpr::known)
+    {
+        if u64::from(defined_bits) != bits {
+            return Err(create_user_parsing_compiler_error(
+                bits_attribute.and_then(BitsAttribute::span).expect("Expected span for bits"),
+                format!(
+                    "The bits of a packed array field are the element bits times the array length \
+                     ({bits} bits), remove the bits or make them match."
+                ),
+            ));
+        }
+    }
+
+    let bits = u32::try_from(bits).map_err(|_| {
+        create_user_parsing_compiler_error(
+            element_span,
+            format!("The packed array field has too many bits ({bits} bits)."),
+        )
+    })?;
+    Ok(BitsExpr::Known(bits))
+}
+
+/// Checks that arrays of custom types define the bits of their elements, as
+/// they can only be packed.
+fn check_custom_array_field_without_element_bits(
+    field_tokens: &syn::Field,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    if !matches!(
+        spanned_data_type_token.data_type(),
+        DataType::Array {
+            element_type: ArrayElementType::Custom,
+            ..
+        }
+    ) {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        field_tokens.ty.span(),
+        "Arrays of custom types must define the bits of each element with the 'element' argument.",
+    ))
+}
+
+/// Checks that an array field with a const length (e.g. `[u8; LENGTH]`), whose
+/// bits are the bits of all its bytes, doesn't define its bits and that its
+/// length doesn't depend on the generic parameters of the bitfield.
+fn check_const_length_array_field(
+    generic_parameters: &GenericParameters,
+    field_tokens: &syn::Field,
+    bits_attribute: Option<&BitsAttribute>,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    if !spanned_data_type_token.has_const_array_length() {
+        return Ok(());
+    }
+
+    // The layout is asserted outside of the bitfield, where the generic
+    // parameters can't be referred to.
+    let length_tokens =
+        spanned_data_type_token.array_length_tokens().expect("Expected length for array type");
+    if generic_parameters.is_parameter_dependent(&length_tokens) {
+        return Err(create_user_parsing_compiler_error(
+            field_tokens.ty.span(),
+            "The length of an array field can't depend on the generic parameters of the bitfield.",
+        ));
+    }
+
+    if let Some(bits_span) =
+        bits_attribute.filter(|attr| attr.bits().is_some()).and_then(BitsAttribute::span)
+    {
+        return Err(create_user_parsing_compiler_error(
+            bits_span,
+            "The bits of an array field with a const length are the bits of all its bytes, remove \
+             the bits.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the shift argument is only used by split fields.
+fn check_shift_without_segments(bits_attribute: Option<&BitsAttribute>) -> syn::Result<()> {
+    let Some(shift_span) = bits_attribute.and_then(|attr| attr.arguments().shift_span()) else {
+        return Ok(());
+    };
+
+    Err(create_user_parsing_compiler_error(
+        shift_span,
+        "The 'shift' argument can only be used by split fields with 'segments'.",
+    ))
 }
 
 /// Validates the bits argument of a field.
@@ -294,6 +893,13 @@ fn check_default_value_fit_in_field(
         return Ok(());
     };
 
+    if matches!(default_value_expr, ConstExpr::Float { .. }) {
+        return Err(create_user_parsing_compiler_error(
+            default_value_expr.span(),
+            "Float default values are only supported by float fields.",
+        ));
+    }
+
     // Check if the user is trying to use a const variable or const
     // function or something as a default, leave this to the compiler
     // there's nothing we can do.
@@ -350,25 +956,667 @@ fn check_default_value_fit_in_field(
     Ok(())
 }
 
-/// Checks if the field can contain the defined bits.
-fn check_field_data_type_can_hold_bits(
+/// The bits of a half-precision float field.
+const HALF_PRECISION_FLOAT_BITS: u32 = 16;
+
+/// The largest finite half-precision float.
+const HALF_PRECISION_FLOAT_MAX: f64 = 65504.0;
+
+/// Checks that float fields are stored in the bits of their type, or as a
+/// half-precision float, and that their float default value is representable.
+fn check_float_field(
     bits_attribute: Option<&BitsAttribute>,
-    bits: u32,
+    bits: &BitsExpr,
     spanned_data_type_token: &SpannedDataTypeToken,
 ) -> syn::Result<()> {
-    let Some(bits_attr) = bits_attribute else {
+    let half = bits_attribute.is_some_and(|attr| attr.arguments().half());
+    let Some(float_bits) = spanned_data_type_token.float_bits() else {
+        if half {
+            return Err(create_user_parsing_compiler_error(
+                bits_attribute
+                    .and_then(|attr| attr.arguments().half_span())
+                    .expect("Expected span for half argument"),
+                "Only `f32` and `f64` fields can be half-precision floats.",
+            ));
+        }
+        return Ok(());
+    };
+    let Some(bits_attribute) = bits_attribute else {
         return Ok(());
     };
 
-    let field_max_bits = spanned_data_type_token.data_type().bit_size();
-    let spanned_data_type_tokens = spanned_data_type_token.get_data_type_tokens();
-    if bits > field_max_bits {
-        return Err(create_user_parsing_compiler_error(
-            bits_attr.span().expect("Expected bits attribute for bits checking"),
+    let expected_bits = if half { HALF_PRECISION_FLOAT_BITS } else { float_bits };
+    if bits.known() != Some(expected_bits) {
+        let message = if half {
+            format!("Half-precision float fields must have '{HALF_PRECISION_FLOAT_BITS} bits'.")
+        } else {
             format!(
-                "The field type '{spanned_data_type_tokens}' is too small to hold the specified \
-                 '{bits} bits'."
-            ),
+                "The float field type '{spanned_data_type_token}' must have '{float_bits} bits', \
+                 or '{HALF_PRECISION_FLOAT_BITS} bits' as a half-precision float with `half = \
+                 true`."
+            )
+        };
+        return Err(create_user_parsing_compiler_error(
+            bits_attribute.span().unwrap_or_else(|| spanned_data_type_token.span()),
+            message,
+        ));
+    }
+
+    match bits_attribute.arguments().default_value_expr() {
+        Some(ConstExpr::Literal {
+            spanned_token, ..
+        }) => Err(create_user_parsing_compiler_error(
+            spanned_token.span(),
+            "Float fields must have float default values (e.g. `1.0`).",
+        )),
+        Some(ConstExpr::Float {
+            value,
+            spanned_token,
+        }) => {
+            let max_value = match (half, float_bits) {
+                (true, _) => HALF_PRECISION_FLOAT_MAX,
+                (false, 32) => f64::from(f32::MAX),
+                _ => f64::MAX,
+            };
+            if value.abs() > max_value {
+                return Err(create_user_parsing_compiler_error(
+                    spanned_token.span(),
+                    format!(
+                        "The default value '{}' exceeds the maximum value of the '{expected_bits} \
+                         bits' float ({max_value}).",
+                        spanned_token.token()
+                    ),
+                ));
+            }
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Checks that string default values are only on ASCII string fields, and that
+/// they're ASCII and fit in the length of the string.
+fn check_ascii_str_field(
+    bits_attribute: Option<&BitsAttribute>,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(ConstExpr::Str {
+        value,
+        spanned_token,
+    }) = bits_attribute.and_then(|attr| attr.arguments().default_value_expr())
+    else {
+        return Ok(());
+    };
+
+    let Some(length_expr) = spanned_data_type_token.ascii_str_length_expr() else {
+        return Err(create_user_parsing_compiler_error(
+            spanned_token.span(),
+            "String default values are only supported by ASCII string fields (e.g. \
+             `AsciiStr<12>`).",
+        ));
+    };
+
+    if !value.is_ascii() {
+        return Err(create_user_parsing_compiler_error(
+            spanned_token.span(),
+            format!("The default value {} contains non-ASCII characters.", spanned_token.token()),
+        ));
+    }
+
+    // Lengths of const paths are left to the compiler.
+    if let Some(length) = evaluate_literal_arithmetic(&length_expr) {
+        if value.len() as u128 > length {
+            return Err(create_user_parsing_compiler_error(
+                spanned_token.span(),
+                format!(
+                    "The default value {} is longer than the '{length} bytes' of the ASCII string.",
+                    spanned_token.token()
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that arbitrary-width integer fields (e.g. `u4`) have the bits of
+/// their width, and that their default value fits in the width.
+fn check_arbitrary_int_field(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let (Some(arbitrary_int), Some(bits_attribute)) =
+        (spanned_data_type_token.arbitrary_int(), bits_attribute)
+    else {
+        return Ok(());
+    };
+
+    let int_bits = arbitrary_int.bits();
+    if bits.known().is_some_and(|bits| bits != int_bits) {
+        return Err(create_user_parsing_compiler_error(
+            bits_attribute.span().unwrap_or_else(|| spanned_data_type_token.span()),
+            format!(
+                "The arbitrary-width integer field type '{spanned_data_type_token}' must have \
+                 '{int_bits} bits'."
+            ),
+        ));
+    }
+
+    let Some(ConstExpr::Literal {
+        number,
+        negative_sign,
+        spanned_token,
+    }) = bits_attribute.arguments().default_value_expr()
+    else {
+        return Ok(());
+    };
+
+    let (signed_min, unsigned_max) = min_max_for_bits(int_bits);
+    let (min, max) = if arbitrary_int.signed() {
+        (signed_min, -(signed_min + 1))
+    } else {
+        (0, unsigned_max as i128)
+    };
+    let default_value = if negative_sign { -(number as i128) } else { number as i128 };
+    if !(min..=max).contains(&default_value) {
+        return Err(create_user_parsing_compiler_error(
+            spanned_token.span(),
+            format!(
+                "The default value '{}' doesn't fit in the field type '{spanned_data_type_token}' \
+                 ({min}..={max}).",
+                spanned_token.token()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that fixed-point and scaled fields are integer fields with either
+/// fraction bits that fit in the field, or a scale and offset.
+fn check_scaled_field(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(arguments) = bits_attribute.map(BitsAttribute::arguments) else {
+        return Ok(());
+    };
+    let Some(scaling_span) = arguments
+        .fixed_span()
+        .or(arguments.scale_span())
+        .or(arguments.scale_offset_span())
+        .or(arguments.unit_span())
+    else {
+        return Ok(());
+    };
+
+    if arguments.scaling().is_none() {
+        return Err(create_user_parsing_compiler_error(
+            scaling_span,
+            "The `unit` argument can only be used with the `fixed`, `scale` or `offset` arguments.",
+        ));
+    }
+
+    if let (Some(fixed_span), true) =
+        (arguments.fixed_span(), arguments.scale().is_some() || arguments.scale_offset().is_some())
+    {
+        return Err(create_user_parsing_compiler_error(
+            fixed_span,
+            "The `fixed` argument can't be combined with the `scale` and `offset` arguments.",
+        ));
+    }
+
+    if !matches!(spanned_data_type_token.data_type(), DataType::Integer(integer_type) if integer_type != IntegerType::Bool)
+    {
+        return Err(create_user_parsing_compiler_error(
+            scaling_span,
+            "Only integer fields can be fixed-point or scaled fields.",
+        ));
+    }
+
+    if let (Some(fixed), Some(bits)) = (arguments.fixed(), bits.known()) {
+        if fixed > bits {
+            return Err(create_user_parsing_compiler_error(
+                arguments.fixed_span().expect("Expected span for fixed argument"),
+                format!("The fraction bits '{fixed}' exceed the '{bits} bits' of the field."),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that fields saturating values are integer fields or arrays of
+/// integers, as other fields have no bounds to clamp to.
+fn check_field_overflow(
+    bits_attribute: Option<&BitsAttribute>,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(arguments) = bits_attribute.map(BitsAttribute::arguments) else {
+        return Ok(());
+    };
+    let (Some(OverflowPolicy::Saturate), Some(overflow_span)) =
+        (arguments.overflow(), arguments.overflow_span())
+    else {
+        return Ok(());
+    };
+
+    let is_integer_field = match spanned_data_type_token.data_type() {
+        DataType::Integer(integer_type)
+        | DataType::Array {
+            element_type: ArrayElementType::Integer(integer_type), ..
+        } => integer_type != IntegerType::Bool,
+        _ => false,
+    };
+    if !is_integer_field {
+        return Err(create_user_parsing_compiler_error(
+            overflow_span,
+            "Only integer fields and arrays of integers can saturate values.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the `range` or `values` constraint of a field is on an integer
+/// field, fits in the bits of the field, and is satisfied by the default value.
+fn check_field_constraint(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(arguments) = bits_attribute.map(BitsAttribute::arguments) else {
+        return Ok(());
+    };
+    let Some(constraint) = arguments.constraint() else {
+        return Ok(());
+    };
+    let constraint_span = arguments.constraint_span().expect("Expected span for constraint");
+
+    if !matches!(spanned_data_type_token.data_type(), DataType::Integer(integer_type) if integer_type != IntegerType::Bool)
+    {
+        return Err(create_user_parsing_compiler_error(
+            constraint_span,
+            "Only integer fields can have a `range` or `values` constraint.",
+        ));
+    }
+
+    if let Some(bits) = bits.known() {
+        let (min, max) =
+            get_field_value_bounds(bits, spanned_data_type_token, arguments.encoding());
+        if let Some(value) =
+            constraint.bound_values().into_iter().find(|value| !(min..=max).contains(value))
+        {
+            return Err(create_user_parsing_compiler_error(
+                constraint_span,
+                format!(
+                    "The value '{value}' of the `{constraint}` constraint doesn't fit in the \
+                     '{bits} bits ({min}..={max})' of the field."
+                ),
+            ));
+        }
+    }
+
+    if let Some(ConstExpr::Literal {
+        number,
+        negative_sign,
+        spanned_token,
+        ..
+    }) = arguments.default_value_expr()
+    {
+        let default_value = if negative_sign { -(number as i128) } else { number as i128 };
+        if !constraint.contains(default_value) {
+            return Err(create_user_parsing_compiler_error(
+                spanned_token.span(),
+                format!(
+                    "The default value '{}' doesn't satisfy the `{constraint}` constraint of the \
+                     field.",
+                    spanned_token.token()
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that an encoded field has a type and bits supported by its encoding,
+/// and that its default value can be encoded.
+fn check_field_encoding(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(arguments) = bits_attribute.map(BitsAttribute::arguments) else {
+        return Ok(());
+    };
+    let Some(encoding) = arguments.encoding() else {
+        return Ok(());
+    };
+    let encoding_span = arguments.encoding_span().expect("Expected span for encoding");
+    let data_type = spanned_data_type_token.data_type();
+
+    let (supported, supported_fields) = match encoding {
+        FieldEncoding::SignMagnitude | FieldEncoding::OffsetBinary => (
+            matches!(data_type, DataType::Integer(_)) && !data_type.unsigned(),
+            "signed integer fields",
+        ),
+        FieldEncoding::Bcd | FieldEncoding::Gray => (
+            matches!(data_type, DataType::Integer(integer_type) if integer_type != IntegerType::Bool && integer_type.is_unsigned()),
+            "unsigned integer fields",
+        ),
+        FieldEncoding::ActiveLow => (
+            matches!(data_type, DataType::Integer(integer_type) if integer_type.is_unsigned()),
+            "bool and unsigned integer fields",
+        ),
+    };
+    if !supported {
+        return Err(create_user_parsing_compiler_error(
+            encoding_span,
+            format!("The `{encoding}` encoding is only supported by {supported_fields}."),
+        ));
+    }
+
+    let Some(bits) = bits.known() else {
+        return Ok(());
+    };
+    if encoding == FieldEncoding::SignMagnitude && bits < 2 {
+        return Err(create_user_parsing_compiler_error(
+            encoding_span,
+            "Sign-magnitude fields must have at least '2 bits', as one of them is the sign bit.",
+        ));
+    }
+    if encoding == FieldEncoding::Bcd && bits % 4 != 0 {
+        return Err(create_user_parsing_compiler_error(
+            encoding_span,
+            format!(
+                "BCD fields must have a multiple of '4 bits', as each decimal digit takes 4 bits, \
+                 but the field has '{bits} bits'."
+            ),
+        ));
+    }
+
+    if let Some(ConstExpr::Literal {
+        number,
+        negative_sign,
+        spanned_token,
+        ..
+    }) = arguments.default_value_expr()
+    {
+        let (min, max) = get_field_value_bounds(bits, spanned_data_type_token, Some(encoding));
+        let default_value = if negative_sign { -(number as i128) } else { number as i128 };
+        if !(min..=max).contains(&default_value) {
+            return Err(create_user_parsing_compiler_error(
+                spanned_token.span(),
+                format!(
+                    "The default value '{}' is outside the values of the '{bits} bits' \
+                     `{encoding}` field ({min}..={max}).",
+                    spanned_token.token()
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a field with a byte order is an integer field made of whole
+/// bytes.
+fn check_field_endian(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(endian_span) = bits_attribute.and_then(|attr| attr.arguments().endian_span()) else {
+        return Ok(());
+    };
+
+    if !matches!(spanned_data_type_token.data_type(), DataType::Integer(integer_type) if integer_type != IntegerType::Bool)
+    {
+        return Err(create_user_parsing_compiler_error(
+            endian_span,
+            "Only integer fields can have a byte order.",
+        ));
+    }
+
+    if let Some(bits) = bits.known() {
+        if bits % 8 != 0 {
+            return Err(create_user_parsing_compiler_error(
+                endian_span,
+                format!(
+                    "Fields with a byte order must have a multiple of '8 bits', but the field has \
+                     '{bits} bits'."
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that non-zero fields fit in the bits of their integer type, and that
+/// `Option` fields wrap a supported type and have a `none` value that fits in
+/// their bits.
+fn check_niche_field(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let none_value = bits_attribute.and_then(|attr| attr.arguments().none());
+    let Some(inner_spanned_data_type_token) = spanned_data_type_token.option_inner_type() else {
+        if none_value.is_some() {
+            return Err(create_user_parsing_compiler_error(
+                bits_attribute
+                    .and_then(|attr| attr.arguments().none_span())
+                    .expect("Expected span for none argument"),
+                "The `none` argument can only be used by `Option` fields.",
+            ));
+        }
+        return check_non_zero_field_bits(bits_attribute, bits, spanned_data_type_token);
+    };
+
+    let unsupported_inner_type_error = || {
+        create_user_parsing_compiler_error(
+            spanned_data_type_token.span(),
+            "`Option` fields can only wrap unsigned integer, non-zero integer or custom types.",
+        )
+    };
+    let inner_spanned_data_type_token =
+        inner_spanned_data_type_token.map_err(|_| unsupported_inner_type_error())?;
+    let inner_bits = match inner_spanned_data_type_token.data_type() {
+        DataType::Integer(integer_type)
+            if integer_type != IntegerType::Bool && integer_type.is_unsigned() =>
+        {
+            Some(integer_type.bit_size())
+        },
+        DataType::Custom => inner_spanned_data_type_token.non_zero_bits(),
+        _ => return Err(unsupported_inner_type_error()),
+    };
+
+    let Some(none_value) = none_value else {
+        return Err(create_user_parsing_compiler_error(
+            spanned_data_type_token.span(),
+            "`Option` fields must define the bits representing `None` with the `none` argument \
+             (e.g. `#[bits(8, none = 0xFF)]`).",
+        ));
+    };
+
+    let Some(bits) = bits.known() else {
+        return Ok(());
+    };
+    if let Some(inner_bits) = inner_bits {
+        if bits > inner_bits {
+            return Err(create_user_parsing_compiler_error(
+                bits_attribute
+                    .and_then(BitsAttribute::span)
+                    .unwrap_or_else(|| spanned_data_type_token.span()),
+                format!(
+                    "The wrapped type '{inner_spanned_data_type_token}' is too small to hold the \
+                     specified '{bits} bits'."
+                ),
+            ));
+        }
+    }
+
+    let (_, max_value) = min_max_for_bits(bits);
+    if none_value > max_value {
+        return Err(create_user_parsing_compiler_error(
+            bits_attribute
+                .and_then(|attr| attr.arguments().none_span())
+                .expect("Expected span for none argument"),
+            format!(
+                "The none value '{none_value}' exceeds the maximum value for the '{bits} bits' of \
+                 the field ({max_value})."
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that a field with enumerated values is typed by the name of the enum
+/// generated for it, and that its values are unique and fit in its bits.
+fn check_field_enumerated_values(
+    field_tokens: &syn::Field,
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(enumerated_values) =
+        bits_attribute.and_then(|attr| attr.arguments().enumerated_values())
+    else {
+        return Ok(());
+    };
+    let enumerated_values_span = bits_attribute
+        .and_then(|attr| attr.arguments().enumerated_values_span())
+        .expect("Expected span for values argument");
+
+    let is_enum_name = matches!(
+        &field_tokens.ty,
+        syn::Type::Path(type_path) if type_path.qself.is_none() && type_path.path.get_ident().is_some()
+    );
+    if !is_enum_name
+        || !matches!(spanned_data_type_token.data_type(), DataType::Custom)
+        || spanned_data_type_token.float_bits().is_some()
+        || spanned_data_type_token.non_zero_bits().is_some()
+        || spanned_data_type_token.arbitrary_int().is_some()
+    {
+        return Err(create_user_parsing_compiler_error(
+            spanned_data_type_token.span(),
+            "Fields with enumerated values must be typed by the name of the enum generated for \
+             them (e.g. `mode: Mode`).",
+        ));
+    }
+
+    let Some(bits) = bits_attribute.and_then(BitsAttribute::bits).and_then(|_| bits.known()) else {
+        return Err(create_user_parsing_compiler_error(
+            enumerated_values_span,
+            "Fields with enumerated values must define bits known by the macro (e.g. `#[bits(2, \
+             values(A = 0, B = 1))]`).",
+        ));
+    };
+
+    let (_, max_value) = min_max_for_bits(bits);
+    for (index, enumerated_value) in enumerated_values.iter().enumerate() {
+        let name_ident = enumerated_value.name_ident();
+        let value = enumerated_value.value();
+        if value > max_value {
+            return Err(create_user_parsing_compiler_error(
+                enumerated_value.value_span(),
+                format!(
+                    "The value '{value}' of '{name_ident}' exceeds the maximum value for the \
+                     '{bits} bits' of the field ({max_value})."
+                ),
+            ));
+        }
+
+        for prev_enumerated_value in &enumerated_values[..index] {
+            if prev_enumerated_value.name_ident() == name_ident {
+                return Err(create_user_parsing_compiler_error(
+                    name_ident.span(),
+                    format!("Duplicate enumerated value '{name_ident}'."),
+                ));
+            }
+            if prev_enumerated_value.value() == value {
+                return Err(create_user_parsing_compiler_error(
+                    enumerated_value.value_span(),
+                    format!(
+                        "The value '{value}' of '{name_ident}' is already the value of '{}'.",
+                        prev_enumerated_value.name_ident()
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that a non-zero field doesn't have more bits than its integer type.
+fn check_non_zero_field_bits(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: &BitsExpr,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let (Some(non_zero_bits), Some(bits)) = (spanned_data_type_token.non_zero_bits(), bits.known())
+    else {
+        return Ok(());
+    };
+
+    if bits > non_zero_bits {
+        return Err(create_user_parsing_compiler_error(
+            bits_attribute
+                .and_then(BitsAttribute::span)
+                .unwrap_or_else(|| spanned_data_type_token.span()),
+            format!(
+                "The field type '{spanned_data_type_token}' is too small to hold the specified \
+                 '{bits} bits'."
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the smallest and largest values an integer field with the given
+/// bits and encoding holds, capped to the `i128` range.
+fn get_field_value_bounds(
+    bits: u32,
+    spanned_data_type_token: &SpannedDataTypeToken,
+    encoding: Option<FieldEncoding>,
+) -> (i128, i128) {
+    let (signed_min, unsigned_max) = min_max_for_bits(bits);
+    match encoding {
+        Some(FieldEncoding::SignMagnitude) => (signed_min + 1, -(signed_min + 1)),
+        Some(FieldEncoding::Bcd) => (0, 10i128.pow(bits / 4) - 1),
+        _ if spanned_data_type_token.data_type().unsigned() => {
+            (0, unsigned_max.min(i128::MAX as u128) as i128)
+        },
+        _ => (signed_min, -(signed_min + 1)),
+    }
+}
+
+/// Checks if the field can contain the defined bits.
+fn check_field_data_type_can_hold_bits(
+    bits_attribute: Option<&BitsAttribute>,
+    bits: u32,
+    spanned_data_type_token: &SpannedDataTypeToken,
+) -> syn::Result<()> {
+    let Some(bits_attr) = bits_attribute else {
+        return Ok(());
+    };
+
+    let field_max_bits = spanned_data_type_token.data_type().bit_size();
+    let spanned_data_type_tokens = spanned_data_type_token.get_data_type_tokens();
+    if bits > field_max_bits {
+        return Err(create_user_parsing_compiler_error(
+            bits_attr.span().expect("Expected bits attribute for bits checking"),
+            format!(
+                "The field type '{spanned_data_type_tokens}' is too small to hold the specified \
+                 '{bits} bits'."
+            ),
         ));
     }
 
@@ -383,33 +1631,68 @@ const fn min_max_for_bits(bits: u32) -> (i128, u128) {
     (min, max)
 }
 
+/// Calculates the offset of a field without an explicit bit position, placing
+/// it right after the previous field in the bit order.
 fn calculate_field_offset(
     bitfield_attribute: &BitfieldAttribute,
     field_tokens: &syn::Field,
-    bits: u32,
+    bits: &BitsExpr,
     prev_fields: &[Field],
-) -> syn::Result<u32> {
-    let offset = prev_fields.iter().map(Field::bits).sum::<u32>();
+) -> syn::Result<BitsExpr> {
+    let bitfield_bits = bitfield_attribute.spanned_data_type_token().bits();
 
+    // Fields follow the last bit range of the previous field, which is its last
+    // segment if it's split.
+    let prev_field_bit_range =
+        prev_fields.last().and_then(|prev_field| prev_field.bit_ranges().pop());
     match bitfield_attribute.arguments().order() {
-        BitOrder::Lsb => Ok(offset),
+        BitOrder::Lsb => Ok(prev_field_bit_range
+            .map_or(BitsExpr::Known(0), |(prev_field_offset, prev_field_bits)| {
+                prev_field_offset + prev_field_bits
+            })),
         BitOrder::Msb => {
-            let bitfield_bit_size =
-                bitfield_attribute.spanned_data_type_token().data_type().bit_size();
+            let prev_field_offset = prev_field_bit_range
+                .map_or(bitfield_bits.clone(), |(prev_field_offset, _)| prev_field_offset);
+            let (Some(prev_field_offset), Some(bits)) = (prev_field_offset.known(), bits.known())
+            else {
+                // The bits are only known by the compiler, an overflow is
+                // caught by the layout assertion.
+                return Ok(prev_field_offset - bits.clone());
+            };
             // We calculate offset starting from the left. There's a chance that
             // the total bits of all fields is greater than the number of bits
-            // of the bitfield type. We will catch it later so
-            // we can ignore for now.
-            let total_bits = offset + bits;
-            if total_bits <= bitfield_bit_size {
-                Ok(bitfield_bit_size - bits - offset)
+            // of the bitfield type.
+            if bits <= prev_field_offset {
+                Ok(BitsExpr::Known(prev_field_offset - bits))
             } else {
-                // We've overflown the bitfield type.
+                // We've overflown the bitfield type, whose bits are known as
+                // the offset of the previous field is.
+                let bitfield_bit_size =
+                    bitfield_bits.known().expect("Expected known bitfield bits");
+                let prev_fields_bits =
+                    prev_fields.iter().map(Field::occupied_bits).sum::<BitsExpr>().known();
+                if prev_fields_bits == Some(bitfield_bit_size - prev_field_offset) {
+                    let total_bits = bitfield_bit_size - prev_field_offset + bits;
+                    return Err(create_user_parsing_compiler_error(
+                        field_tokens.span(),
+                        format!(
+                            "The total bits of the fields ({total_bits} bits) exceeds the bit \
+                             size of the bitfield type ({bitfield_bit_size} bits)."
+                        ),
+                    ));
+                }
+
+                // The previous fields don't fill the bits above the field, so
+                // it follows a field with an explicit bit position. Its bits
+                // below bit 0 are reported as negative.
+                let bits_start = i64::from(prev_field_offset) - i64::from(bits);
+                let bits_end = i64::from(prev_field_offset) - 1;
                 Err(create_user_parsing_compiler_error(
                     field_tokens.span(),
                     format!(
-                        "The total bits of the fields ({total_bits} bits) exceeds the bit size of \
-                         the bitfield type ({bitfield_bit_size} bits)."
+                        "The bits '{bits_start}..={bits_end}' of field '{}' run past bit 0 of the \
+                         bitfield type ({bitfield_bit_size} bits).",
+                        field_tokens.ident.as_ref().expect("Expected field identifier")
                     ),
                 ))
             }
@@ -417,6 +1700,317 @@ fn calculate_field_offset(
     }
 }
 
+/// Checks that a field with an explicit bit position fits in the bitfield
+/// type, returning its offset.
+///
+/// Explicit bit positions are counted from the least significant bit,
+/// regardless of the bit order of the bitfield.
+fn check_explicit_field_position(
+    bitfield_attribute: &BitfieldAttribute,
+    field_tokens: &syn::Field,
+    offset: u32,
+    bits: &BitsExpr,
+    explicit_position_span: Span,
+) -> syn::Result<BitsExpr> {
+    let Some(bits) = bits.known() else {
+        return Err(create_user_parsing_compiler_error(
+            field_tokens.ty.span(),
+            "Fields with an explicit bit position require a defined bit size, add the bits to the \
+             '#[bits]' attribute.",
+        ));
+    };
+
+    let bitfield_bit_size = get_known_bitfield_bit_size(
+        bitfield_attribute,
+        explicit_position_span,
+        "Fields with an explicit bit position",
+    )?;
+    let bits_end = u64::from(offset) + u64::from(bits) - 1;
+    if bits_end >= u64::from(bitfield_bit_size) {
+        return Err(create_user_parsing_compiler_error(
+            explicit_position_span,
+            format!(
+                "The bits '{offset}..={bits_end}' of field '{}' exceed the bit size of the \
+                 bitfield type ({bitfield_bit_size} bits).",
+                field_tokens.ident.as_ref().expect("Expected field identifier")
+            ),
+        ));
+    }
+
+    Ok(BitsExpr::Known(offset))
+}
+
+/// Calculates the offset of an alias field, checking that its bits are within
+/// the bits of the aliased field.
+///
+/// Without an explicit bit position, the alias starts at the offset of the
+/// aliased field.
+fn calculate_alias_field_offset(
+    field_tokens: &syn::Field,
+    alias_of: &SpannedToken,
+    bits: &BitsExpr,
+    explicit_offset: Option<u32>,
+    explicit_position_span: Option<Span>,
+    fields: &[Field],
+) -> syn::Result<BitsExpr> {
+    let field_name = field_tokens.ident.as_ref().expect("Expected field identifier");
+    let Some(aliased_field) =
+        fields.iter().find(|field| !field.is_alias() && field.name() == alias_of.token())
+    else {
+        return Err(create_user_parsing_compiler_error(
+            alias_of.span(),
+            format!(
+                "Unknown aliased field '{}', an alias must refer to a non-alias field of the \
+                 bitfield.",
+                alias_of.token()
+            ),
+        ));
+    };
+
+    let (Some(aliased_field_offset), Some(aliased_field_bits)) =
+        (aliased_field.offset().known(), aliased_field.bits().known())
+    else {
+        return Err(create_user_parsing_compiler_error(
+            alias_of.span(),
+            format!(
+                "The aliased field '{}' requires a defined bit size, add the bits to its \
+                 '#[bits]' attribute.",
+                aliased_field.name()
+            ),
+        ));
+    };
+    let Some(bits) = bits.known() else {
+        return Err(create_user_parsing_compiler_error(
+            field_tokens.ty.span(),
+            "Alias fields require a defined bit size, add the bits to the '#[bits]' attribute.",
+        ));
+    };
+
+    let offset = explicit_offset.unwrap_or(aliased_field_offset);
+    let bits_end = u64::from(offset) + u64::from(bits) - 1;
+    let aliased_field_bits_end = aliased_field_offset + aliased_field_bits - 1;
+    if offset < aliased_field_offset || bits_end > u64::from(aliased_field_bits_end) {
+        return Err(create_user_parsing_compiler_error(
+            explicit_position_span.unwrap_or_else(|| field_tokens.span()),
+            format!(
+                "The bits '{offset}..={bits_end}' of alias field '{field_name}' are outside the \
+                 bits '{aliased_field_offset}..={aliased_field_bits_end}' of the aliased field \
+                 '{}'.",
+                aliased_field.name()
+            ),
+        ));
+    }
+
+    Ok(BitsExpr::Known(offset))
+}
+
+/// Checks that the fields of a bitfield with explicit bit positions don't
+/// overlap or exceed the bitfield type.
+fn check_field_positions(
+    bitfield_attribute: &BitfieldAttribute,
+    fields: &[Field],
+    field_position_spans: &[Span],
+) -> syn::Result<()> {
+    let bitfield_bit_size = bitfield_attribute
+        .spanned_data_type_token()
+        .bits()
+        .known()
+        .expect("Expected known bitfield bits");
+    let mut field_ranges = Vec::with_capacity(fields.len());
+    for (field, field_position_span) in fields.iter().zip(field_position_spans) {
+        if field.bits().known().is_none() {
+            return Err(create_user_parsing_compiler_error(
+                field.spanned_data_type_token().span(),
+                "Fields of a bitfield with explicit bit positions require a defined bit size, add \
+                 the bits to the '#[bits]' attribute.",
+            ));
+        }
+        for (offset, bits) in field.bit_ranges() {
+            let offset = offset.known().expect("Expected known offset for known bits");
+            let bits = bits.known().expect("Expected known bits");
+            let bits_end = u64::from(offset) + u64::from(bits) - 1;
+            if bits_end >= u64::from(bitfield_bit_size) {
+                return Err(create_user_parsing_compiler_error(
+                    *field_position_span,
+                    format!(
+                        "The bits '{offset}..={bits_end}' of field '{}' exceed the bit size of \
+                         the bitfield type ({bitfield_bit_size} bits).",
+                        field.name(),
+                    ),
+                ));
+            }
+            field_ranges.push((offset, offset + bits - 1, field, *field_position_span));
+        }
+    }
+
+    field_ranges.sort_by_key(|(offset, ..)| *offset);
+
+    let mut field_ranges_iter = field_ranges.iter();
+    let Some(mut widest_field_range) = field_ranges_iter.next() else {
+        return Ok(());
+    };
+    for field_range in field_ranges_iter {
+        let (offset, bits_end, field, field_position_span) = field_range;
+        let (prev_offset, prev_bits_end, prev_field, prev_field_position_span) = widest_field_range;
+
+        if offset <= prev_bits_end {
+            let mut error = create_user_parsing_compiler_error(
+                *field_position_span,
+                format!(
+                    "The bits '{offset}..={bits_end}' of field '{}' overlap the bits \
+                     '{prev_offset}..={prev_bits_end}' of field '{}'.",
+                    field.name(),
+                    prev_field.name()
+                ),
+            );
+            error.combine(create_user_parsing_compiler_error(
+                *prev_field_position_span,
+                format!(
+                    "The field '{}' occupies the bits '{prev_offset}..={prev_bits_end}'.",
+                    prev_field.name()
+                ),
+            ));
+            return Err(error);
+        }
+
+        if bits_end > prev_bits_end {
+            widest_field_range = field_range;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the bit size of the bitfield type, which must be known by the macro
+/// to lay out the fields described by `fields_description`.
+fn get_known_bitfield_bit_size(
+    bitfield_attribute: &BitfieldAttribute,
+    span: Span,
+    fields_description: &str,
+) -> syn::Result<u32> {
+    bitfield_attribute.spanned_data_type_token().bits().known().ok_or_else(|| {
+        create_user_parsing_compiler_error(
+            span,
+            format!(
+                "{fields_description} require a bitfield array length known by the macro, const \
+                 paths can't be evaluated by the macro."
+            ),
+        )
+    })
+}
+
+/// Checks that the bits of the fields are known, as the bits filled by the
+/// `fill` argument are calculated by the macro.
+fn check_fill_field_bits(
+    bitfield_attribute: &BitfieldAttribute,
+    fields: &[Field],
+) -> syn::Result<()> {
+    get_known_bitfield_bit_size(
+        bitfield_attribute,
+        bitfield_attribute.spanned_data_type_token().span(),
+        "Bitfields with the `fill` argument",
+    )?;
+    match fields.iter().find(|field| field.bits().known().is_none()) {
+        Some(field) => Err(create_user_parsing_compiler_error(
+            field.spanned_data_type_token().span(),
+            "Fields of a bitfield with the `fill` argument require bits known by the macro, const \
+             paths can't be evaluated by the macro.",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Generates reserved fields for the bits that aren't occupied by any field
+/// in a bitfield with explicit bit positions or the `fill` argument.
+fn generate_implicit_reserved_fields(
+    bitfield_attribute: &BitfieldAttribute,
+    fields: &[Field],
+) -> Vec<Field> {
+    let bitfield_bit_size = bitfield_attribute
+        .spanned_data_type_token()
+        .bits()
+        .known()
+        .expect("Expected known bitfield bits");
+    let mut occupied_field_ranges: Vec<(u32, u32)> = fields
+        .iter()
+        .flat_map(Field::bit_ranges)
+        .map(|(offset, bits)| {
+            let offset = offset.known().expect("Expected known offset");
+            let bits = bits.known().expect("Expected known bits");
+            (offset, offset + bits)
+        })
+        .collect();
+    occupied_field_ranges.sort_unstable();
+
+    let mut gaps: Vec<(u32, u32)> = Vec::new();
+    let mut next_unoccupied_bit = 0;
+    for (offset, end) in occupied_field_ranges {
+        if offset > next_unoccupied_bit {
+            gaps.push((next_unoccupied_bit, offset));
+        }
+        next_unoccupied_bit = next_unoccupied_bit.max(end);
+    }
+    if next_unoccupied_bit < bitfield_bit_size {
+        gaps.push((next_unoccupied_bit, bitfield_bit_size));
+    }
+
+    let span = bitfield_attribute.spanned_data_type_token().span();
+    let fill = bitfield_attribute.arguments().fill().unwrap_or(BitfieldFill::Reserved);
+    gaps.into_iter()
+        .flat_map(|(gap_start, gap_end)| {
+            // Gaps larger than the biggest integer type are split.
+            (gap_start..gap_end).step_by(u128::BITS as usize).map(move |offset| {
+                let bits = (gap_end - offset).min(u128::BITS);
+                generate_implicit_reserved_field(offset, bits, span, fill)
+            })
+        })
+        .collect()
+}
+
+/// Generates a reserved field occupying the given bits, which are set to ones
+/// by default with the `ReservedOnes` fill.
+fn generate_implicit_reserved_field(
+    offset: u32,
+    bits: u32,
+    span: Span,
+    fill: BitfieldFill,
+) -> Field {
+    let field_type: syn::Type = match bits {
+        0..=8 => syn::parse_quote_spanned!(span => u8),
+        9..=16 => syn::parse_quote_spanned!(span => u16),
+        17..=32 => syn::parse_quote_spanned!(span => u32),
+        33..=64 => syn::parse_quote_spanned!(span => u64),
+        _ => syn::parse_quote_spanned!(span => u128),
+    };
+    let spanned_data_type_token = SpannedDataTypeToken::new(&field_type)
+        .expect("Expected integer type for implicit reserved field");
+
+    let (access, arguments) = match fill {
+        BitfieldFill::Reserved => (FieldAccess::NoAccess, None),
+        BitfieldFill::ReservedOnes => {
+            let ones = u128::MAX >> (u128::BITS - bits);
+            let default_value_expr = ConstExpr::new(&SpannedToken::new(format!("{ones:#X}"), span))
+                .expect("Expected valid default value for implicit reserved field");
+            (
+                FieldAccess::ReadOnly,
+                Some(BitsArguments::with_default_value_expr(default_value_expr)),
+            )
+        },
+    };
+
+    Field::new(
+        Visibility::Private,
+        Ident::new("__", span),
+        spanned_data_type_token,
+        BitsExpr::Known(bits),
+        BitsExpr::Known(offset),
+        /* reserved= */ true,
+        access,
+        arguments,
+        /* ignored= */ false,
+    )
+}
+
 /// Determine the effective access level for a field based on its
 /// `#[bits]` arguments and whether it is reserved.
 fn get_field_access(
@@ -450,6 +2044,39 @@ fn get_field_access(
         .access())
 }
 
+/// Checks that the `get` and `set` visibilities are only on fields with the
+/// accessors they apply to. Read-only fields keep their builder methods, so
+/// they accept the `set` argument.
+fn check_field_accessor_visibility(
+    bits_attribute: Option<&BitsAttribute>,
+    reserved: bool,
+    access: FieldAccess,
+) -> syn::Result<()> {
+    let Some(arguments) = bits_attribute.map(BitsAttribute::arguments) else {
+        return Ok(());
+    };
+
+    if let Some(getter_visibility_span) = arguments.getter_visibility_span() {
+        if reserved || matches!(access, FieldAccess::WriteOnly | FieldAccess::NoAccess) {
+            return Err(create_user_parsing_compiler_error(
+                getter_visibility_span,
+                "The `get` argument can only be used on fields with read access.",
+            ));
+        }
+    }
+
+    if let Some(setter_visibility_span) = arguments.setter_visibility_span() {
+        if reserved || access == FieldAccess::NoAccess {
+            return Err(create_user_parsing_compiler_error(
+                setter_visibility_span,
+                "The `set` argument can only be used on fields with write access.",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Get the visibility that should be applied to a field: if the field is
 /// private, inherit the bitfield's visibility; otherwise use the field's.
 fn get_field_visibility(bitfield_visibility: &Visibility, field_tokens: &syn::Field) -> Visibility {
@@ -464,12 +2091,26 @@ fn get_field_visibility(bitfield_visibility: &Visibility, field_tokens: &syn::Fi
 
 /// Ensure the total bits occupied by fields exactly match the bitfield type
 /// size. Returns an error if too many or few bits are used.
+///
+/// If the bits of a field are only known by the compiler, the check is left to
+/// the generated layout assertion.
 fn check_fields_fit_in_bitfield_type(
     bitfield_attribute: &BitfieldAttribute,
     fields: &[Field],
 ) -> syn::Result<()> {
-    let total_field_bits = fields.iter().map(Field::bits).sum::<u32>();
-    let bitfield_bit_size = bitfield_attribute.spanned_data_type_token().data_type().bit_size();
+    let Some(total_field_bits) = fields
+        .iter()
+        .filter(|field| !field.is_alias())
+        .map(Field::occupied_bits)
+        .sum::<BitsExpr>()
+        .known()
+    else {
+        return Ok(());
+    };
+    let Some(bitfield_bit_size) = bitfield_attribute.spanned_data_type_token().bits().known()
+    else {
+        return Ok(());
+    };
 
     match total_field_bits.cmp(&bitfield_bit_size) {
         Ordering::Greater => Err(create_user_parsing_compiler_error(
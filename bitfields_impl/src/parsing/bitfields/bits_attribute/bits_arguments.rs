@@ -2,16 +2,23 @@ use std::collections::HashSet;
 use std::str::FromStr;
 
 use getset::{CloneGetters, CopyGetters, Getters};
-use proc_macro2::Span;
+use proc_macro2::{Group, Ident, Span};
 use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
-use syn::parse::{Parse, ParseStream};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, Token};
 
 use crate::parsing::common::attribute_argument_parser::{
     parse_attribute_arguments, parse_boolean_attribute_argument,
 };
 use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
-use crate::parsing::common::const_expr::ConstExpr;
+use crate::parsing::common::const_expr::{ConstExpr, evaluate_literal_arithmetic};
+use crate::parsing::common::conversion_endian::ConversionEndian;
+use crate::parsing::common::overflow_policy::OverflowPolicy;
+use crate::parsing::common::spanned_token::SpannedToken;
+use crate::parsing::common::visibility::Visibility;
 
 /// Represents the access of a field.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -45,6 +52,164 @@ impl FromStr for FieldAccess {
     }
 }
 
+/// Represents how the value of a field is encoded in its bits.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FieldEncoding {
+    /// A sign bit followed by the magnitude of the value.
+    SignMagnitude,
+
+    /// The value offset by half the range of the field, which flips the sign
+    /// bit of the two's complement value.
+    OffsetBinary,
+
+    /// A decimal digit in each 4 bits.
+    Bcd,
+
+    /// The reflected binary Gray code of the value.
+    Gray,
+
+    /// The inverted bits of the value.
+    ActiveLow,
+}
+
+impl FromStr for FieldEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "sign_magnitude" => Ok(Self::SignMagnitude),
+            "offset_binary" => Ok(Self::OffsetBinary),
+            "bcd" => Ok(Self::Bcd),
+            "gray" => Ok(Self::Gray),
+            "active_low" => Ok(Self::ActiveLow),
+            _ => Err(format!(
+                "Invalid field encoding argument '{s}'. Valid values are 'sign_magnitude', \
+                 'offset_binary', 'bcd', 'gray', or 'active_low'."
+            )),
+        }
+    }
+}
+
+impl FieldEncoding {
+    /// Returns if the encoding stores the logical zero (or `false`) as zeroed
+    /// bits.
+    pub const fn stores_zero_as_zeroed_bits(self) -> bool {
+        !matches!(self, Self::OffsetBinary | Self::ActiveLow)
+    }
+}
+
+impl std::fmt::Display for FieldEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let encoding = match self {
+            Self::SignMagnitude => "sign_magnitude",
+            Self::OffsetBinary => "offset_binary",
+            Self::Bcd => "bcd",
+            Self::Gray => "gray",
+            Self::ActiveLow => "active_low",
+        };
+        write!(f, "{encoding}")
+    }
+}
+
+/// Represents a contiguous bit range of a split field.
+#[derive(Clone, Copy, Debug, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct FieldSegment {
+    /// The offset of the segment in the bitfield.
+    offset: u32,
+
+    /// The bits of the segment.
+    bits: u32,
+}
+
+/// Represents the scaling of a fixed-point or linearly scaled field, which has
+/// the value `raw * scale + offset`.
+#[derive(Clone, Debug, Getters, CopyGetters)]
+pub struct FieldScaling {
+    /// The scale of the raw value.
+    #[getset(get_copy = "pub")]
+    scale: f64,
+
+    /// The offset added to the scaled raw value.
+    #[getset(get_copy = "pub")]
+    offset: f64,
+
+    /// The fraction bits of a fixed-point field.
+    #[getset(get_copy = "pub")]
+    fixed: Option<u32>,
+
+    /// The unit of the value.
+    #[getset(get = "pub")]
+    unit: Option<String>,
+}
+
+/// Represents the values a field accepts.
+#[derive(Clone, Debug)]
+pub enum FieldConstraint {
+    /// The values of an inclusive range, e.g. `range = 1..=12`.
+    Range { start: i128, end: i128 },
+
+    /// A list of values, e.g. `values = [4, 8, 16]`.
+    Values(Vec<i128>),
+}
+
+impl FieldConstraint {
+    /// Returns if the value satisfies the constraint.
+    pub fn contains(&self, value: i128) -> bool {
+        match self {
+            Self::Range {
+                start,
+                end,
+            } => (*start..=*end).contains(&value),
+            Self::Values(values) => values.contains(&value),
+        }
+    }
+
+    /// Returns the values of the constraint which are checked to fit in the
+    /// field, the bounds of a range or every listed value.
+    pub fn bound_values(&self) -> Vec<i128> {
+        match self {
+            Self::Range {
+                start,
+                end,
+            } => vec![*start, *end],
+            Self::Values(values) => values.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for FieldConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Range {
+                start,
+                end,
+            } => write!(f, "range = {start}..={end}"),
+            Self::Values(values) => {
+                let values =
+                    values.iter().map(ToString::to_string).collect::<Vec<String>>().join(", ");
+                write!(f, "values = [{values}]")
+            },
+        }
+    }
+}
+
+/// Represents a named value of a field with enumerated values, e.g. `Video =
+/// 1`, which becomes a variant of the enum generated for the field.
+#[derive(Clone, Debug, Getters, CopyGetters)]
+pub struct FieldEnumeratedValue {
+    /// The name of the variant.
+    #[getset(get = "pub")]
+    name_ident: Ident,
+
+    /// The bits of the variant.
+    #[getset(get_copy = "pub")]
+    value: u128,
+
+    #[getset(get_copy = "pub")]
+    value_span: Span,
+}
+
 /// Represents the arguments of the `#[bits]` attribute.
 #[derive(Clone, Debug, Getters, CopyGetters, CloneGetters)]
 pub struct BitsArguments {
@@ -65,6 +230,216 @@ pub struct BitsArguments {
     /// The field default value expression.
     #[getset(get_clone = "pub")]
     default_value_expr: Option<ConstExpr>,
+
+    /// The explicit bit position of the field.
+    #[getset(get_copy = "pub")]
+    at: Option<u32>,
+
+    #[getset(get_copy = "pub")]
+    at_span: Option<Span>,
+
+    /// The name of the field this field is an alias of.
+    #[getset(get_clone = "pub")]
+    alias_of: Option<SpannedToken>,
+
+    /// The segments of a split field, from the most significant segment.
+    #[getset(get_clone = "pub")]
+    segments: Option<Vec<FieldSegment>>,
+
+    #[getset(get_copy = "pub")]
+    segments_span: Option<Span>,
+
+    /// The number of implicit zero bits below the segments of a split field.
+    #[getset(get_copy = "pub")]
+    shift: Option<u32>,
+
+    #[getset(get_copy = "pub")]
+    shift_span: Option<Span>,
+
+    /// The bits of each element of a packed array field.
+    #[getset(get_copy = "pub")]
+    element: Option<u32>,
+
+    #[getset(get_copy = "pub")]
+    element_span: Option<Span>,
+
+    /// Whether a float field is stored as a half-precision float.
+    #[getset(get_copy = "pub")]
+    half: bool,
+
+    #[getset(get_copy = "pub")]
+    half_span: Option<Span>,
+
+    /// The fraction bits of a fixed-point field.
+    #[getset(get_copy = "pub")]
+    fixed: Option<u32>,
+
+    #[getset(get_copy = "pub")]
+    fixed_span: Option<Span>,
+
+    /// The scale of a linearly scaled field.
+    #[getset(get_copy = "pub")]
+    scale: Option<f64>,
+
+    #[getset(get_copy = "pub")]
+    scale_span: Option<Span>,
+
+    /// The offset of a linearly scaled field.
+    #[getset(get_copy = "pub")]
+    scale_offset: Option<f64>,
+
+    #[getset(get_copy = "pub")]
+    scale_offset_span: Option<Span>,
+
+    /// The unit of a scaled field.
+    #[getset(get_clone = "pub")]
+    unit: Option<String>,
+
+    #[getset(get_copy = "pub")]
+    unit_span: Option<Span>,
+
+    /// The values the field accepts.
+    #[getset(get = "pub")]
+    constraint: Option<FieldConstraint>,
+
+    #[getset(get_copy = "pub")]
+    constraint_span: Option<Span>,
+
+    /// The encoding of the field value in its bits.
+    #[getset(get_copy = "pub")]
+    encoding: Option<FieldEncoding>,
+
+    #[getset(get_copy = "pub")]
+    encoding_span: Option<Span>,
+
+    /// The byte order of the field.
+    #[getset(get_copy = "pub")]
+    endian: Option<ConversionEndian>,
+
+    #[getset(get_copy = "pub")]
+    endian_span: Option<Span>,
+
+    /// The bits of an `Option` field that represent `None`.
+    #[getset(get_copy = "pub")]
+    none: Option<u128>,
+
+    #[getset(get_copy = "pub")]
+    none_span: Option<Span>,
+
+    /// The named values of a field with enumerated values, from the
+    /// `values(..)` argument.
+    #[getset(get_clone = "pub")]
+    enumerated_values: Option<Vec<FieldEnumeratedValue>>,
+
+    #[getset(get_copy = "pub")]
+    enumerated_values_span: Option<Span>,
+
+    /// What the setters of the field do with values that don't fit in the
+    /// field bits, overriding the bitfield.
+    #[getset(get_copy = "pub")]
+    overflow: Option<OverflowPolicy>,
+
+    #[getset(get_copy = "pub")]
+    overflow_span: Option<Span>,
+
+    /// The visibility of the getters of the field, if it overrides the field
+    /// visibility.
+    #[getset(get_clone = "pub")]
+    getter_visibility: Option<Visibility>,
+
+    #[getset(get_copy = "pub")]
+    getter_visibility_span: Option<Span>,
+
+    /// The visibility of the setters of the field, if it overrides the field
+    /// visibility.
+    #[getset(get_clone = "pub")]
+    setter_visibility: Option<Visibility>,
+
+    #[getset(get_copy = "pub")]
+    setter_visibility_span: Option<Span>,
+}
+
+impl BitsArguments {
+    /// Creates the arguments of a field only defining a default value, such as
+    /// an implicit reserved field.
+    pub fn with_default_value_expr(default_value_expr: ConstExpr) -> Self {
+        Self {
+            default_value_expr: Some(default_value_expr),
+            ..Self::default()
+        }
+    }
+
+    /// Parses the named values of the `values(..)` argument (e.g. `values(A =
+    /// 0, B = 1)`), which are unsigned integer literals or arithmetic of them.
+    pub fn parse_enumerated_values(&mut self, group: &Group) -> syn::Result<()> {
+        let invalid_enumerated_value_error = |span: Span| {
+            create_user_parsing_compiler_error(
+                span,
+                "Invalid enumerated value, expected a variant name and an unsigned integer value \
+                 (e.g. `values(A = 0, B = 1)`).",
+            )
+        };
+        let exprs = Punctuated::<Expr, Token![,]>::parse_terminated
+            .parse2(group.stream())
+            .map_err(|err| invalid_enumerated_value_error(err.span()))?;
+        if exprs.is_empty() {
+            return Err(invalid_enumerated_value_error(group.span()));
+        }
+
+        let enumerated_values = exprs
+            .iter()
+            .map(|expr| {
+                let Expr::Assign(assign_expr) = expr else {
+                    return Err(invalid_enumerated_value_error(expr.span()));
+                };
+                let Expr::Path(name_path) = assign_expr.left.as_ref() else {
+                    return Err(invalid_enumerated_value_error(assign_expr.left.span()));
+                };
+                let Some(name_ident) = name_path.path.get_ident() else {
+                    return Err(invalid_enumerated_value_error(name_path.span()));
+                };
+                let value = evaluate_literal_arithmetic(&assign_expr.right).ok_or_else(|| {
+                    create_user_parsing_compiler_error(
+                        assign_expr.right.span(),
+                        "The enumerated value must be an unsigned integer literal or arithmetic \
+                         of them (e.g. `2 * 4`), const paths can't be evaluated by the macro.",
+                    )
+                })?;
+
+                Ok(FieldEnumeratedValue {
+                    name_ident: name_ident.clone(),
+                    value,
+                    value_span: assign_expr.right.span(),
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        self.enumerated_values = Some(enumerated_values);
+        self.enumerated_values_span = Some(group.span());
+        Ok(())
+    }
+
+    /// Returns the scaling of a fixed-point or linearly scaled field.
+    pub fn scaling(&self) -> Option<FieldScaling> {
+        if let Some(fixed) = self.fixed {
+            return Some(FieldScaling {
+                scale: 1.0 / (1u128 << fixed) as f64,
+                offset: 0.0,
+                fixed: Some(fixed),
+                unit: self.unit.clone(),
+            });
+        }
+        if self.scale.is_none() && self.scale_offset.is_none() {
+            return None;
+        }
+
+        Some(FieldScaling {
+            scale: self.scale.unwrap_or(1.0),
+            offset: self.scale_offset.unwrap_or(0.0),
+            fixed: None,
+            unit: self.unit.clone(),
+        })
+    }
 }
 
 impl Default for BitsArguments {
@@ -75,6 +450,41 @@ impl Default for BitsArguments {
             user_set_access: false,
             ignored: false,
             default_value_expr: None,
+            at: None,
+            at_span: None,
+            alias_of: None,
+            segments: None,
+            segments_span: None,
+            shift: None,
+            shift_span: None,
+            element: None,
+            element_span: None,
+            half: false,
+            half_span: None,
+            fixed: None,
+            fixed_span: None,
+            scale: None,
+            scale_span: None,
+            scale_offset: None,
+            scale_offset_span: None,
+            unit: None,
+            unit_span: None,
+            constraint: None,
+            constraint_span: None,
+            encoding: None,
+            encoding_span: None,
+            endian: None,
+            endian_span: None,
+            none: None,
+            none_span: None,
+            enumerated_values: None,
+            enumerated_values_span: None,
+            overflow: None,
+            overflow_span: None,
+            getter_visibility: None,
+            getter_visibility_span: None,
+            setter_visibility: None,
+            setter_visibility_span: None,
         }
     }
 }
@@ -89,6 +499,60 @@ enum BitsArgumentKey {
 
     #[strum(serialize = "default")]
     Default,
+
+    #[strum(serialize = "at")]
+    At,
+
+    #[strum(serialize = "alias_of")]
+    AliasOf,
+
+    #[strum(serialize = "segments")]
+    Segments,
+
+    #[strum(serialize = "shift")]
+    Shift,
+
+    #[strum(serialize = "element")]
+    Element,
+
+    #[strum(serialize = "half")]
+    Half,
+
+    #[strum(serialize = "fixed")]
+    Fixed,
+
+    #[strum(serialize = "scale")]
+    Scale,
+
+    #[strum(serialize = "offset")]
+    Offset,
+
+    #[strum(serialize = "unit")]
+    Unit,
+
+    #[strum(serialize = "range")]
+    Range,
+
+    #[strum(serialize = "values")]
+    Values,
+
+    #[strum(serialize = "encoding")]
+    Encoding,
+
+    #[strum(serialize = "endian")]
+    Endian,
+
+    #[strum(serialize = "none")]
+    None,
+
+    #[strum(serialize = "overflow")]
+    Overflow,
+
+    #[strum(serialize = "get")]
+    Get,
+
+    #[strum(serialize = "set")]
+    Set,
 }
 
 impl Parse for BitsArguments {
@@ -114,15 +578,378 @@ impl Parse for BitsArguments {
                     bits_arguments.user_set_access = true;
                     bits_arguments.access_span = Some(argument.value().span());
                 },
+                BitsArgumentKey::Half => {
+                    bits_arguments.half_span = Some(argument.value().span());
+                    bits_arguments.half = parse_boolean_attribute_argument(argument)?;
+                },
                 BitsArgumentKey::Ignore => {
                     bits_arguments.ignored = parse_boolean_attribute_argument(argument)?;
                 },
                 BitsArgumentKey::Default => {
                     bits_arguments.default_value_expr = Some(ConstExpr::new(&argument.value())?);
                 },
+                BitsArgumentKey::At => {
+                    let at = argument.value().token().parse::<u32>().map_err(|_| {
+                        create_user_parsing_compiler_error(
+                            argument.value().span(),
+                            format!(
+                                "Invalid bit position '{}', expected an unsigned integer without \
+                                 a suffix.",
+                                argument.value().token()
+                            ),
+                        )
+                    })?;
+                    bits_arguments.at = Some(at);
+                    bits_arguments.at_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::AliasOf => {
+                    if syn::parse_str::<syn::Ident>(argument.value().token().as_str()).is_err() {
+                        return Err(create_user_parsing_compiler_error(
+                            argument.value().span(),
+                            format!(
+                                "Invalid aliased field '{}', expected a field name.",
+                                argument.value().token()
+                            ),
+                        ));
+                    }
+                    bits_arguments.alias_of = Some(argument.value());
+                },
+                BitsArgumentKey::Segments => {
+                    bits_arguments.segments = Some(parse_segments(&argument.value())?);
+                    bits_arguments.segments_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Shift => {
+                    let shift = argument.value().token().parse::<u32>().map_err(|_| {
+                        create_user_parsing_compiler_error(
+                            argument.value().span(),
+                            format!(
+                                "Invalid shift '{}', expected an unsigned integer without a \
+                                 suffix.",
+                                argument.value().token()
+                            ),
+                        )
+                    })?;
+                    bits_arguments.shift = Some(shift);
+                    bits_arguments.shift_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Element => {
+                    let element = argument
+                        .value()
+                        .token()
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|element| *element > 0)
+                        .ok_or_else(|| {
+                            create_user_parsing_compiler_error(
+                                argument.value().span(),
+                                format!(
+                                    "Invalid element bits '{}', expected an unsigned integer \
+                                     greater than 0 without a suffix.",
+                                    argument.value().token()
+                                ),
+                            )
+                        })?;
+                    bits_arguments.element = Some(element);
+                    bits_arguments.element_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Fixed => {
+                    let fixed = argument
+                        .value()
+                        .token()
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|fixed| (1..128).contains(fixed))
+                        .ok_or_else(|| {
+                            create_user_parsing_compiler_error(
+                                argument.value().span(),
+                                format!(
+                                    "Invalid fraction bits '{}', expected an unsigned integer \
+                                     between 1 and 127 without a suffix.",
+                                    argument.value().token()
+                                ),
+                            )
+                        })?;
+                    bits_arguments.fixed = Some(fixed);
+                    bits_arguments.fixed_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Scale => {
+                    let scale = parse_number(&argument.value())
+                        .filter(|scale| *scale != 0.0)
+                        .ok_or_else(|| {
+                            create_user_parsing_compiler_error(
+                                argument.value().span(),
+                                format!(
+                                    "Invalid scale '{}', expected a non-zero number without a \
+                                     suffix.",
+                                    argument.value().token()
+                                ),
+                            )
+                        })?;
+                    bits_arguments.scale = Some(scale);
+                    bits_arguments.scale_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Offset => {
+                    let offset = parse_number(&argument.value()).ok_or_else(|| {
+                        create_user_parsing_compiler_error(
+                            argument.value().span(),
+                            format!(
+                                "Invalid offset '{}', expected a number without a suffix.",
+                                argument.value().token()
+                            ),
+                        )
+                    })?;
+                    bits_arguments.scale_offset = Some(offset);
+                    bits_arguments.scale_offset_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Unit => {
+                    let unit =
+                        syn::parse_str::<syn::LitStr>(&argument.value().token()).map_err(|_| {
+                            create_user_parsing_compiler_error(
+                                argument.value().span(),
+                                format!(
+                                    "Invalid unit '{}', expected a string literal (e.g. `unit = \
+                                     \"mV\"`).",
+                                    argument.value().token()
+                                ),
+                            )
+                        })?;
+                    bits_arguments.unit = Some(unit.value());
+                    bits_arguments.unit_span = Some(argument.value().span());
+                },
+                key @ (BitsArgumentKey::Range | BitsArgumentKey::Values) => {
+                    if bits_arguments.constraint.is_some() {
+                        return Err(create_user_parsing_compiler_error(
+                            argument.key().span(),
+                            "A field can only have one `range` or `values` constraint.",
+                        ));
+                    }
+                    let constraint = if matches!(key, BitsArgumentKey::Range) {
+                        parse_range_constraint(&argument.value())?
+                    } else {
+                        parse_values_constraint(&argument.value())?
+                    };
+                    bits_arguments.constraint = Some(constraint);
+                    bits_arguments.constraint_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Encoding => {
+                    bits_arguments.encoding =
+                        Some(FieldEncoding::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?);
+                    bits_arguments.encoding_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Endian => {
+                    bits_arguments.endian = Some(
+                        ConversionEndian::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?,
+                    );
+                    bits_arguments.endian_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::None => {
+                    let none = syn::parse_str::<syn::LitInt>(&argument.value().token())
+                        .ok()
+                        .filter(|lit| lit.suffix().is_empty())
+                        .and_then(|lit| lit.base10_parse::<u128>().ok())
+                        .ok_or_else(|| {
+                            create_user_parsing_compiler_error(
+                                argument.value().span(),
+                                format!(
+                                    "Invalid none value '{}', expected an unsigned integer \
+                                     without a suffix (e.g. `none = 0xFF`).",
+                                    argument.value().token()
+                                ),
+                            )
+                        })?;
+                    bits_arguments.none = Some(none);
+                    bits_arguments.none_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Overflow => {
+                    bits_arguments.overflow =
+                        Some(OverflowPolicy::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?);
+                    bits_arguments.overflow_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Get => {
+                    bits_arguments.getter_visibility =
+                        Some(Visibility::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?);
+                    bits_arguments.getter_visibility_span = Some(argument.value().span());
+                },
+                BitsArgumentKey::Set => {
+                    bits_arguments.setter_visibility =
+                        Some(Visibility::from_str(argument.value().token().as_str()).map_err(
+                            |err| create_user_parsing_compiler_error(argument.value().span(), err),
+                        )?);
+                    bits_arguments.setter_visibility_span = Some(argument.value().span());
+                },
             }
         }
 
         Ok(bits_arguments)
     }
 }
+
+/// Parses the `range` constraint of a field, e.g. `1..=12` or `1..13`.
+fn parse_range_constraint(value: &SpannedToken) -> syn::Result<FieldConstraint> {
+    let token = value.token().replace(' ', "");
+    let invalid_range_error = || {
+        create_user_parsing_compiler_error(
+            value.span(),
+            format!("Invalid range '{token}', expected a range of integers (e.g. `1..=12`)."),
+        )
+    };
+
+    let Ok(syn::Expr::Range(syn::ExprRange {
+        start: Some(start),
+        limits,
+        end: Some(end),
+        ..
+    })) = syn::parse_str::<syn::Expr>(&token)
+    else {
+        return Err(invalid_range_error());
+    };
+    let start = parse_constraint_value(&start).ok_or_else(invalid_range_error)?;
+    let end = parse_constraint_value(&end).ok_or_else(invalid_range_error)?;
+    let end = match limits {
+        syn::RangeLimits::Closed(_) => Some(end),
+        syn::RangeLimits::HalfOpen(_) => end.checked_sub(1),
+    };
+
+    match end {
+        Some(end) if start <= end => Ok(FieldConstraint::Range {
+            start,
+            end,
+        }),
+        _ => Err(create_user_parsing_compiler_error(
+            value.span(),
+            format!("The range '{token}' doesn't contain any value."),
+        )),
+    }
+}
+
+/// Parses the `values` constraint of a field, e.g. `[4, 8, 16]`.
+fn parse_values_constraint(value: &SpannedToken) -> syn::Result<FieldConstraint> {
+    let invalid_values_error = || {
+        create_user_parsing_compiler_error(
+            value.span(),
+            format!(
+                "Invalid values '{}', expected a non-empty array of integers (e.g. `[4, 8, 16]`).",
+                value.token()
+            ),
+        )
+    };
+
+    let Ok(syn::Expr::Array(array)) = syn::parse_str::<syn::Expr>(&value.token()) else {
+        return Err(invalid_values_error());
+    };
+    let values = array
+        .elems
+        .iter()
+        .map(parse_constraint_value)
+        .collect::<Option<Vec<i128>>>()
+        .filter(|values| !values.is_empty())
+        .ok_or_else(invalid_values_error)?;
+
+    Ok(FieldConstraint::Values(values))
+}
+
+/// Parses an integer literal of a constraint without a suffix, which can be
+/// negative.
+fn parse_constraint_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit), ..
+        }) if lit.suffix().is_empty() => lit.base10_parse::<i128>().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_constraint_value(expr)?.checked_neg(),
+        _ => None,
+    }
+}
+
+/// Parses a decimal integer or float literal, which can be negative, e.g.
+/// `-40` or `0.1`.
+fn parse_number(value: &SpannedToken) -> Option<f64> {
+    let token = value.token().replace([' ', '_'], "");
+    let digits = token.strip_prefix('-').unwrap_or(&token);
+    if !digits.starts_with(|c: char| c.is_ascii_digit())
+        || !digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+    {
+        return None;
+    }
+
+    token.parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+/// Parses the segments of a split field, e.g. `[31, 7, 30..=25, 11..=8]`.
+fn parse_segments(value: &SpannedToken) -> syn::Result<Vec<FieldSegment>> {
+    let invalid_segments_error = || {
+        create_user_parsing_compiler_error(
+            value.span(),
+            format!(
+                "Invalid segments '{}', expected an array of bit positions and inclusive bit \
+                 ranges, e.g. `[31, 7, 30..=25, 11..=8]`.",
+                value.token()
+            ),
+        )
+    };
+
+    let segments_array =
+        syn::parse_str::<syn::ExprArray>(&value.token()).map_err(|_| invalid_segments_error())?;
+    if segments_array.elems.is_empty() {
+        return Err(invalid_segments_error());
+    }
+
+    segments_array
+        .elems
+        .iter()
+        .map(|segment_expr| {
+            let (start, end) = match segment_expr {
+                syn::Expr::Lit(_) => {
+                    let position = parse_segment_bit_position(segment_expr)
+                        .ok_or_else(invalid_segments_error)?;
+                    (position, position)
+                },
+                syn::Expr::Range(syn::ExprRange {
+                    start: Some(start),
+                    limits: syn::RangeLimits::Closed(_),
+                    end: Some(end),
+                    ..
+                }) => (
+                    parse_segment_bit_position(start).ok_or_else(invalid_segments_error)?,
+                    parse_segment_bit_position(end).ok_or_else(invalid_segments_error)?,
+                ),
+                _ => return Err(invalid_segments_error()),
+            };
+
+            // Segments can be written from the high bit (e.g. `30..=25`) like
+            // in instruction set manuals, or from the low bit.
+            let (offset, bits_end) = (start.min(end), start.max(end));
+            Ok(FieldSegment {
+                offset,
+                bits: bits_end - offset + 1,
+            })
+        })
+        .collect()
+}
+
+/// Parses the bit position of a segment.
+fn parse_segment_bit_position(expr: &syn::Expr) -> Option<u32> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit), ..
+    }) = expr
+    else {
+        return None;
+    };
+
+    if !lit.suffix().is_empty() {
+        return None;
+    }
+
+    lit.base10_parse::<u32>().ok()
+}
@@ -1,19 +1,30 @@
 //! Module for parsing the entire `#[bits]` attribute.
 
 use getset::{CloneGetters, Getters};
-use proc_macro2::Span;
-use syn::parse::{Parse, ParseStream};
-use syn::{LitInt, Token};
+use proc_macro2::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Expr, LitInt, RangeLimits, Token};
 
 use crate::parsing::bitfields::bits_attribute::bits_arguments::BitsArguments;
+use crate::parsing::common::bits_expr::BitsExpr;
 use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
+use crate::parsing::common::const_expr::evaluate_literal_arithmetic;
 
 /// Represents the `#[bits]` attribute.
 #[derive(Clone, Debug, Getters, CloneGetters)]
 #[getset(get_clone = "pub")]
 pub struct BitsAttribute {
     /// The bits of the attribute.
-    bits: Option<u32>,
+    bits: Option<BitsExpr>,
+
+    /// The explicit bit position (offset) of the field, from a `start..=end`
+    /// range or the `at` argument.
+    offset: Option<u32>,
+
+    /// The span of the explicit bit position.
+    offset_span: Option<Span>,
 
     /// The arguments of the attribute.
     arguments: BitsArguments,
@@ -25,29 +36,126 @@ pub struct BitsAttribute {
 impl Parse for BitsAttribute {
     /// Parse a `BitsAttribute` from the attribute token stream.
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let bits_and_span = Self::parse_bits(input)?;
-        let arguments = BitsArguments::parse(input)?;
-        let bits = bits_and_span
-            .is_some()
-            .then(|| bits_and_span.expect("Expected a bits and span for bits attribute").0);
-        let span = bits_and_span
-            .is_some()
-            .then(|| bits_and_span.expect("Expected a bits and span for bits attribute").1);
+        let (bits_tokens, arguments_tokens, enumerated_values_group) =
+            Self::split_bits_and_arguments_tokens(input)?;
+        let parsed_bits = Self::parse_bits.parse2(bits_tokens)?;
+        let mut arguments = syn::parse2::<BitsArguments>(arguments_tokens)?;
+        if let Some(enumerated_values_group) = enumerated_values_group {
+            arguments.parse_enumerated_values(&enumerated_values_group)?;
+        }
+        let bits = parsed_bits.as_ref().map(|parsed_bits| parsed_bits.bits.clone());
+        let span = parsed_bits.as_ref().map(|parsed_bits| parsed_bits.span);
+        let range_start = parsed_bits.and_then(|parsed_bits| parsed_bits.range_start);
+
+        let (offset, offset_span) = match (range_start, arguments.at()) {
+            (Some(_), Some(_)) => {
+                return Err(create_user_parsing_compiler_error(
+                    arguments.at_span().expect("Expected span for at argument"),
+                    "The bit position of the field is already defined by the bit range, remove \
+                     the 'at' argument.",
+                ));
+            },
+            (Some(range_start), None) => (Some(range_start), span),
+            (None, Some(at)) => (Some(at), arguments.at_span()),
+            (None, None) => (None, None),
+        };
+
         Ok(Self {
             bits,
+            offset,
+            offset_span,
             arguments,
             span,
         })
     }
 }
 
+/// Represents the leading bits of the `#[bits]` attribute.
+struct ParsedBits {
+    /// The bits of the field.
+    bits: BitsExpr,
+
+    /// The start of the bit range, if the bits were defined as a range.
+    range_start: Option<u32>,
+
+    /// The span of the bits or bit range.
+    span: Span,
+}
+
 impl BitsAttribute {
-    /// Parses an optional non-negative integer bit count from the input.
+    /// Splits the attribute into the bits tokens, the key-value arguments
+    /// tokens and the group of the `values(..)` argument.
+    ///
+    /// The bits (e.g. `8`, `ADDR_BITS + 4` or `4..=7`) are the only argument
+    /// without a key, so they can be placed anywhere in the attribute, e.g.
+    /// `#[bits(8, default = 1)]` or `#[bits(alias_of = ax, 8..=15)]`.
+    fn split_bits_and_arguments_tokens(
+        input: ParseStream,
+    ) -> syn::Result<(TokenStream, TokenStream, Option<Group>)> {
+        let tokens = input.parse::<TokenStream>()?;
+        let mut segments: Vec<Vec<TokenTree>> = vec![Vec::new()];
+        for token in tokens {
+            match &token {
+                TokenTree::Punct(punct) if punct.as_char() == ',' => segments.push(Vec::new()),
+                _ => segments.last_mut().expect("Expected a segment").push(token),
+            }
+        }
+
+        let enumerated_values_group = segments
+            .iter()
+            .position(|segment| Self::is_enumerated_values(segment))
+            .and_then(|index| match segments.remove(index).pop() {
+                Some(TokenTree::Group(group)) => Some(group),
+                _ => None,
+            });
+
+        let bits_segment_index =
+            segments.iter().position(|segment| !segment.is_empty() && !Self::is_argument(segment));
+        let bits_tokens = bits_segment_index
+            .map(|index| segments.remove(index).into_iter().collect())
+            .unwrap_or_default();
+
+        let comma = TokenTree::Punct(Punct::new(',', Spacing::Alone));
+        let arguments_tokens = segments
+            .into_iter()
+            .filter(|segment| !segment.is_empty())
+            .enumerate()
+            .flat_map(|(index, segment)| {
+                (index > 0).then(|| comma.clone()).into_iter().chain(segment)
+            })
+            .collect();
+
+        Ok((bits_tokens, arguments_tokens, enumerated_values_group))
+    }
+
+    /// Returns true if the segment is the `values(..)` argument of a field
+    /// with enumerated values, which isn't a key-value argument.
+    fn is_enumerated_values(segment: &[TokenTree]) -> bool {
+        matches!(
+            segment,
+            [TokenTree::Ident(ident), TokenTree::Group(group)]
+                if ident == "values" && group.delimiter() == Delimiter::Parenthesis
+        )
+    }
+
+    /// Returns true if the segment is a key-value argument (e.g. `default =
+    /// 1`).
+    fn is_argument(segment: &[TokenTree]) -> bool {
+        matches!(
+            segment,
+            [TokenTree::Ident(_), TokenTree::Punct(punct), ..]
+                if punct.as_char() == '=' && punct.spacing() == Spacing::Alone
+        )
+    }
+
+    /// Parses an optional bit count or inclusive bit range from the input.
     ///
-    /// Accepts either `<n>` or `<n>,` at the start of the attribute, where
-    /// `<n>` is a non-negative integer literal (e.g. `8`, `4`). If no
-    /// integer is present the bit count is `None`.
-    fn parse_bits(input: ParseStream) -> syn::Result<Option<(u32, Span)>> {
+    /// Accepts either `<n>`, `<n>,`, `<start>..=<end>`, or `<start>..=<end>,`
+    /// at the start of the attribute. The bit count `<n>` is a non-negative
+    /// integer literal or a const expression (e.g. `8`, `ADDR_BITS + 4`), while
+    /// `<start>` and `<end>` must be evaluated by the macro (e.g. `4..=7`). If
+    /// no bits are present the bit count is `None`.
+    fn parse_bits(input: ParseStream) -> syn::Result<Option<ParsedBits>> {
         if input.peek(Token![,]) {
             input.parse::<Token![,]>()?;
         }
@@ -59,12 +167,113 @@ impl BitsAttribute {
             ));
         }
 
-        if !input.peek(LitInt) {
+        if input.is_empty() {
             return Ok(None);
         }
 
-        let lit: LitInt = input.parse()?;
+        let expr: Expr = input.parse()?;
+        let parsed_bits = match &expr {
+            Expr::Range(range) => {
+                let RangeLimits::Closed(_) = range.limits else {
+                    return Err(create_user_parsing_compiler_error(
+                        range.limits.span(),
+                        "Invalid bit range, only inclusive ranges are supported (e.g. `4..=7`).",
+                    ));
+                };
+                let (Some(start_expr), Some(end_expr)) = (&range.start, &range.end) else {
+                    return Err(create_user_parsing_compiler_error(
+                        range.span(),
+                        "Invalid bit range, expected an unsigned integer for the start and end of \
+                         the range.",
+                    ));
+                };
+                let start = Self::parse_bit_position(start_expr)?;
+                let end = Self::parse_bit_position(end_expr)?;
+                let span = range.span();
+
+                if start > end {
+                    return Err(create_user_parsing_compiler_error(
+                        span,
+                        format!(
+                            "Invalid bit range '{start}..={end}', the start of the range must not \
+                             be greater than its end."
+                        ),
+                    ));
+                }
+
+                ParsedBits {
+                    bits: BitsExpr::Known(end - start + 1),
+                    range_start: Some(start),
+                    span,
+                }
+            },
+            expr => ParsedBits {
+                bits: Self::parse_bit_count(expr)?,
+                range_start: None,
+                span: expr.span(),
+            },
+        };
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Some(parsed_bits))
+    }
+
+    /// Parses a bit count, which is evaluated if it's a literal or arithmetic
+    /// of literals, and otherwise left to the compiler (e.g. `ADDR_BITS + 4`).
+    fn parse_bit_count(expr: &Expr) -> syn::Result<BitsExpr> {
+        match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit), ..
+            }) => Ok(BitsExpr::Known(Self::parse_bit_count_literal(lit)?)),
+            Expr::Lit(_) => Err(create_user_parsing_compiler_error(
+                expr.span(),
+                "Invalid bit count, expected an unsigned integer.".to_string(),
+            )),
+            _ => match evaluate_literal_arithmetic(expr) {
+                Some(bits) => Ok(BitsExpr::Known(Self::check_bit_count_size(bits, expr)?)),
+                None => Ok(BitsExpr::Deferred(quote! { #expr })),
+            },
+        }
+    }
+
+    /// Parses the start or end of a bit range, which must be a literal or
+    /// arithmetic of literals, as the bit position of the field is needed by
+    /// the macro.
+    fn parse_bit_position(expr: &Expr) -> syn::Result<u32> {
+        if let Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit), ..
+        }) = expr
+        {
+            return Self::parse_bit_count_literal(lit);
+        }
+
+        let Some(position) = evaluate_literal_arithmetic(expr) else {
+            return Err(create_user_parsing_compiler_error(
+                expr.span(),
+                "Invalid bit range, the start and end of the range must be unsigned integer \
+                 literals or arithmetic of them (e.g. `4..=7`).",
+            ));
+        };
+        Self::check_bit_count_size(position, expr)
+    }
+
+    /// Checks that an evaluated bit count or bit position fits in a `u32`.
+    fn check_bit_count_size(bits: u128, expr: &Expr) -> syn::Result<u32> {
+        u32::try_from(bits).map_err(|_| {
+            create_user_parsing_compiler_error(
+                expr.span(),
+                format!(
+                    "Bit count '{bits}' is too big, the maximum amount of bits is '4,294,967,295.'"
+                ),
+            )
+        })
+    }
 
+    /// Parses a bit count or bit position integer literal.
+    fn parse_bit_count_literal(lit: &LitInt) -> syn::Result<u32> {
         if !lit.suffix().is_empty() {
             return Err(create_user_parsing_compiler_error(
                 lit.span(),
@@ -72,19 +281,13 @@ impl BitsAttribute {
             ));
         }
 
-        let bits = lit.base10_parse::<u32>().map_err(|_| {
+        lit.base10_parse::<u32>().map_err(|_| {
             create_user_parsing_compiler_error(
                 lit.span(),
                 format!(
                     "Bit count '{lit}' is too big, the maximum amount of bits is '4,294,967,295.'"
                 ),
             )
-        })?;
-
-        if input.peek(Token![,]) {
-            input.parse::<Token![,]>()?;
-        }
-
-        Ok(Some((bits, lit.span())))
+        })
     }
 }
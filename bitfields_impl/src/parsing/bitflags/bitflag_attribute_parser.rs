@@ -69,7 +69,13 @@ impl BitflagAttribute {
         }
 
         match input.parse::<syn::Type>() {
-            Ok(ty) => SpannedDataTypeToken::new(&ty),
+            Ok(ty) => {
+                let spanned_data_type_token = SpannedDataTypeToken::new(&ty)?;
+                if spanned_data_type_token.float_bits().is_some() {
+                    return Err(TypeParsingError::UnexpectedFloat);
+                }
+                Ok(spanned_data_type_token)
+            },
             Err(err) => Err(TypeParsingError::NonType(err.to_string())),
         }
     }
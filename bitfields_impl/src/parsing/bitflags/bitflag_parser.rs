@@ -68,7 +68,14 @@ fn parse_variant_helper(variant: &Variant) -> syn::Result<BitflagVariant> {
             let token = quote!(#expr).to_string();
             let span = expr.span();
             let spanned_token = SpannedToken::new(token, span);
-            ConstExpr::new(&spanned_token)?
+            let value = ConstExpr::new(&spanned_token)?;
+            if matches!(value, ConstExpr::Float { .. }) {
+                return Err(create_user_parsing_compiler_error(span, "Float are not supported."));
+            }
+            if matches!(value, ConstExpr::Str { .. }) {
+                return Err(create_user_parsing_compiler_error(span, "Strings are not supported."));
+            }
+            value
         },
         None => {
             return Err(create_user_parsing_compiler_error(
@@ -85,6 +85,17 @@ pub fn parse_attribute_arguments(
             ));
         }
 
+        // Parse value — accept a visibility (e.g. `pub(crate)`), which isn't an
+        // expression
+        if input.peek(Token![pub]) {
+            let visibility: syn::Visibility = input.parse()?;
+            arguments.push(AttributeArgument::new(
+                SpannedToken::new(key.to_string(), key.span()),
+                SpannedToken::new(quote!(#visibility).to_string(), visibility.span()),
+            ));
+            continue;
+        }
+
         // Parse value — accept an ident/path (e.g. `lsb` or `CustomType::A`) or
         // a literal (e.g. `true`, `42`)
         let expr: syn::Expr = match input.parse() {
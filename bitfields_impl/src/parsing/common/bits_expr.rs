@@ -0,0 +1,77 @@
+use std::ops::{Add, Sub};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::parsing::common::to_tokens::ToTokens;
+
+/// Represents a number of bits, such as a field's bits or offset.
+///
+/// Most bits are known while parsing, but some depend on other types (e.g.
+/// the bits of a nested bitfield) and can only be evaluated by the compiler.
+#[derive(Clone, Debug)]
+pub enum BitsExpr {
+    /// The bits are known while parsing.
+    Known(u32),
+
+    /// The bits are a const expression evaluated by the compiler.
+    Deferred(TokenStream),
+}
+
+impl BitsExpr {
+    /// Returns the bits if known while parsing.
+    pub const fn known(&self) -> Option<u32> {
+        match self {
+            Self::Known(bits) => Some(*bits),
+            Self::Deferred(_) => None,
+        }
+    }
+}
+
+impl ToTokens for BitsExpr {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::Known(bits) => quote! { #bits },
+            Self::Deferred(expr) => quote! { (#expr) },
+        }
+    }
+}
+
+impl Add for BitsExpr {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match (&self, &rhs) {
+            (Self::Known(lhs), Self::Known(rhs)) => Self::Known(lhs + rhs),
+            (Self::Known(0), _) => rhs,
+            (_, Self::Known(0)) => self,
+            _ => {
+                let lhs_tokens = self.to_tokens();
+                let rhs_tokens = rhs.to_tokens();
+                Self::Deferred(quote! { #lhs_tokens + #rhs_tokens })
+            },
+        }
+    }
+}
+
+impl Sub for BitsExpr {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        match (&self, &rhs) {
+            (Self::Known(lhs), Self::Known(rhs)) => Self::Known(lhs - rhs),
+            (_, Self::Known(0)) => self,
+            _ => {
+                let lhs_tokens = self.to_tokens();
+                let rhs_tokens = rhs.to_tokens();
+                Self::Deferred(quote! { #lhs_tokens - #rhs_tokens })
+            },
+        }
+    }
+}
+
+impl std::iter::Sum for BitsExpr {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::Known(0), Add::add)
+    }
+}
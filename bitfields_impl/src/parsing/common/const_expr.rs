@@ -13,6 +13,7 @@ const INTEGER_IDENTIFIERS: [&str; 12] =
     ["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
 const BOOLEAN_IDENTIFIERS: [&str; 2] = ["true", "false"];
 const NEGATIVE_SIGN: &str = "-";
+const STRING_QUOTE: &str = "\"";
 
 /// Represents a constant expression.
 #[derive(Clone, Debug)]
@@ -20,6 +21,12 @@ pub enum ConstExpr {
     /// An integer or boolean literal with optional negation.
     Literal { number: u128, negative_sign: bool, spanned_token: SpannedToken },
 
+    /// A float literal with optional negation, only supported by float fields.
+    Float { value: f64, spanned_token: SpannedToken },
+
+    /// A string literal, only supported by ASCII string fields.
+    Str { value: String, spanned_token: SpannedToken },
+
     /// A constant path.
     Path { spanned_token: SpannedToken },
 }
@@ -59,10 +66,16 @@ impl IntegerPrefix {
 
 impl ConstExpr {
     pub fn new(spanned_token: &SpannedToken) -> syn::Result<Self> {
+        if spanned_token.token().trim().starts_with(STRING_QUOTE) {
+            return Self::parse_string(spanned_token);
+        }
+
         let trimmed_number_str =
             spanned_token.token().trim().replace([' ', '_'], "").to_ascii_lowercase();
 
-        Self::check_float(&trimmed_number_str, spanned_token.span())?;
+        if Self::is_float(&trimmed_number_str) {
+            return Self::parse_float(&trimmed_number_str, spanned_token);
+        }
 
         if Self::is_boolean(&trimmed_number_str) {
             return Ok(Self::parse_boolean(&trimmed_number_str, spanned_token));
@@ -77,19 +90,45 @@ impl ConstExpr {
         Ok(Self::parse_path(spanned_token))
     }
 
-    fn check_float(number_str: &str, span: Span) -> syn::Result<()> {
-        if !number_str.starts_with(&IntegerPrefix::Hex.prefix()) {
-            let contains_float_identifier =
-                FLOAT_IDENTIFIERS.iter().any(|&identifier| number_str.ends_with(identifier));
-            if number_str.contains(FLOAT_DOT) || contains_float_identifier {
-                return Err(create_user_parsing_compiler_error(
-                    span,
-                    "Float are not supported.".to_string(),
-                ));
-            }
+    fn is_float(number_str: &str) -> bool {
+        if number_str.starts_with(&IntegerPrefix::Hex.prefix()) {
+            return false;
+        }
+
+        let contains_float_identifier =
+            FLOAT_IDENTIFIERS.iter().any(|&identifier| number_str.ends_with(identifier));
+        number_str.contains(FLOAT_DOT) || contains_float_identifier
+    }
+
+    fn parse_float(number_str: &str, spanned_token: &SpannedToken) -> syn::Result<Self> {
+        let stripped_suffix_number_str = FLOAT_IDENTIFIERS
+            .iter()
+            .find_map(|&identifier| number_str.strip_suffix(identifier))
+            .unwrap_or(number_str);
+
+        match stripped_suffix_number_str.parse::<f64>() {
+            Ok(value) => Ok(Self::Float {
+                value,
+                spanned_token: spanned_token.clone(),
+            }),
+            Err(err) => Err(create_user_parsing_compiler_error(
+                spanned_token.span(),
+                format!("Failed to parse float literal: {err}"),
+            )),
         }
+    }
 
-        Ok(())
+    fn parse_string(spanned_token: &SpannedToken) -> syn::Result<Self> {
+        match syn::parse_str::<syn::LitStr>(&spanned_token.token()) {
+            Ok(lit_str) => Ok(Self::Str {
+                value: lit_str.value(),
+                spanned_token: spanned_token.clone(),
+            }),
+            Err(err) => Err(create_user_parsing_compiler_error(
+                spanned_token.span(),
+                format!("Failed to parse string literal: {err}"),
+            )),
+        }
     }
 
     fn is_boolean(number_str: &str) -> bool {
@@ -199,6 +238,12 @@ impl ConstExpr {
             Self::Literal {
                 spanned_token, ..
             }
+            | Self::Float {
+                spanned_token, ..
+            }
+            | Self::Str {
+                spanned_token, ..
+            }
             | Self::Path {
                 spanned_token,
             } => spanned_token.span(),
@@ -211,11 +256,23 @@ impl ToTokens for ConstExpr {
         match self {
             Self::Literal {
                 spanned_token, ..
+            }
+            | Self::Float {
+                spanned_token, ..
             } => {
                 let expr: Expr = syn::parse_str(&spanned_token.token())
                     .expect("Failed to parse previously accepted literal expression");
                 quote! { #expr }
             },
+            // String literals are only supported by ASCII string fields, which are
+            // checked to hold them at compile time.
+            Self::Str {
+                spanned_token, ..
+            } => {
+                let lit_str: syn::LitStr = syn::parse_str(&spanned_token.token())
+                    .expect("Failed to parse previously accepted string literal");
+                quote! { ::bitfields::AsciiStr::from_ascii(#lit_str) }
+            },
             Self::Path {
                 spanned_token,
             } => {
@@ -226,3 +283,46 @@ impl ToTokens for ConstExpr {
         }
     }
 }
+
+/// Evaluates an unsigned integer literal or simple arithmetic of them (e.g. `4
+/// * 8 + 2`).
+///
+/// Returns `None` if the expression refers to a const path or can't be
+/// evaluated without overflowing, in which case it's left to the compiler.
+pub fn evaluate_literal_arithmetic(expr: &Expr) -> Option<u128> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int), ..
+        }) => lit_int.base10_parse::<u128>().ok(),
+        Expr::Paren(syn::ExprParen {
+            expr, ..
+        })
+        | Expr::Group(syn::ExprGroup {
+            expr, ..
+        }) => evaluate_literal_arithmetic(expr),
+        Expr::Binary(syn::ExprBinary {
+            left,
+            op,
+            right,
+            ..
+        }) => {
+            let left = evaluate_literal_arithmetic(left)?;
+            let right = evaluate_literal_arithmetic(right)?;
+            match op {
+                syn::BinOp::Add(_) => left.checked_add(right),
+                syn::BinOp::Sub(_) => left.checked_sub(right),
+                syn::BinOp::Mul(_) => left.checked_mul(right),
+                syn::BinOp::Div(_) => left.checked_div(right),
+                syn::BinOp::Rem(_) => left.checked_rem(right),
+                syn::BinOp::Shl(_) => {
+                    u32::try_from(right).ok().and_then(|right| left.checked_shl(right))
+                },
+                syn::BinOp::Shr(_) => {
+                    u32::try_from(right).ok().and_then(|right| left.checked_shr(right))
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,109 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{GenericParam, Generics};
+
+/// Represents the generic parameters of an annotated struct, which are carried
+/// onto the generated struct and all generated `impl` blocks.
+#[derive(Clone, Debug, Default)]
+pub struct GenericParameters {
+    generics: Generics,
+}
+
+impl GenericParameters {
+    /// Creates a new [`GenericParameters`] instance.
+    pub fn new(generics: &Generics) -> Self {
+        Self {
+            generics: generics.clone(),
+        }
+    }
+
+    /// Returns if there are type parameters, which must be held by a
+    /// `PhantomData` as the struct only holds the bits.
+    pub fn has_type_parameters(&self) -> bool {
+        self.generics.type_params().next().is_some()
+    }
+
+    /// Returns if the tokens reference a type parameter.
+    pub fn is_type_parameter_dependent(&self, tokens: &TokenStream) -> bool {
+        tokens.clone().into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => {
+                self.generics.type_params().any(|type_param| type_param.ident == ident)
+            },
+            TokenTree::Group(group) => self.is_type_parameter_dependent(&group.stream()),
+            _ => false,
+        })
+    }
+
+    /// Returns if the tokens reference a type or const parameter.
+    pub fn is_parameter_dependent(&self, tokens: &TokenStream) -> bool {
+        tokens.clone().into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => {
+                self.generics.type_params().any(|type_param| type_param.ident == ident)
+                    || self.generics.const_params().any(|const_param| const_param.ident == ident)
+            },
+            TokenTree::Group(group) => self.is_parameter_dependent(&group.stream()),
+            _ => false,
+        })
+    }
+
+    /// Returns the parameters with their bounds, for `impl` blocks and struct
+    /// definitions (e.g. `<T: Kind, const N: usize>`).
+    pub fn impl_generics_tokens(&self) -> TokenStream {
+        let (impl_generics, _, _) = self.generics.split_for_impl();
+        quote! { #impl_generics }
+    }
+
+    /// Returns the parameters to name the type (e.g. `<T, N>`).
+    pub fn type_generics_tokens(&self) -> TokenStream {
+        let (_, type_generics, _) = self.generics.split_for_impl();
+        quote! { #type_generics }
+    }
+
+    /// Returns the parameters to name the type in expressions (e.g.
+    /// `::<T, N>`).
+    pub fn turbofish_tokens(&self) -> TokenStream {
+        let (_, type_generics, _) = self.generics.split_for_impl();
+        let turbofish = type_generics.as_turbofish();
+        quote! { #turbofish }
+    }
+
+    /// Returns the where clause, with the additional predicates.
+    pub fn where_clause_tokens(&self, additional_predicates: &[TokenStream]) -> TokenStream {
+        let predicates = self
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .map(|predicate| quote! { #predicate })
+            .chain(additional_predicates.iter().cloned())
+            .collect::<Vec<_>>();
+
+        if predicates.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            where #( #predicates, )*
+        }
+    }
+
+    /// Returns the `PhantomData` type holding the type parameters, or `None`
+    /// without type parameters.
+    pub fn phantom_data_type_tokens(&self) -> Option<TokenStream> {
+        let type_param_idents = self
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(type_param) => Some(&type_param.ident),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        (!type_param_idents.is_empty()).then(|| {
+            quote! {
+                ::core::marker::PhantomData<( #( #type_param_idents, )* )>
+            }
+        })
+    }
+}
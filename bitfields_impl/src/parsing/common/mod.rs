@@ -1,7 +1,10 @@
 pub mod attribute_argument_parser;
+pub mod bits_expr;
 pub mod compiler_error;
 pub mod const_expr;
 pub mod conversion_endian;
+pub mod generic_parameters;
+pub mod overflow_policy;
 pub mod spanned_data_type;
 pub mod spanned_token;
 pub mod to_tokens;
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+/// What the setters of a field do with values that don't fit in the field
+/// bits.
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum OverflowPolicy {
+    /// The bits that don't fit are discarded.
+    Truncate,
+
+    /// The value is clamped to the bounds of the field.
+    Saturate,
+
+    /// The setter panics.
+    Panic,
+
+    /// The setter panics in debug builds and truncates in release builds.
+    DebugAssert,
+}
+
+impl FromStr for OverflowPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "truncate" => Ok(Self::Truncate),
+            "saturate" => Ok(Self::Saturate),
+            "panic" => Ok(Self::Panic),
+            "debug_assert" => Ok(Self::DebugAssert),
+            _ => Err(format!(
+                "Invalid overflow argument '{s}'. Valid values are 'truncate', 'saturate', \
+                 'panic' or 'debug_assert'."
+            )),
+        }
+    }
+}
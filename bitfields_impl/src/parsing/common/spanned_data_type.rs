@@ -1,11 +1,14 @@
 use std::cmp::PartialEq;
 
 use derive_more::Display;
+use getset::CopyGetters;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::Type;
 use syn::spanned::Spanned;
 
+use crate::parsing::common::bits_expr::BitsExpr;
+use crate::parsing::common::const_expr::evaluate_literal_arithmetic;
 use crate::parsing::common::spanned_token::SpannedToken;
 use crate::parsing::common::to_tokens::ToTokens;
 use crate::parsing::common::type_parse_error::TypeParsingError;
@@ -17,6 +20,9 @@ pub struct SpannedDataTypeToken {
     data_type: DataType,
     spanned_token: SpannedToken,
     original_type_tokens: TokenStream,
+    float_bits: Option<u32>,
+    non_zero_bits: Option<u32>,
+    arbitrary_int: Option<ArbitraryIntType>,
 }
 
 impl SpannedDataTypeToken {
@@ -25,11 +31,94 @@ impl SpannedDataTypeToken {
         let str_repr = Self::get_syn_type_string(syn_type);
         let data_type = Self::get_data_type(syn_type)?;
         let original_type_tokens = quote! { #syn_type };
+        let float_bits = Self::get_float_bits(syn_type);
+        let non_zero_bits = Self::get_non_zero_bits(syn_type);
+        let arbitrary_int = Self::get_arbitrary_int(syn_type);
 
         Ok(Self {
             data_type,
             spanned_token: SpannedToken::new(str_repr, syn_type.span()),
             original_type_tokens,
+            float_bits,
+            non_zero_bits,
+            arbitrary_int,
+        })
+    }
+
+    /// Returns the bits of a float type, which are stored as their IEEE 754
+    /// bits through their `BitfieldField` implementation.
+    fn get_float_bits(syn_type: &Type) -> Option<u32> {
+        let Type::Path(type_path) = syn_type else {
+            return None;
+        };
+
+        match type_path.path.segments.last()?.ident.to_string().as_str() {
+            "f32" => Some(32),
+            "f64" => Some(64),
+            _ => None,
+        }
+    }
+
+    /// Returns the bits of a non-zero unsigned integer type, which are stored
+    /// as their integer bits through their `BitfieldField` implementation.
+    fn get_non_zero_bits(syn_type: &Type) -> Option<u32> {
+        let Type::Path(type_path) = syn_type else {
+            return None;
+        };
+
+        match type_path.path.segments.last()?.ident.to_string().as_str() {
+            "NonZeroU8" => Some(8),
+            "NonZeroU16" => Some(16),
+            "NonZeroU32" => Some(32),
+            "NonZeroU64" => Some(64),
+            "NonZeroU128" => Some(128),
+            _ => None,
+        }
+    }
+
+    /// Returns the width and signedness of an arbitrary-width integer type,
+    /// either an alias (e.g. `u4` or `i12`) or `UInt<T, N>` and `Int<T, N>`
+    /// with a literal width.
+    fn get_arbitrary_int(syn_type: &Type) -> Option<ArbitraryIntType> {
+        let Type::Path(type_path) = syn_type else {
+            return None;
+        };
+        let last_segment = type_path.path.segments.last()?;
+        let type_name = last_segment.ident.to_string();
+
+        let (signed, bits) = match &last_segment.arguments {
+            syn::PathArguments::None => {
+                let (signed, bits_str) = match type_name.split_at_checked(1)? {
+                    ("u", bits_str) => (false, bits_str),
+                    ("i", bits_str) => (true, bits_str),
+                    _ => return None,
+                };
+                // Primitive integer widths and leading zeros aren't aliases.
+                let bits = bits_str.parse::<u32>().ok()?;
+                if bits_str.starts_with('0') || matches!(bits, 8 | 16 | 32 | 64 | 128) {
+                    return None;
+                }
+                (signed, bits)
+            },
+            syn::PathArguments::AngleBracketed(arguments) if arguments.args.len() == 2 => {
+                let signed = match type_name.as_str() {
+                    "UInt" => false,
+                    "Int" => true,
+                    _ => return None,
+                };
+                let syn::GenericArgument::Const(bits_expr) = arguments.args.last()? else {
+                    return None;
+                };
+                (signed, u32::try_from(evaluate_literal_arithmetic(bits_expr)?).ok()?)
+            },
+            syn::PathArguments::AngleBracketed(_) | syn::PathArguments::Parenthesized(_) => {
+                return None;
+            },
+        };
+
+        (1..=128).contains(&bits).then_some(ArbitraryIntType {
+            bits,
+            signed,
         })
     }
 
@@ -51,10 +140,6 @@ impl SpannedDataTypeToken {
                 let type_name =
                     type_path.path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
 
-                if matches!(type_name.as_str(), "f32" | "f64") {
-                    return Err(TypeParsingError::UnexpectedFloat);
-                }
-
                 let type_category = DataType::new(&type_name).unwrap_or(DataType::Custom);
 
                 match type_category {
@@ -65,35 +150,40 @@ impl SpannedDataTypeToken {
                 }
             },
             Type::Array(type_array) => {
-                let element_type = Self::get_data_type(&type_array.elem)?;
-                let DataType::Integer(integer_type) = element_type else {
-                    return Err(TypeParsingError::NonIntegerArrayType);
-                };
-
-                if !matches!(integer_type, IntegerType::U8) {
-                    return Err(TypeParsingError::NonIntegerArrayType);
+                if Self::get_float_bits(&type_array.elem).is_some() {
+                    return Err(TypeParsingError::UnexpectedFloat);
                 }
 
+                let element_type = match Self::get_data_type(&type_array.elem)? {
+                    DataType::Integer(integer_type) => ArrayElementType::Integer(integer_type),
+                    DataType::Custom => ArrayElementType::Custom,
+                    _ => return Err(TypeParsingError::NonIntegerArrayType),
+                };
+
+                // Array lengths of literal arithmetic (e.g. `[u8; 2 * 4]`) are evaluated,
+                // while const lengths (e.g. `[u8; LENGTH]`) are left to the compiler.
                 let length = match &type_array.len {
-                    syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
-                        syn::Lit::Int(lit_int) => lit_int
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(lit_int), ..
+                    }) => Some(
+                        lit_int
                             .base10_parse::<u32>()
                             .map_err(|_| TypeParsingError::ArrayLengthTooLarge)?,
-                        _ => {
-                            return Err(TypeParsingError::InvalidArrayLength);
-                        },
-                    },
-                    _ => {
-                        return Err(TypeParsingError::InvalidArrayLength);
-                    },
+                    ),
+                    len => evaluate_literal_arithmetic(len)
+                        .map(|length| {
+                            u32::try_from(length).map_err(|_| TypeParsingError::ArrayLengthTooLarge)
+                        })
+                        .transpose()?,
                 };
 
-                if length == 0 {
+                if length == Some(0) {
                     return Err(TypeParsingError::ZeroArrayLength);
                 }
 
                 Ok(DataType::Array {
                     length,
+                    element_type,
                 })
             },
             _ => Err(TypeParsingError::Unexpected(format!("Unsupported type: {syn_type:?}"))),
@@ -104,13 +194,109 @@ impl SpannedDataTypeToken {
         self.spanned_token.span()
     }
 
+    /// Returns the bits of the type if it's a float (`f32` or `f64`), which is
+    /// a custom type.
+    pub const fn float_bits(&self) -> Option<u32> {
+        self.float_bits
+    }
+
+    /// Returns the bits of the type if it's a non-zero unsigned integer (e.g.
+    /// `NonZeroU8`), which is a custom type.
+    pub const fn non_zero_bits(&self) -> Option<u32> {
+        self.non_zero_bits
+    }
+
+    /// Returns the width and signedness of the type if it's an arbitrary-width
+    /// integer (e.g. `u4`), which is a custom type.
+    pub const fn arbitrary_int(&self) -> Option<ArbitraryIntType> {
+        self.arbitrary_int
+    }
+
+    /// Returns the wrapped type of an `Option<T>` type, or `None` for other
+    /// types.
+    pub fn option_inner_type(&self) -> Option<Result<Self, TypeParsingError>> {
+        let Type::Path(type_path) = syn::parse2::<Type>(self.original_type_tokens.clone()).ok()?
+        else {
+            return None;
+        };
+        let last_segment = type_path.path.segments.last()?;
+        if last_segment.ident != "Option" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(arguments) = &last_segment.arguments else {
+            return None;
+        };
+        match arguments.args.first()? {
+            syn::GenericArgument::Type(inner_type) if arguments.args.len() == 1 => {
+                Some(Self::new(inner_type))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns if the type converts through the `BitfieldField` trait, which
+    /// custom types do other than floats and arbitrary-width integers, and
+    /// `Option` types of integers, floats and arbitrary-width integers, which
+    /// convert through const functions.
+    pub fn converts_through_trait(&self) -> bool {
+        if !matches!(self.data_type, DataType::Custom) {
+            return matches!(
+                self.data_type,
+                DataType::Array {
+                    element_type: ArrayElementType::Custom,
+                    ..
+                }
+            );
+        }
+
+        if let Some(inner_type) = self.option_inner_type() {
+            return inner_type.is_ok_and(|inner_type| {
+                !matches!(inner_type.data_type, DataType::Integer(_))
+                    && inner_type.converts_through_trait()
+            });
+        }
+
+        self.float_bits.is_none() && self.arbitrary_int.is_none()
+    }
+
+    /// Returns the length expression of an `AsciiStr<N>` type, or `None` for
+    /// other types.
+    pub fn ascii_str_length_expr(&self) -> Option<syn::Expr> {
+        let Type::Path(type_path) = syn::parse2::<Type>(self.original_type_tokens.clone()).ok()?
+        else {
+            return None;
+        };
+        let last_segment = type_path.path.segments.last()?;
+        if last_segment.ident != "AsciiStr" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(arguments) = &last_segment.arguments else {
+            return None;
+        };
+        match arguments.args.first()? {
+            syn::GenericArgument::Const(length_expr) if arguments.args.len() == 1 => {
+                Some(length_expr.clone())
+            },
+            // Const lengths of a single identifier (e.g. `AsciiStr<LENGTH>`) are parsed as
+            // types.
+            syn::GenericArgument::Type(Type::Path(length_path)) if arguments.args.len() == 1 => {
+                Some(syn::Expr::Path(syn::ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: length_path.path.clone(),
+                }))
+            },
+            _ => None,
+        }
+    }
+
     /// Returns the category of the type kind.
     pub const fn data_type(&self) -> DataType {
         self.data_type
     }
 
     /// Returns the byte length of an array-backed type, or `None` for
-    /// non-array types.
+    /// non-array types and arrays with a const length.
     ///
     /// For `[u8; N]` this returns `Some(N)`, which is the same value that
     /// `core::mem::size_of::<[u8; N]>()` produces at runtime.  Callers in
@@ -119,12 +305,62 @@ impl SpannedDataTypeToken {
     pub const fn array_length(&self) -> Option<usize> {
         match self.data_type {
             DataType::Array {
-                length,
+                length: Some(length), ..
             } => Some(length as usize),
             _ => None,
         }
     }
 
+    /// Returns the length of an array type as tokens, which is the const
+    /// expression of arrays whose length is only known by the compiler (e.g.
+    /// `(LENGTH)` for `[u8; LENGTH]`), or `None` for non-array types.
+    pub fn array_length_tokens(&self) -> Option<TokenStream> {
+        if let Some(length) = self.array_length() {
+            return Some(quote! { #length });
+        }
+
+        let type_array = syn::parse2::<syn::TypeArray>(self.original_type_tokens.clone()).ok()?;
+        let length_expr = type_array.len;
+        Some(quote! { (#length_expr) })
+    }
+
+    /// Returns if the type is an array with a const length (e.g. `[u8;
+    /// LENGTH]`), which only the compiler can evaluate.
+    pub const fn has_const_array_length(&self) -> bool {
+        matches!(
+            self.data_type,
+            DataType::Array {
+                length: None,
+                ..
+            }
+        )
+    }
+
+    /// Returns the bits of an integer or array type, which are only known by
+    /// the compiler for arrays with a const length.
+    pub fn bits(&self) -> BitsExpr {
+        match self.data_type {
+            DataType::Array {
+                length: None,
+                element_type: ArrayElementType::Integer(integer_type),
+            } => {
+                let length_tokens =
+                    self.array_length_tokens().expect("Expected length for array type");
+                let element_bits = integer_type.bit_size();
+                BitsExpr::Deferred(quote! { #length_tokens as u32 * #element_bits })
+            },
+            data_type => BitsExpr::Known(data_type.bit_size()),
+        }
+    }
+
+    /// Returns the element type tokens of an array type, or `None` for
+    /// non-array types.
+    pub fn array_element_type_tokens(&self) -> Option<TokenStream> {
+        let type_array = syn::parse2::<syn::TypeArray>(self.original_type_tokens.clone()).ok()?;
+        let element_type = type_array.elem;
+        Some(quote! { #element_type })
+    }
+
     /// Returns the token representation of the data type.
     /// For array-backed types, returns `u128` as the internal backing type.
     pub fn get_data_type_tokens(&self) -> TokenStream {
@@ -144,12 +380,27 @@ impl ToTokens for SpannedDataTypeToken {
     }
 }
 
+/// Represents an arbitrary-width integer type of the `bitfields` crate (e.g.
+/// `u4` or `i12`), which holds values of exactly its width.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct ArbitraryIntType {
+    /// The width of the integer.
+    bits: u32,
+
+    /// Whether the integer is signed.
+    signed: bool,
+}
+
 /// Classifies a parsed type as a built-in integer, array, or custom type.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum DataType {
     Integer(IntegerType),
     Array {
-        length: u32,
+        /// The length of the array, or `None` for a const length (e.g. `[u8;
+        /// LENGTH]`) that only the compiler can evaluate.
+        length: Option<u32>,
+        element_type: ArrayElementType,
     },
     /// A user-defined type.
     Custom,
@@ -180,14 +431,38 @@ impl DataType {
         match self {
             Self::Integer(integer_type) => integer_type.bit_size(),
             Self::Array {
-                length,
-            } => length * IntegerType::U8.bit_size(),
+                length: Some(length),
+                element_type: ArrayElementType::Integer(integer_type),
+            } => length * integer_type.bit_size(),
+            Self::Array {
+                length: None, ..
+            } => unreachable!(
+                "Arrays with a const length should not have a known bit size and all calls should \
+                 be guarded."
+            ),
+            Self::Array {
+                element_type: ArrayElementType::Custom, ..
+            } => unreachable!(
+                "Arrays of custom types should not have a bit size and all calls should be \
+                 guarded."
+            ),
             Self::Custom => unreachable!(
                 "Custom types should not have a bit size and all calls should be guarded."
             ),
         }
     }
 
+    /// Returns the bits of each element of arrays that are always packed, which
+    /// are arrays of integer types other than `u8`.
+    pub fn implicit_element_bits(self) -> Option<u32> {
+        match self {
+            Self::Array {
+                element_type: ArrayElementType::Integer(integer_type), ..
+            } if integer_type != IntegerType::U8 => Some(integer_type.bit_size()),
+            _ => None,
+        }
+    }
+
     pub const fn unsigned(self) -> bool {
         match self {
             Self::Integer(integer_type) => integer_type.is_unsigned(),
@@ -199,6 +474,14 @@ impl DataType {
     }
 }
 
+/// Classifies the element type of an array.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ArrayElementType {
+    Integer(IntegerType),
+    /// A user-defined type.
+    Custom,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum IntegerType {
     U8,
@@ -217,7 +500,7 @@ pub enum IntegerType {
 }
 
 impl IntegerType {
-    fn bit_size(self) -> u32 {
+    pub fn bit_size(self) -> u32 {
         match self {
             Self::U8 | Self::I8 => 8,
             Self::U16 | Self::I16 => 16,
@@ -232,7 +515,7 @@ impl IntegerType {
         }
     }
 
-    const fn is_unsigned(self) -> bool {
+    pub const fn is_unsigned(self) -> bool {
         matches!(self, Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::U128 | Self::Bool)
     }
 }
@@ -20,9 +20,6 @@ pub enum TypeParsingError {
     #[error("Array length must greater than 0")]
     ZeroArrayLength,
 
-    #[error("Invalid array length")]
-    InvalidArrayLength,
-
     #[error("Array length too large")]
     ArrayLengthTooLarge,
 
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -33,6 +35,42 @@ impl Visibility {
             syn::Visibility::Inherited => Self::Private,
         }
     }
+
+    /// Returns the visibility reaching the most code out of the two.
+    pub fn widest(self, other: Self) -> Self {
+        let reach = |visibility: &Self| match visibility {
+            Self::Public => 3,
+            Self::Restricted(path) if path == "crate" => 2,
+            Self::Restricted(path) if path == "super" => 1,
+            Self::Restricted(_) | Self::Private => 0,
+        };
+
+        if reach(&other) > reach(&self) { other } else { self }
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = String;
+
+    /// Parses a visibility argument (e.g. `pub(crate)`). Restrictions to
+    /// arbitrary paths (`pub(in path)`) aren't supported.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_visibility_error = || {
+            "Invalid visibility. Valid values are 'pub', 'pub(crate)', 'pub(super)' or 'pub(self)'."
+                .to_string()
+        };
+
+        let vis = syn::parse_str::<syn::Visibility>(s).map_err(|_| invalid_visibility_error())?;
+        match &vis {
+            syn::Visibility::Public(_) => Ok(Self::Public),
+            syn::Visibility::Restricted(r)
+                if r.in_token.is_none() && r.path.get_ident().is_some() =>
+            {
+                Ok(Self::new(&vis))
+            },
+            _ => Err(invalid_visibility_error()),
+        }
+    }
 }
 
 impl ToTokens for Visibility {
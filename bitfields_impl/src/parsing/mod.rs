@@ -1,3 +1,4 @@
 pub mod bitfields;
 pub mod bitflags;
 pub mod common;
+pub mod tagged_unions;
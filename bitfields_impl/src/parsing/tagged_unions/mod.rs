@@ -0,0 +1,2 @@
+pub mod tagged_union;
+pub mod tagged_union_parser;
@@ -0,0 +1,125 @@
+use getset::{CloneGetters, Getters};
+use proc_macro2::{Ident, TokenStream};
+use quote::ToTokens;
+
+use crate::parsing::bitfields::bitfield::{Bitfield, Field};
+use crate::parsing::bitfields::bitfield_attribute::bitfield_arguments::BitfieldArguments;
+use crate::parsing::common::spanned_data_type::SpannedDataTypeToken;
+use crate::parsing::common::visibility::Visibility;
+
+/// The name of the tag field shared by the variants of a tagged union.
+pub const TAG_FIELD_NAME: &str = "tag";
+
+/// Represents an annotated enum that represents a tagged-union bitfield, where
+/// the value of a tag field selects the layout of the remaining bits.
+#[derive(Getters, CloneGetters)]
+#[getset(get_clone = "pub")]
+pub struct TaggedUnion {
+    /// The user defined attributes of the tagged union.
+    user_attributes_tokens: Vec<TokenStream>,
+
+    /// The visibility of the tagged union.
+    visibility: Visibility,
+
+    /// The ident of the tagged union.
+    #[getset(skip)]
+    name_ident: Ident,
+
+    /// The type of the tagged union.
+    spanned_data_type_token: SpannedDataTypeToken,
+
+    /// The type of the tag.
+    tag_data_type_tokens: TokenStream,
+
+    /// The variants of the tagged union.
+    #[getset(skip)]
+    variants: Vec<TaggedUnionVariant>,
+
+    /// The arguments of the tagged union.
+    arguments: BitfieldArguments,
+}
+
+impl TaggedUnion {
+    /// Creates a new [`TaggedUnion`] instance.
+    pub fn new(
+        user_attributes_tokens: Vec<TokenStream>,
+        visibility: Visibility,
+        name_ident: Ident,
+        spanned_data_type_token: SpannedDataTypeToken,
+        tag_data_type_tokens: TokenStream,
+        variants: Vec<TaggedUnionVariant>,
+        arguments: BitfieldArguments,
+    ) -> Self {
+        Self {
+            user_attributes_tokens,
+            visibility,
+            name_ident,
+            spanned_data_type_token,
+            tag_data_type_tokens,
+            variants,
+            arguments,
+        }
+    }
+
+    /// Returns the name as tokens.
+    pub fn name_tokens(&self) -> TokenStream {
+        self.name_ident.to_token_stream()
+    }
+
+    /// Returns the variants of the tagged union.
+    pub fn variants(&self) -> &[TaggedUnionVariant] {
+        &self.variants
+    }
+}
+
+/// Represents a tagged-union variant, which is backed by a generated bitfield
+/// struct holding the tag and the fields of the variant.
+#[derive(Getters, CloneGetters)]
+pub struct TaggedUnionVariant {
+    /// The user defined documentation attributes of the variant.
+    #[getset(get_clone = "pub")]
+    documentation_attributes_tokens: Vec<TokenStream>,
+
+    /// The ident of the variant.
+    #[getset(get = "pub")]
+    name_ident: Ident,
+
+    /// The tag selecting the variant.
+    #[getset(get_clone = "pub")]
+    tag_tokens: TokenStream,
+
+    /// The bitfield struct of the variant.
+    #[getset(get = "pub")]
+    bitfield: Bitfield,
+}
+
+impl TaggedUnionVariant {
+    /// Creates a new [`TaggedUnionVariant`] instance.
+    pub const fn new(
+        documentation_attributes_tokens: Vec<TokenStream>,
+        name_ident: Ident,
+        tag_tokens: TokenStream,
+        bitfield: Bitfield,
+    ) -> Self {
+        Self {
+            documentation_attributes_tokens,
+            name_ident,
+            tag_tokens,
+            bitfield,
+        }
+    }
+
+    /// Returns the name as tokens.
+    pub fn name_tokens(&self) -> TokenStream {
+        self.name_ident.to_token_stream()
+    }
+
+    /// Returns the tag field of the variant.
+    pub fn tag_field(&self) -> Field {
+        self.bitfield
+            .fields()
+            .into_iter()
+            .find(|field| field.name() == TAG_FIELD_NAME)
+            .expect("Expected a tag field in the tagged-union variant")
+    }
+}
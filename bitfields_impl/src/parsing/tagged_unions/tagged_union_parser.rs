@@ -0,0 +1,439 @@
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, Field, Fields, ItemEnum, ItemStruct, Lit, LitInt, Meta, Variant};
+
+use crate::parsing::bitfields::bitfield_attribute::bitfield_attribute_parser::BitfieldAttribute;
+use crate::parsing::bitfields::bitfield_parser::{
+    parse_bitfield_attribute, parse_bitfield_item_struct,
+};
+use crate::parsing::common::compiler_error::create_user_parsing_compiler_error;
+use crate::parsing::common::spanned_data_type::DataType;
+use crate::parsing::common::to_tokens::ToTokens;
+use crate::parsing::common::visibility::Visibility;
+use crate::parsing::tagged_unions::tagged_union::{
+    TAG_FIELD_NAME, TaggedUnion, TaggedUnionVariant,
+};
+
+const TAG_ATTRIBUTE_NAME: &str = "tag";
+const DERIVE_ATTRIBUTE_NAME: &str = "derive";
+const DOC_ATTRIBUTE_NAME: &str = "doc";
+
+/// Parses an enum annotated with `#[bitfield(..)]` into a [`TaggedUnion`].
+///
+/// Each variant is parsed as a bitfield struct named after the enum and the
+/// variant, with the tag as its first field unless the variant declares it.
+pub fn parse_tagged_union_enum(args: TokenStream, input: TokenStream) -> syn::Result<TaggedUnion> {
+    let enum_tokens = parse_enum_tokens(&input)?;
+    let bitfield_attribute = parse_bitfield_attribute(args)?;
+
+    check_generic_parameters(&enum_tokens)?;
+    check_variants_have_named_fields(&enum_tokens)?;
+
+    let tag_bits = get_tag_bits(&bitfield_attribute, &enum_tokens)?;
+
+    check_integer_backed(&bitfield_attribute)?;
+    check_layout_assertions(&bitfield_attribute)?;
+    check_from_into_bits(&bitfield_attribute, &enum_tokens)?;
+
+    let user_attributes_tokens =
+        enum_tokens.attrs.iter().map(quote::ToTokens::into_token_stream).collect();
+    let visibility = Visibility::new(&enum_tokens.vis);
+    let name_ident = enum_tokens.ident.clone();
+    let tag_data_type_tokens = get_tag_data_type_tokens(tag_bits);
+    let variants = enum_tokens
+        .variants
+        .iter()
+        .map(|variant| {
+            parse_variant(
+                &bitfield_attribute,
+                &enum_tokens,
+                variant,
+                tag_bits,
+                &tag_data_type_tokens,
+            )
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    check_unique_tags(&enum_tokens)?;
+    check_tag_positions(&variants)?;
+
+    Ok(TaggedUnion::new(
+        user_attributes_tokens,
+        visibility,
+        name_ident,
+        bitfield_attribute.spanned_data_type_token(),
+        tag_data_type_tokens,
+        variants,
+        bitfield_attribute.arguments(),
+    ))
+}
+
+fn parse_enum_tokens(input: &TokenStream) -> syn::Result<ItemEnum> {
+    syn::parse2::<ItemEnum>(input.clone())
+}
+
+/// Returns if the enum is a tagged union, which has a variant with a tag or
+/// named fields. Other enums are annotated by mistake, as `#[bitfield]`
+/// expects a struct.
+pub fn is_tagged_union_enum(enum_tokens: &ItemEnum) -> bool {
+    enum_tokens.variants.iter().any(|variant| {
+        matches!(variant.fields, Fields::Named(_))
+            || variant.attrs.iter().any(|attr| attr.path().is_ident(TAG_ATTRIBUTE_NAME))
+    })
+}
+
+/// Checks that the enum doesn't have generic parameters, as the variants are
+/// generated as separate structs.
+fn check_generic_parameters(enum_tokens: &ItemEnum) -> syn::Result<()> {
+    if enum_tokens.generics.params.is_empty() {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        enum_tokens.generics.span(),
+        "Tagged-union bitfields can't have generic parameters.",
+    ))
+}
+
+/// Checks that every variant declares named fields, which make up the layout
+/// of the variant.
+fn check_variants_have_named_fields(enum_tokens: &ItemEnum) -> syn::Result<()> {
+    for variant in &enum_tokens.variants {
+        if !matches!(variant.fields, Fields::Named(_)) || variant.discriminant.is_some() {
+            return Err(create_user_parsing_compiler_error(
+                variant.span(),
+                format!(
+                    "Tagged-union bitfield variant '{}' must have named fields (e.g. `{} {{ a: u8 \
+                     }}`).",
+                    variant.ident, variant.ident
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the bits of the tag, which tagged-union bitfields must define.
+fn get_tag_bits(
+    bitfield_attribute: &BitfieldAttribute,
+    enum_tokens: &ItemEnum,
+) -> syn::Result<u32> {
+    bitfield_attribute.arguments().tag_bits().ok_or_else(|| {
+        create_user_parsing_compiler_error(
+            enum_tokens.ident.span(),
+            "Tagged-union bitfields must define the bits of their tag (e.g. `#[bitfield(u32, \
+             tag_bits = 7)]`).",
+        )
+    })
+}
+
+/// Checks that the tagged union is backed by an unsigned integer, array-backed
+/// and signed tagged unions are not supported.
+fn check_integer_backed(bitfield_attribute: &BitfieldAttribute) -> syn::Result<()> {
+    if matches!(
+        bitfield_attribute.spanned_data_type_token().data_type(),
+        DataType::Integer(integer_type) if integer_type.is_unsigned()
+    ) {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        bitfield_attribute.spanned_data_type_token().span(),
+        "Tagged-union bitfields must have an unsigned integer type.",
+    ))
+}
+
+/// Checks that the tagged union doesn't declare layout assertions, as the
+/// variants don't share their fields.
+fn check_layout_assertions(bitfield_attribute: &BitfieldAttribute) -> syn::Result<()> {
+    let Some(layout_assertion) = bitfield_attribute.layout_assertions().first().cloned() else {
+        return Ok(());
+    };
+
+    Err(create_user_parsing_compiler_error(
+        layout_assertion.span(),
+        "Layout assertions are not supported on tagged-union bitfields.",
+    ))
+}
+
+/// Checks that the `from_into_bits` functions are generated, as the variants
+/// are decoded from and encoded into bits with them.
+fn check_from_into_bits(
+    bitfield_attribute: &BitfieldAttribute,
+    enum_tokens: &ItemEnum,
+) -> syn::Result<()> {
+    if bitfield_attribute.arguments().generate_from_into_bits() {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        enum_tokens.ident.span(),
+        "Tagged-union bitfields must generate the `from_into_bits` functions, as the variants are \
+         decoded from and encoded into bits with them.",
+    ))
+}
+
+/// Returns the smallest unsigned integer type that holds the tag.
+fn get_tag_data_type_tokens(tag_bits: u32) -> TokenStream {
+    match tag_bits {
+        0..=8 => quote! { u8 },
+        9..=16 => quote! { u16 },
+        17..=32 => quote! { u32 },
+        33..=64 => quote! { u64 },
+        _ => quote! { u128 },
+    }
+}
+
+/// Parses a variant into a [`TaggedUnionVariant`] by parsing the bitfield
+/// struct generated for it.
+fn parse_variant(
+    bitfield_attribute: &BitfieldAttribute,
+    enum_tokens: &ItemEnum,
+    variant: &Variant,
+    tag_bits: u32,
+    tag_data_type_tokens: &TokenStream,
+) -> syn::Result<TaggedUnionVariant> {
+    let tag_literal = parse_variant_tag(variant)?;
+    check_tag_fits_in_tag_bits(variant, &tag_literal, tag_bits)?;
+    let struct_tokens = create_variant_struct_tokens(
+        enum_tokens,
+        variant,
+        tag_bits,
+        &tag_literal,
+        tag_data_type_tokens,
+    )?;
+    let bitfield = parse_bitfield_item_struct(bitfield_attribute, &struct_tokens)?;
+    let documentation_attributes_tokens = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(DOC_ATTRIBUTE_NAME))
+        .map(quote::ToTokens::into_token_stream)
+        .collect();
+
+    Ok(TaggedUnionVariant::new(
+        documentation_attributes_tokens,
+        variant.ident.clone(),
+        quote! { #tag_literal },
+        bitfield,
+    ))
+}
+
+/// Parses the `#[tag = ..]` attribute of the variant.
+fn parse_variant_tag(variant: &Variant) -> syn::Result<LitInt> {
+    let tag_attribute = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(TAG_ATTRIBUTE_NAME))
+        .ok_or_else(|| {
+            create_user_parsing_compiler_error(
+                variant.ident.span(),
+                format!(
+                    "Tagged-union bitfield variant '{}' must have a tag (e.g. `#[tag = 0b01]`).",
+                    variant.ident
+                ),
+            )
+        })?;
+
+    parse_tag_literal(tag_attribute).ok_or_else(|| {
+        create_user_parsing_compiler_error(
+            tag_attribute.span(),
+            "Invalid tag, expected an unsigned integer literal (e.g. `#[tag = 0b01]`).",
+        )
+    })
+}
+
+fn parse_tag_literal(tag_attribute: &Attribute) -> Option<LitInt> {
+    let Meta::NameValue(name_value) = &tag_attribute.meta else {
+        return None;
+    };
+    let Expr::Lit(expr_lit) = &name_value.value else {
+        return None;
+    };
+    let Lit::Int(lit_int) = &expr_lit.lit else {
+        return None;
+    };
+
+    lit_int.base10_parse::<u128>().ok().map(|_| lit_int.clone())
+}
+
+/// Checks that the tag of the variant fits in the bits of the tag.
+fn check_tag_fits_in_tag_bits(
+    variant: &Variant,
+    tag_literal: &LitInt,
+    tag_bits: u32,
+) -> syn::Result<()> {
+    let tag = tag_literal.base10_parse::<u128>()?;
+    let max_tag = u128::MAX >> (128 - tag_bits.min(128));
+    if tag <= max_tag {
+        return Ok(());
+    }
+
+    Err(create_user_parsing_compiler_error(
+        tag_literal.span(),
+        format!(
+            "The tag '{tag_literal}' of the variant '{}' exceeds the maximum value for the \
+             '{tag_bits} bits ({max_tag})' of the tag.",
+            variant.ident
+        ),
+    ))
+}
+
+/// Creates the bitfield struct of a variant, which holds the tag, defaulted to
+/// the tag of the variant, followed by the fields of the variant. A `tag` field
+/// declared by the variant places the tag among the fields instead.
+///
+/// The derives of the enum are shared with the struct, as the enum can only
+/// derive them if the struct does.
+fn create_variant_struct_tokens(
+    enum_tokens: &ItemEnum,
+    variant: &Variant,
+    tag_bits: u32,
+    tag_literal: &LitInt,
+    tag_data_type_tokens: &TokenStream,
+) -> syn::Result<ItemStruct> {
+    let visibility = &enum_tokens.vis;
+    let struct_name_ident = create_variant_struct_name_ident(&enum_tokens.ident, &variant.ident);
+    let tag_field_ident = format_ident!("{}", TAG_FIELD_NAME);
+    let derive_attributes =
+        enum_tokens.attrs.iter().filter(|attr| attr.path().is_ident(DERIVE_ATTRIBUTE_NAME));
+    let variant_attributes =
+        variant.attrs.iter().filter(|attr| !attr.path().is_ident(TAG_ATTRIBUTE_NAME));
+    let tag_bits_literal = Literal::u32_unsuffixed(tag_bits);
+    let tag_field_tokens = quote! {
+        #[bits(#tag_bits_literal, default = #tag_literal, access = ro)]
+        #tag_field_ident: #tag_data_type_tokens
+    };
+
+    let declared_tag_field = variant.fields.iter().find(|field| is_tag_field(field));
+    let fields_tokens = if let Some(declared_tag_field) = declared_tag_field {
+        check_declared_tag_field(variant, declared_tag_field, tag_data_type_tokens)?;
+        variant
+            .fields
+            .iter()
+            .map(|field| {
+                if !is_tag_field(field) {
+                    return quote! { #field };
+                }
+                let documentation_attributes =
+                    field.attrs.iter().filter(|attr| attr.path().is_ident(DOC_ATTRIBUTE_NAME));
+                quote! {
+                    #( #documentation_attributes )*
+                    #tag_field_tokens
+                }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        std::iter::once(tag_field_tokens)
+            .chain(variant.fields.iter().map(|field| quote! { #field }))
+            .collect()
+    };
+
+    syn::parse2(quote! {
+        #( #derive_attributes )*
+        #( #variant_attributes )*
+        #visibility struct #struct_name_ident {
+            #( #fields_tokens, )*
+        }
+    })
+}
+
+fn is_tag_field(field: &Field) -> bool {
+    field.ident.as_ref().is_some_and(|ident| ident == TAG_FIELD_NAME)
+}
+
+/// Checks that a `tag` field declared by a variant only places the tag, as its
+/// bits, default value and access are the ones of the tag.
+fn check_declared_tag_field(
+    variant: &Variant,
+    tag_field: &Field,
+    tag_data_type_tokens: &TokenStream,
+) -> syn::Result<()> {
+    if let Some(attr) =
+        tag_field.attrs.iter().find(|attr| !attr.path().is_ident(DOC_ATTRIBUTE_NAME))
+    {
+        return Err(create_user_parsing_compiler_error(
+            attr.span(),
+            format!(
+                "The `tag` field of the variant '{}' places the tag of the tagged union and can \
+                 only have documentation attributes.",
+                variant.ident
+            ),
+        ));
+    }
+
+    let tag_field_type = &tag_field.ty;
+    if quote! { #tag_field_type }.to_string() != tag_data_type_tokens.to_string() {
+        return Err(create_user_parsing_compiler_error(
+            tag_field_type.span(),
+            format!(
+                "The `tag` field of the variant '{}' places the tag of the tagged union and must \
+                 have the type of the tag '{tag_data_type_tokens}'.",
+                variant.ident
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the name of the struct of a variant, which is the name of the enum
+/// followed by the name of the variant (e.g. `InstructionR`).
+fn create_variant_struct_name_ident(enum_ident: &Ident, variant_ident: &Ident) -> Ident {
+    format_ident!("{}{}", enum_ident, variant_ident, span = variant_ident.span())
+}
+
+/// Checks that no two variants share a tag, as the tag couldn't select a
+/// variant.
+fn check_unique_tags(enum_tokens: &ItemEnum) -> syn::Result<()> {
+    let mut seen_tags: Vec<(u128, &Ident)> = Vec::new();
+
+    for variant in &enum_tokens.variants {
+        let tag_literal = parse_variant_tag(variant)?;
+        let tag = tag_literal.base10_parse::<u128>()?;
+
+        if let Some((_, other_variant_ident)) = seen_tags.iter().find(|(seen, _)| *seen == tag) {
+            return Err(create_user_parsing_compiler_error(
+                tag_literal.span(),
+                format!(
+                    "The tag '{tag_literal}' of the variant '{}' is already used by the variant \
+                     '{other_variant_ident}'.",
+                    variant.ident
+                ),
+            ));
+        }
+
+        seen_tags.push((tag, &variant.ident));
+    }
+
+    Ok(())
+}
+
+/// Checks that the tag has the same offset in every variant, as the tag is
+/// decoded before the variant is known.
+fn check_tag_positions(variants: &[TaggedUnionVariant]) -> syn::Result<()> {
+    let Some((first_variant, other_variants)) = variants.split_first() else {
+        return Ok(());
+    };
+    let first_tag_offset_tokens = first_variant.tag_field().offset().to_tokens().to_string();
+
+    for variant in other_variants {
+        let tag_field = variant.tag_field();
+        if tag_field.offset().to_tokens().to_string() == first_tag_offset_tokens {
+            continue;
+        }
+
+        return Err(create_user_parsing_compiler_error(
+            variant.name_ident().span(),
+            format!(
+                "The tag of the variant '{}' must be at the same bit offset as the tag of the \
+                 variant '{}'.",
+                variant.name_ident(),
+                first_variant.name_ident()
+            ),
+        ));
+    }
+
+    Ok(())
+}